@@ -0,0 +1,24 @@
+// generates the grpc server code from `proto/` when the `grpc` feature is enabled, protoc is
+// vendored so the build does not depend on a system install
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // migrations are embedded with `sqlx::migrate!`, new files have to trigger a rebuild
+    println!("cargo:rerun-if-changed=database/migrations");
+    println!("cargo:rerun-if-changed=database/sqlite");
+    println!("cargo:rerun-if-changed=proto");
+
+    #[cfg(feature = "grpc")]
+    {
+        let mut config = tonic_prost_build::Config::new();
+        config.protoc_executable(protoc_bin_vendored::protoc_bin_path()?);
+
+        tonic_prost_build::configure()
+            .build_client(false)
+            .compile_with_config(
+                config,
+                &["proto/todo.proto", "proto/user.proto"],
+                &["proto"],
+            )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS time_entries (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    todo_id UUID NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    note TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now(),
+
+    CONSTRAINT time_entries_fk_users
+    FOREIGN KEY (user_id)
+    REFERENCES users (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    CONSTRAINT time_entries_fk_todos
+    FOREIGN KEY (todo_id)
+    REFERENCES todos (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    CONSTRAINT time_entries_range
+    CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+-- a user can only have one running timer at a time
+CREATE UNIQUE INDEX IF NOT EXISTS time_entries_running_idx ON time_entries (user_id) WHERE ended_at IS NULL;
+CREATE INDEX IF NOT EXISTS time_entries_todo_idx ON time_entries (todo_id);
+CREATE INDEX IF NOT EXISTS time_entries_started_idx ON time_entries (user_id, started_at);
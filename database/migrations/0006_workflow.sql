@@ -0,0 +1,36 @@
+CREATE TABLE IF NOT EXISTS workflow_states (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    category TEXT NOT NULL CHECK (category IN ('todo', 'doing', 'done')),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now(),
+
+    CONSTRAINT workflow_states_fk_users
+    FOREIGN KEY (user_id)
+    REFERENCES users (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    CONSTRAINT workflow_states_name_key UNIQUE (user_id, name)
+);
+
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS state_id UUID
+    REFERENCES workflow_states (id) ON DELETE SET NULL;
+
+-- state ids are kept without foreign keys so history survives deleted states
+CREATE TABLE IF NOT EXISTS state_transitions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    todo_id UUID NOT NULL,
+    from_state_id UUID,
+    to_state_id UUID NOT NULL,
+    transitioned_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT state_transitions_fk_todos
+    FOREIGN KEY (todo_id)
+    REFERENCES todos (id)
+    ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS state_transitions_todo_idx ON state_transitions (todo_id, transitioned_at);
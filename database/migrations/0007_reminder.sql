@@ -0,0 +1,58 @@
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS snoozed_until TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS reminders (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    todo_id UUID NOT NULL,
+    remind_at TIMESTAMPTZ,
+    minutes_before_due INTEGER,
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT now(),
+
+    CONSTRAINT reminders_fk_users
+    FOREIGN KEY (user_id)
+    REFERENCES users (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    CONSTRAINT reminders_fk_todos
+    FOREIGN KEY (todo_id)
+    REFERENCES todos (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    -- a reminder fires either at an absolute time or relative to the todo due date
+    CONSTRAINT reminders_trigger
+    CHECK ((remind_at IS NULL) <> (minutes_before_due IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS reminders_pending_idx ON reminders (todo_id) WHERE delivered_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS notifications (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    todo_id UUID,
+    reminder_id UUID,
+    title VARCHAR(255) NOT NULL,
+    message TEXT NOT NULL DEFAULT '',
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT now(),
+
+    CONSTRAINT notifications_fk_users
+    FOREIGN KEY (user_id)
+    REFERENCES users (id)
+    ON DELETE CASCADE ON UPDATE CASCADE,
+
+    CONSTRAINT notifications_fk_todos
+    FOREIGN KEY (todo_id)
+    REFERENCES todos (id)
+    ON DELETE SET NULL ON UPDATE CASCADE,
+
+    CONSTRAINT notifications_fk_reminders
+    FOREIGN KEY (reminder_id)
+    REFERENCES reminders (id)
+    ON DELETE SET NULL ON UPDATE CASCADE,
+
+    -- guards against a reminder being delivered twice
+    CONSTRAINT notifications_reminder_unique UNIQUE (reminder_id)
+);
+
+CREATE INDEX IF NOT EXISTS notifications_user_idx ON notifications (user_id, created_at DESC);
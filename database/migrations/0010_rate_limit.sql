@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS rate_limits (
+    -- the action and who attempts it, e.g. `login:ip:203.0.113.7` or `login:email:someone@domain.com`
+    key TEXT PRIMARY KEY,
+    hits INTEGER NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS rate_limits_expires_idx ON rate_limits (expires_at);
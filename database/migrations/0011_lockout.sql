@@ -0,0 +1,18 @@
+-- failed logins since the last successful one, the row is removed on success or unlock
+CREATE TABLE IF NOT EXISTS login_failures (
+    user_id UUID PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE ON UPDATE CASCADE,
+    failures INTEGER NOT NULL,
+    locked_until TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS lockout_events (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE ON UPDATE CASCADE,
+    kind VARCHAR(16) NOT NULL,
+    failures INTEGER NOT NULL,
+    locked_until TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS lockout_events_user_idx ON lockout_events (user_id, created_at);
@@ -0,0 +1,2 @@
+-- todos may belong to a project, time reports group by it
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS project VARCHAR(100);
@@ -0,0 +1,3 @@
+-- anonymous keys are scoped by client address now, the old shared scope may hold login,
+-- register and refresh responses with live tokens
+DELETE FROM idempotency_keys WHERE scope = 'anonymous';
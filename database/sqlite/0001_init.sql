@@ -0,0 +1,39 @@
+-- single file schema for the sqlite backend, only todos, users and the todo event log
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    name VARCHAR(50) NOT NULL,
+    email VARCHAR(100) UNIQUE NOT NULL,
+    password VARCHAR(64) NOT NULL,
+    token VARCHAR(255),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS todos (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES users (id) ON DELETE RESTRICT ON UPDATE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    is_completed BOOLEAN NOT NULL DEFAULT FALSE,
+    state_id TEXT,
+    priority SMALLINT NOT NULL DEFAULT 0,
+    -- json array of strings
+    tags TEXT NOT NULL DEFAULT '[]',
+    due_at TEXT,
+    snoozed_until TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS todos_user_idx ON todos (user_id);
+
+CREATE TABLE IF NOT EXISTS todo_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL REFERENCES users (id) ON DELETE CASCADE ON UPDATE CASCADE,
+    todo_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS todo_events_user_idx ON todo_events (user_id, id);
@@ -0,0 +1,3 @@
+ALTER TABLE users ADD COLUMN email_verified_at TEXT;
+
+UPDATE users SET email_verified_at = created_at WHERE email_verified_at IS NULL;
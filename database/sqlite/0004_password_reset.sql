@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS password_resets (
+    token_hash TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL UNIQUE REFERENCES users (id) ON DELETE CASCADE ON UPDATE CASCADE,
+    expires_at TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
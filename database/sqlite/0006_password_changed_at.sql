@@ -0,0 +1,2 @@
+-- access tokens issued before the latest password change are no longer accepted
+ALTER TABLE users ADD COLUMN password_changed_at TEXT;
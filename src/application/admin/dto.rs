@@ -0,0 +1,29 @@
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+pub struct CreateUserRequest {
+    #[validate(length(min = 1, message = "name is required"))]
+    pub name: String,
+
+    #[validate(
+        email(message = "Please enter a valid email address"),
+        length(min = 1, message = "email is required")
+    )]
+    pub email: String,
+
+    #[validate(length(min = 1, message = "password is required"))]
+    pub password: String,
+}
+
+#[derive(Debug, Validate)]
+pub struct ResetPasswordRequest {
+    pub email: String,
+
+    #[validate(length(min = 1, message = "password is required"))]
+    pub password: String,
+}
+
+#[derive(Debug)]
+pub struct PurgeResult {
+    pub todos: usize,
+}
@@ -0,0 +1,18 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum AdminError {
+    NotFound,
+    Conflict,
+    GeneralError,
+}
+
+impl From<ModelError> for AdminError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            ModelError::Conflict => Self::Conflict,
+            ModelError::Database(_) => Self::GeneralError,
+        }
+    }
+}
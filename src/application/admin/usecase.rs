@@ -0,0 +1,467 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        admin::{
+            dto::{CreateUserRequest, PurgeResult, ResetPasswordRequest},
+            error::AdminError,
+        },
+        user::dto::UserResponse,
+    },
+    domain::{
+        lockout::{
+            model::{LockoutEvent, LockoutEventKind},
+            repository::LockoutRepository,
+        },
+        shared::transaction::{TransactionManager, UnitOfWork},
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::database::replica,
+};
+
+// operator tasks run from the command line or the admin api, they are not scoped to a signed in
+// user
+pub struct AdminUseCase<U, X, L>
+where
+    U: UserRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+    L: LockoutRepository + Send + Sync,
+{
+    user_repository: U,
+    transactions: X,
+    lockout_repository: L,
+}
+
+impl<U: UserRepository, X: TransactionManager, L: LockoutRepository> AdminUseCase<U, X, L> {
+    pub fn new(user: U, transactions: X, lockout: L) -> Self {
+        Self {
+            user_repository: user,
+            transactions,
+            lockout_repository: lockout,
+        }
+    }
+
+    pub async fn list_users(&self) -> Result<Vec<UserResponse>, AdminError> {
+        self.user_repository
+            .find_all()
+            .await
+            .map_err(AdminError::from)
+            .map(|users| users.into_iter().map(UserResponse::from).collect())
+    }
+
+    pub async fn create_user(&self, dto: CreateUserRequest) -> Result<UserResponse, AdminError> {
+        let password = User::hash_password(&dto.password).map_err(|err| {
+            tracing::error!("failed hash password : {}", err);
+            AdminError::GeneralError
+        })?;
+
+        let user = User {
+            id: Uuid::new_v4(),
+            name: dto.name,
+            email: dto.email.to_lowercase(),
+            password,
+            token: None,
+            // the operator vouches for the address
+            email_verified_at: Some(Utc::now()),
+            password_changed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.user_repository
+            .create(user)
+            .await
+            .map(UserResponse::from)
+            .map_err(AdminError::from)
+    }
+
+    // a new password also signs the user out, the old refresh and access tokens stop working
+    pub async fn reset_password(&self, dto: ResetPasswordRequest) -> Result<(), AdminError> {
+        let mut user = self.find_by_email(&dto.email).await?;
+
+        user.password = User::hash_password(&dto.password).map_err(|err| {
+            tracing::error!("failed hash password : {}", err);
+            AdminError::GeneralError
+        })?;
+        user.token = None;
+        user.password_changed_at = Some(Utc::now());
+        user.updated_at = Utc::now();
+
+        self.user_repository
+            .update(&user)
+            .await
+            .map(|_| ())
+            .map_err(AdminError::from)
+    }
+
+    pub async fn revoke_token(&self, email: &str) -> Result<(), AdminError> {
+        let mut user = self.find_by_email(email).await?;
+
+        user.token = None;
+        user.updated_at = Utc::now();
+
+        self.user_repository
+            .update(&user)
+            .await
+            .map(|_| ())
+            .map_err(AdminError::from)
+    }
+
+    // lifts a lock before it runs out and forgets the failed logins that led to it
+    pub async fn unlock(&self, id: Uuid) -> Result<(), AdminError> {
+        self.user_repository
+            .find_by_id(id)
+            .await
+            .map_err(AdminError::from)?
+            .ok_or(AdminError::NotFound)?;
+
+        self.unlock_user(id).await
+    }
+
+    pub async fn unlock_by_email(&self, email: &str) -> Result<(), AdminError> {
+        let user = self.find_by_email(email).await?;
+
+        self.unlock_user(user.id).await
+    }
+
+    // todos restrict deleting their owner, everything else goes with the user row, either all
+    // of it is removed or nothing
+    pub async fn purge_user(&self, email: &str) -> Result<PurgeResult, AdminError> {
+        let user = self.find_by_email(email).await?;
+        let uow = self.transactions.begin().await.map_err(AdminError::from)?;
+
+        let todos = match Self::delete_account(uow.as_ref(), user.id).await {
+            Ok(todos) => todos,
+            Err(err) => {
+                if let Err(err) = uow.rollback().await {
+                    tracing::error!("failed to roll back user purge : {}", err);
+                }
+                return Err(err);
+            }
+        };
+
+        uow.commit().await.map_err(AdminError::from)?;
+
+        Ok(PurgeResult { todos })
+    }
+
+    // returns how many todos were deleted along with the user
+    async fn delete_account(uow: &dyn UnitOfWork, user_id: Uuid) -> Result<usize, AdminError> {
+        let todos = uow
+            .todos()
+            .find_all_including_snoozed(user_id)
+            .await
+            .map_err(AdminError::from)?;
+
+        for todo in &todos {
+            uow.todos()
+                .delete(user_id, todo.id)
+                .await
+                .map_err(AdminError::from)?;
+        }
+
+        uow.users()
+            .delete(user_id)
+            .await
+            .map_err(AdminError::from)?;
+
+        Ok(todos.len())
+    }
+
+    // an account without failed logins has nothing to unlock and leaves no event
+    async fn unlock_user(&self, user_id: Uuid) -> Result<(), AdminError> {
+        let Some(failures) = self
+            .lockout_repository
+            .find(user_id)
+            .await
+            .map_err(AdminError::from)?
+        else {
+            return Ok(());
+        };
+
+        self.lockout_repository
+            .reset(user_id)
+            .await
+            .map_err(AdminError::from)?;
+
+        self.lockout_repository
+            .record_event(LockoutEvent::new(
+                LockoutEventKind::Unlocked,
+                failures.user_id,
+                failures.failures,
+                None,
+            ))
+            .await
+            .map_err(AdminError::from)
+    }
+
+    // read from the primary, the user is written back whole
+    async fn find_by_email(&self, email: &str) -> Result<User, AdminError> {
+        replica::scope(
+            true,
+            self.user_repository.find_by_email(&email.to_lowercase()),
+        )
+        .await
+        .map_err(AdminError::from)?
+        .ok_or(AdminError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::admin::{
+            dto::{CreateUserRequest, ResetPasswordRequest},
+            error::AdminError,
+            usecase::AdminUseCase,
+        },
+        domain::{
+            lockout::{
+                model::{LockoutEventKind, LoginFailures},
+                repository::MockLockoutRepository,
+            },
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork},
+            },
+            todo::model::Todo,
+            user::{model::User, repository::MockUserRepository},
+        },
+    };
+
+    fn user(id: Uuid) -> User {
+        User {
+            id,
+            name: "test".to_string(),
+            email: "test@domain.com".to_string(),
+            password: User::hash_password("demo123").unwrap(),
+            token: Some("refresh".to_string()),
+            email_verified_at: Some(Utc::now()),
+            password_changed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn todo(user_id: Uuid) -> Todo {
+        Todo {
+            id: Uuid::new_v4(),
+            user_id,
+            title: "todo".to_string(),
+            description: String::new(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_user_conflict() {
+        let mut users = MockUserRepository::new();
+
+        users
+            .expect_create()
+            .return_once(|_| Box::pin(async { Err(ModelError::Conflict) }));
+
+        let usecase = AdminUseCase::new(
+            users,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
+        let result = usecase
+            .create_user(CreateUserRequest {
+                name: "test".to_string(),
+                email: "test@domain.com".to_string(),
+                password: "secret".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AdminError::Conflict)));
+    }
+
+    #[tokio::test]
+    async fn reset_password_revokes_token() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        users
+            .expect_update()
+            .withf(|user| {
+                user.token.is_none()
+                    && user.password_changed_at.is_some()
+                    && user.verify_password("new-secret".to_string())
+            })
+            .return_once(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let usecase = AdminUseCase::new(
+            users,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
+        let result = usecase
+            .reset_password(ResetPasswordRequest {
+                email: "TEST@domain.com".to_string(),
+                password: "new-secret".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn revoke_token_unknown_user() {
+        let mut users = MockUserRepository::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        let usecase = AdminUseCase::new(
+            users,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
+        let result = usecase.revoke_token("nobody@domain.com").await;
+
+        assert!(matches!(result, Err(AdminError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn unlock_resets_failures_and_records_event() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut lockout = MockLockoutRepository::new();
+
+        users
+            .expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        lockout.expect_find().return_once(move |_| {
+            Box::pin(async move {
+                Ok(Some(LoginFailures {
+                    user_id: id,
+                    failures: 7,
+                    locked_until: Some(Utc::now() + chrono::Duration::minutes(5)),
+                    updated_at: Utc::now(),
+                }))
+            })
+        });
+
+        lockout
+            .expect_reset()
+            .withf(move |user_id| *user_id == id)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        lockout
+            .expect_record_event()
+            .withf(move |event| {
+                event.user_id == id
+                    && event.kind == LockoutEventKind::Unlocked
+                    && event.failures == 7
+                    && event.locked_until.is_none()
+            })
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let usecase = AdminUseCase::new(users, MockTransactionManager::new(), lockout);
+
+        assert!(usecase.unlock(id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unlock_unknown_user() {
+        let mut users = MockUserRepository::new();
+        let mut lockout = MockLockoutRepository::new();
+
+        users
+            .expect_find_by_id()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        lockout.expect_reset().never();
+
+        let usecase = AdminUseCase::new(users, MockTransactionManager::new(), lockout);
+        let result = usecase.unlock(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(AdminError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn purge_user_deletes_todos_first() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut uow = MockUnitOfWork::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        uow.todos
+            .expect_find_all_including_snoozed()
+            .withf(move |user_id| *user_id == id)
+            .return_once(move |_| Box::pin(async move { Ok(vec![todo(id), todo(id)]) }));
+
+        uow.todos
+            .expect_delete()
+            .withf(move |user_id, _| *user_id == id)
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        uow.users
+            .expect_delete()
+            .withf(move |user_id| *user_id == id)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let finished = uow.finished.clone();
+        let usecase = AdminUseCase::new(users, uow.begins(), MockLockoutRepository::new());
+        let result = usecase.purge_user("test@domain.com").await.unwrap();
+
+        assert_eq!(result.todos, 2);
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
+    }
+
+    #[tokio::test]
+    async fn purge_user_keeps_todos_when_user_delete_fails() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut uow = MockUnitOfWork::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        uow.todos
+            .expect_find_all_including_snoozed()
+            .return_once(move |_| Box::pin(async move { Ok(vec![todo(id)]) }));
+
+        uow.todos
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        uow.users.expect_delete().return_once(|_| {
+            Box::pin(async { Err(ModelError::Database("lock timeout".to_string())) })
+        });
+
+        let finished = uow.finished.clone();
+        let usecase = AdminUseCase::new(users, uow.begins(), MockLockoutRepository::new());
+        let result = usecase.purge_user("test@domain.com").await;
+
+        assert!(result.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+}
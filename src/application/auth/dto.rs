@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -48,3 +48,18 @@ pub struct RegisterRequest {
     #[validate(length(min = 1, message = "password is required"))]
     pub password: String,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResendVerificationRequest {
+    #[serde(default)]
+    #[validate(email, length(min = 1, message = "email is required"))]
+    #[schema(example = "demo@demo.com")]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+pub struct VerifyEmailQuery {
+    #[serde(default)]
+    #[validate(length(min = 1, message = "token is required"))]
+    pub token: String,
+}
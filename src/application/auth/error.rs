@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::domain::shared::error::ModelError;
 
 #[derive(Debug)]
@@ -6,6 +8,10 @@ pub enum AuthError {
     Conflict,
     InvalidCredentials,
     TokenExpired,
+    // too many failed logins, retry after the given time
+    Locked(DateTime<Utc>),
+    // the password matched but the address was never confirmed
+    EmailNotVerified,
     GeneralError,
 }
 
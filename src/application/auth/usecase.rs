@@ -1,28 +1,164 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 use crate::{
     application::{
         auth::{
-            dto::{AuthResponse, LoginRequest, RefreshTokenRequest, RegisterRequest},
+            dto::{
+                AuthResponse, LoginRequest, RefreshTokenRequest, RegisterRequest,
+                ResendVerificationRequest,
+            },
             error::AuthError,
         },
         user::dto::UserResponse,
     },
-    domain::user::{model::User, repository::UserRepository},
-    infrastructure::security::{jwt::JwtClaims, token::Token},
+    domain::{
+        lockout::{
+            model::{LockoutEvent, LockoutEventKind},
+            repository::LockoutRepository,
+        },
+        mail::mailer::{Mail, Mailer},
+        shared::error::ModelError,
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::{
+        database::replica,
+        security::{
+            jwt::{JwtClaims, VerificationClaims},
+            token::Token,
+        },
+    },
 };
 
-pub struct AuthUseCase<T: UserRepository + Send + Sync> {
+// failed logins allowed before an account is locked, every failure past the threshold doubles
+// the lock up to `max_duration`
+#[derive(Debug, Clone, Copy)]
+pub struct LockoutPolicy {
+    pub threshold: u32,
+    pub duration: std::time::Duration,
+    pub max_duration: std::time::Duration,
+}
+
+impl LockoutPolicy {
+    // none while the failures are below the threshold
+    pub fn lock_for(&self, failures: i32) -> Option<Duration> {
+        let over = u32::try_from(failures)
+            .ok()?
+            .checked_sub(self.threshold.max(1))?;
+        let lock = self
+            .duration
+            .saturating_mul(2u32.saturating_pow(over.min(16)))
+            .min(self.max_duration);
+
+        Duration::from_std(lock).ok()
+    }
+
+    // counts the failure and locks the account once it reached the threshold, returns the end of
+    // the lock
+    pub async fn record_failure<L: LockoutRepository>(
+        &self,
+        lockout: &L,
+        user_id: Uuid,
+    ) -> Result<Option<DateTime<Utc>>, ModelError> {
+        let failures = lockout.record_failure(user_id).await?;
+
+        let Some(lock) = self.lock_for(failures) else {
+            return Ok(None);
+        };
+
+        let until: DateTime<Utc> = Utc::now() + lock;
+        lockout.lock(user_id, until).await?;
+
+        let event = LockoutEvent::new(LockoutEventKind::Locked, user_id, failures, Some(until));
+        if let Err(err) = lockout.record_event(event).await {
+            tracing::error!("failed record lockout event : {}", err);
+        }
+
+        tracing::warn!(
+            "user {} locked until {} after {} failures",
+            user_id,
+            until,
+            failures
+        );
+        Ok(Some(until))
+    }
+}
+
+// every new account is sent a link to `url` with a token valid for `ttl`, accounts that did not
+// follow it can only log in while verification is not `required`
+#[derive(Debug, Clone)]
+pub struct EmailVerification {
+    pub required: bool,
+    pub ttl: std::time::Duration,
+    pub url: String,
+}
+
+impl EmailVerification {
+    // mails the link for the current address of `user`
+    pub async fn send<M: Mailer>(
+        &self,
+        mailer: &M,
+        secret: &str,
+        user: &User,
+    ) -> Result<(), String> {
+        let ttl = Duration::from_std(self.ttl).map_err(|err| err.to_string())?;
+        let claims = VerificationClaims::new(user.id, user.email.clone(), ttl);
+        let token = claims.encode(secret).map_err(|err| err.to_string())?;
+
+        let separator = match self.url.contains('?') {
+            true => '&',
+            false => '?',
+        };
+        let link = format!("{}{}token={}", self.url, separator, token);
+        let expires_at = Utc::now() + ttl;
+
+        let mail = Mail {
+            to: user.email.clone(),
+            subject: "Verify your email address".to_string(),
+            body: format!(
+                "Hi {},\n\nplease confirm this is your email address by opening the link below, \
+                 it is valid until {}.\n\n{}\n",
+                user.name,
+                expires_at.format("%Y-%m-%d %H:%M UTC"),
+                link
+            ),
+        };
+
+        mailer.send(mail).await
+    }
+}
+
+pub struct AuthUseCase<T, L, M>
+where
+    T: UserRepository + Send + Sync,
+    L: LockoutRepository + Send + Sync,
+    M: Mailer + Send + Sync,
+{
     user_repository: T,
+    lockout_repository: L,
+    mailer: M,
+    lockout_policy: LockoutPolicy,
+    verification: EmailVerification,
     jwt_secret: String,
     jwt_duration: Duration,
 }
 
-impl<T: UserRepository> AuthUseCase<T> {
-    pub fn new(repo: T, jwt_secret: String, jwt_duration: i64) -> Self {
+impl<T: UserRepository, L: LockoutRepository, M: Mailer> AuthUseCase<T, L, M> {
+    pub fn new(
+        repo: T,
+        lockout: L,
+        mailer: M,
+        lockout_policy: LockoutPolicy,
+        verification: EmailVerification,
+        jwt_secret: String,
+        jwt_duration: i64,
+    ) -> Self {
         Self {
             user_repository: repo,
+            lockout_repository: lockout,
+            mailer,
+            lockout_policy,
+            verification,
             jwt_secret,
             jwt_duration: Duration::minutes(jwt_duration),
         }
@@ -40,20 +176,92 @@ impl<T: UserRepository> AuthUseCase<T> {
             email: dto.email.to_lowercase(),
             password,
             token: None,
+            email_verified_at: None,
+            password_changed_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
 
-        self.user_repository
+        let user = self
+            .user_repository
             .create(user)
             .await
+            .map_err(AuthError::from)?;
+
+        // the account exists either way, a lost mail can be sent again
+        if let Err(err) = self
+            .verification
+            .send(&self.mailer, &self.jwt_secret, &user)
+            .await
+        {
+            tracing::error!("failed send verification to {} : {}", user.id, err);
+        }
+
+        Ok(user)
+    }
+
+    pub async fn verify_email(&self, token: &str) -> Result<(), AuthError> {
+        let claims = VerificationClaims::decode(token, &self.jwt_secret)
+            .map_err(|_| AuthError::TokenExpired)?;
+
+        // the whole user is written back, so it is read from the primary
+        let mut user = replica::scope(true, self.user_repository.find_by_id(claims.sub))
+            .await?
+            .ok_or(AuthError::NotFound)?;
+
+        // the link was sent to an address the account no longer uses
+        if user.email != claims.email {
+            return Err(AuthError::TokenExpired);
+        }
+
+        if user.email_verified_at.is_some() {
+            return Ok(());
+        }
+
+        user.email_verified_at = Some(Utc::now());
+        user.updated_at = Utc::now();
+
+        self.user_repository
+            .update(&user)
+            .await
+            .map(|_| ())
             .map_err(AuthError::from)
     }
 
-    pub async fn login(&self, dto: LoginRequest) -> Result<AuthResponse, AuthError> {
-        let mut user = match self
+    // answers the same for unknown and already verified addresses, so it can't be used to find
+    // out who has an account
+    pub async fn resend_verification(
+        &self,
+        dto: ResendVerificationRequest,
+    ) -> Result<(), AuthError> {
+        let user = match self
             .user_repository
-            .find_by_email(&dto.email)
+            .find_by_email(&dto.email.to_lowercase())
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) | Err(ModelError::NotFound) => return Ok(()),
+            Err(err) => return Err(AuthError::from(err)),
+        };
+
+        if user.email_verified_at.is_some() {
+            return Ok(());
+        }
+
+        if let Err(err) = self
+            .verification
+            .send(&self.mailer, &self.jwt_secret, &user)
+            .await
+        {
+            tracing::error!("failed resend verification to {} : {}", user.id, err);
+        }
+
+        Ok(())
+    }
+
+    pub async fn login(&self, dto: LoginRequest) -> Result<AuthResponse, AuthError> {
+        // a lagging replica may still have the old password, the update below would restore it
+        let mut user = match replica::scope(true, self.user_repository.find_by_email(&dto.email))
             .await
             .map_err(AuthError::from)?
         {
@@ -61,8 +269,25 @@ impl<T: UserRepository> AuthUseCase<T> {
             None => return Err(AuthError::NotFound),
         };
 
+        let failures = self.lockout_repository.find(user.id).await?;
+        if let Some(until) = failures
+            .as_ref()
+            .filter(|failures| failures.is_locked(Utc::now()))
+            .and_then(|failures| failures.locked_until)
+        {
+            return Err(AuthError::Locked(until));
+        }
+
         if !user.verify_password(dto.password) {
-            return Err(AuthError::InvalidCredentials);
+            return Err(self.failed_login(user.id).await);
+        }
+
+        if failures.is_some() {
+            self.lockout_repository.reset(user.id).await?;
+        }
+
+        if self.verification.required && user.email_verified_at.is_none() {
+            return Err(AuthError::EmailNotVerified);
         }
 
         let refresh_token = Token::new(&self.jwt_secret, &Uuid::new_v4().to_string())
@@ -95,11 +320,13 @@ impl<T: UserRepository> AuthUseCase<T> {
         let refresh_token =
             Token::new(&self.jwt_secret, &dto.token).map_err(|_| AuthError::GeneralError)?;
 
-        let user = self
-            .user_repository
-            .find_by_token(&refresh_token.encrypted)
-            .await?
-            .ok_or(AuthError::NotFound)?;
+        // a signed out token may still be on a lagging replica
+        let user = replica::scope(
+            true,
+            self.user_repository.find_by_token(&refresh_token.encrypted),
+        )
+        .await?
+        .ok_or(AuthError::NotFound)?;
 
         let Some(token) = user.token else {
             tracing::error!("token is empty");
@@ -137,9 +364,7 @@ impl<T: UserRepository> AuthUseCase<T> {
     }
 
     pub async fn logout(&self, id: Uuid) -> Result<(), AuthError> {
-        let mut user = match self
-            .user_repository
-            .find_by_id(id)
+        let mut user = match replica::scope(true, self.user_repository.find_by_id(id))
             .await
             .map_err(AuthError::from)?
         {
@@ -156,30 +381,76 @@ impl<T: UserRepository> AuthUseCase<T> {
             .map(|_| ())
             .map_err(|_| AuthError::GeneralError)
     }
+
+    async fn failed_login(&self, user_id: Uuid) -> AuthError {
+        match self
+            .lockout_policy
+            .record_failure(&self.lockout_repository, user_id)
+            .await
+        {
+            Ok(Some(until)) => AuthError::Locked(until),
+            Ok(None) => AuthError::InvalidCredentials,
+            Err(err) => AuthError::from(err),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::time::Duration;
+
     use chrono::Utc;
     use mockall::predicate::eq;
     use uuid::Uuid;
 
     use crate::{
         application::auth::{
-            dto::{LoginRequest, RefreshTokenRequest, RegisterRequest},
+            dto::{LoginRequest, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest},
             error::AuthError,
-            usecase::AuthUseCase,
+            usecase::{AuthUseCase, EmailVerification, LockoutPolicy},
         },
         domain::{
+            lockout::{
+                model::{LockoutEventKind, LoginFailures},
+                repository::MockLockoutRepository,
+            },
+            mail::mailer::MockMailer,
             shared::error::ModelError,
             user::{model::User, repository::MockUserRepository},
         },
-        infrastructure::security::token::Token,
+        infrastructure::security::{jwt::VerificationClaims, token::Token},
     };
 
     const JWT_SECRET: &str = "secret";
     const JWT_DURATION: i64 = 10;
+    const POLICY: LockoutPolicy = LockoutPolicy {
+        threshold: 3,
+        duration: Duration::from_secs(60),
+        max_duration: Duration::from_secs(3600),
+    };
+
+    fn verification(required: bool) -> EmailVerification {
+        EmailVerification {
+            required,
+            ttl: Duration::from_secs(60),
+            url: "http://localhost/verify".to_string(),
+        }
+    }
+
+    fn user_with_password(password: &str) -> User {
+        User {
+            id: Uuid::new_v4(),
+            name: "test".to_string(),
+            email: "test@domain.com".to_string(),
+            password: User::hash_password(password).unwrap(),
+            token: None,
+            email_verified_at: Some(Utc::now()),
+            password_changed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
 
     #[tokio::test]
     async fn register_success() {
@@ -195,7 +466,24 @@ mod tests {
             })
         });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let mut mailer = MockMailer::new();
+        mailer
+            .expect_send()
+            .withf(|mail| {
+                mail.to == "test@domain.com" && mail.body.contains("http://localhost/verify?token=")
+            })
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            mailer,
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = RegisterRequest {
             name: "test".to_string(),
@@ -208,9 +496,104 @@ mod tests {
         if let Ok(user) = result {
             assert!(!user.name.is_empty(), "name should not be empty");
             assert!(!user.email.is_empty(), "email should not be empty");
+            assert!(
+                user.email_verified_at.is_none(),
+                "email should not be verified"
+            );
         }
     }
 
+    #[tokio::test]
+    async fn verify_email_marks_user_verified() {
+        let mut user = user_with_password("test123");
+        user.email_verified_at = None;
+        let token =
+            VerificationClaims::new(user.id, user.email.clone(), chrono::Duration::minutes(1))
+                .encode(JWT_SECRET)
+                .unwrap();
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+        repo.expect_update()
+            .withf(|user| user.email_verified_at.is_some())
+            .times(1)
+            .returning(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(true),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        assert!(auth.verify_email(&token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn verify_email_rejects_link_for_previous_address() {
+        let mut user = user_with_password("test123");
+        user.email_verified_at = None;
+        let token = VerificationClaims::new(
+            user.id,
+            "old@domain.com".to_string(),
+            chrono::Duration::minutes(1),
+        )
+        .encode(JWT_SECRET)
+        .unwrap();
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+        repo.expect_update().never();
+
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(true),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let result = auth.verify_email(&token).await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::TokenExpired));
+    }
+
+    #[tokio::test]
+    async fn resend_verification_unknown_email_sends_nothing() {
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_email()
+            .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
+
+        let mut mailer = MockMailer::new();
+        mailer.expect_send().never();
+
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            mailer,
+            POLICY,
+            verification(true),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let dto = ResendVerificationRequest {
+            email: "nobody@domain.com".to_string(),
+        };
+
+        assert!(auth.resend_verification(dto).await.is_ok());
+    }
+
     #[tokio::test]
     async fn register_duplicated_email() {
         let mut repo = MockUserRepository::new();
@@ -219,7 +602,15 @@ mod tests {
             .withf(|user| user.email == "test@domain.com")
             .return_once(|_| Box::pin(async move { Err(ModelError::Conflict) }));
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = RegisterRequest {
             name: "test".to_string(),
@@ -245,6 +636,8 @@ mod tests {
                     email: "test@domain.com".to_string(),
                     password,
                     token: Some(Uuid::new_v4().to_string()),
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
@@ -257,7 +650,21 @@ mod tests {
             Box::pin(async move { Ok(user_owned) })
         });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        lockout.expect_reset().never();
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = LoginRequest {
             email: "test@domain.com".to_string(),
@@ -282,6 +689,8 @@ mod tests {
                     email: "test@domain.com".to_string(),
                     password,
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
@@ -289,7 +698,24 @@ mod tests {
             })
         });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        lockout
+            .expect_record_failure()
+            .return_once(|_| Box::pin(async { Ok(1) }));
+        lockout.expect_lock().never();
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = LoginRequest {
             email: "test@domain.com".to_string(),
@@ -298,12 +724,194 @@ mod tests {
 
         let result = auth.login(dto).await;
 
-        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), AuthError::InvalidCredentials));
+    }
+
+    #[tokio::test]
+    async fn login_failure_locks_account_at_threshold() {
+        let user = user_with_password("demo123");
+        let user_id = user.id;
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        lockout
+            .expect_record_failure()
+            .with(eq(user_id))
+            .return_once(|_| Box::pin(async { Ok(4) }));
+        lockout
+            .expect_lock()
+            .withf(move |id, until| {
+                // one failure past the threshold doubles the lock
+                let lock = *until - Utc::now();
+                *id == user_id
+                    && lock > chrono::Duration::seconds(110)
+                    && lock <= chrono::Duration::seconds(120)
+            })
+            .return_once(|_, _| Box::pin(async { Ok(()) }));
+        lockout
+            .expect_record_event()
+            .withf(move |event| {
+                event.user_id == user_id
+                    && event.kind == LockoutEventKind::Locked
+                    && event.failures == 4
+            })
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let dto = LoginRequest {
+            email: "test@domain.com".to_string(),
+            password: "test123".to_string(),
+        };
+
+        let result = auth.login(dto).await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::Locked(_)));
+    }
+
+    #[tokio::test]
+    async fn login_locked_rejects_correct_password() {
+        let user = user_with_password("test123");
+        let user_id = user.id;
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+        repo.expect_update().never();
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout.expect_find().return_once(move |_| {
+            Box::pin(async move {
+                Ok(Some(LoginFailures {
+                    user_id,
+                    failures: 3,
+                    locked_until: Some(Utc::now() + chrono::Duration::minutes(1)),
+                    updated_at: Utc::now(),
+                }))
+            })
+        });
+        lockout.expect_record_failure().never();
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let dto = LoginRequest {
+            email: "test@domain.com".to_string(),
+            password: "test123".to_string(),
+        };
+
+        let result = auth.login(dto).await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::Locked(_)));
+    }
+
+    #[tokio::test]
+    async fn login_unverified_refused_when_required() {
+        let mut user = user_with_password("test123");
+        user.email_verified_at = None;
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+        repo.expect_update().never();
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(true),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let dto = LoginRequest {
+            email: "test@domain.com".to_string(),
+            password: "test123".to_string(),
+        };
+
+        let result = auth.login(dto).await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::EmailNotVerified));
+    }
+
+    #[tokio::test]
+    async fn login_success_resets_failures() {
+        let user = user_with_password("test123");
+        let user_id = user.id;
+
+        let mut repo = MockUserRepository::new();
+        repo.expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user)) }));
+        repo.expect_update().returning(|user| {
+            let user = user.clone();
+            Box::pin(async move { Ok(user) })
+        });
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout.expect_find().return_once(move |_| {
+            Box::pin(async move {
+                Ok(Some(LoginFailures {
+                    user_id,
+                    failures: 2,
+                    locked_until: None,
+                    updated_at: Utc::now(),
+                }))
+            })
+        });
+        lockout
+            .expect_reset()
+            .with(eq(user_id))
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let auth = AuthUseCase::new(
+            repo,
+            lockout,
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
+
+        let dto = LoginRequest {
+            email: "test@domain.com".to_string(),
+            password: "test123".to_string(),
+        };
+
+        assert!(auth.login(dto).await.is_ok());
     }
 
     #[tokio::test]
     async fn refresh_access_token_success() {
-        let token = Token::new(&JWT_SECRET.to_string(), &Uuid::new_v4().to_string()).unwrap();
+        let token = Token::new(JWT_SECRET, &Uuid::new_v4().to_string()).unwrap();
 
         let mut repo = MockUserRepository::new();
 
@@ -319,6 +927,8 @@ mod tests {
                         email: "test@domain.com".to_string(),
                         password,
                         token: Some(encripted),
+                        email_verified_at: Some(Utc::now()),
+                        password_changed_at: None,
                         created_at: Utc::now(),
                         updated_at: Utc::now(),
                     };
@@ -327,7 +937,15 @@ mod tests {
                 })
             });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = RefreshTokenRequest {
             token: token.decrypted,
@@ -353,6 +971,8 @@ mod tests {
                     email: "test@domain.com".to_string(),
                     password,
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
@@ -360,7 +980,15 @@ mod tests {
                 Box::pin(async move { Ok(Some(user)) })
             });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
         assert!(auth.whoami(id).await.is_ok());
     }
 
@@ -373,13 +1001,21 @@ mod tests {
             .withf(move |user_id| *user_id == id)
             .return_once(move |_| Box::pin(async move { Ok(None) }));
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
         assert!(auth.whoami(id).await.is_err());
     }
 
     #[tokio::test]
     async fn refresh_access_token_failed() {
-        let token = Token::new(&JWT_SECRET.to_string(), &Uuid::new_v4().to_string()).unwrap();
+        let token = Token::new(JWT_SECRET, &Uuid::new_v4().to_string()).unwrap();
 
         let mut repo = MockUserRepository::new();
 
@@ -394,6 +1030,8 @@ mod tests {
                         email: "test@domain.com".to_string(),
                         password,
                         token: None,
+                        email_verified_at: Some(Utc::now()),
+                        password_changed_at: None,
                         created_at: Utc::now(),
                         updated_at: Utc::now(),
                     };
@@ -402,7 +1040,15 @@ mod tests {
                 })
             });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
 
         let dto = RefreshTokenRequest {
             token: token.decrypted,
@@ -428,6 +1074,8 @@ mod tests {
                     email: "test@domain.com".to_string(),
                     password,
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
@@ -442,7 +1090,15 @@ mod tests {
                 Box::pin(async move { Ok(user) })
             });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
         assert!(auth.logout(id).await.is_ok());
     }
 
@@ -462,7 +1118,15 @@ mod tests {
                 Box::pin(async move { Ok(user) })
             });
 
-        let auth = AuthUseCase::new(repo, JWT_SECRET.to_string(), JWT_DURATION);
+        let auth = AuthUseCase::new(
+            repo,
+            MockLockoutRepository::new(),
+            MockMailer::new(),
+            POLICY,
+            verification(false),
+            JWT_SECRET.to_string(),
+            JWT_DURATION,
+        );
         assert!(auth.logout(id).await.is_err());
     }
 }
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::event::model::{TodoEvent, TodoEventKind};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TodoEventResponse {
+    pub id: i64,
+    pub kind: TodoEventKind,
+    pub todo_id: Uuid,
+    /// the todo right after the change
+    #[schema(value_type = Object)]
+    pub todo: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TodoEvent> for TodoEventResponse {
+    fn from(value: TodoEvent) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            todo_id: value.todo_id,
+            todo: value.payload,
+            created_at: value.created_at,
+        }
+    }
+}
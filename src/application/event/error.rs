@@ -0,0 +1,12 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum EventError {
+    GeneralError,
+}
+
+impl From<ModelError> for EventError {
+    fn from(_: ModelError) -> Self {
+        Self::GeneralError
+    }
+}
@@ -0,0 +1,103 @@
+use uuid::Uuid;
+
+use crate::{
+    application::event::{dto::TodoEventResponse, error::EventError},
+    domain::event::repository::EventRepository,
+};
+
+pub const REPLAY_BATCH: usize = 500;
+
+pub struct EventUseCase<E: EventRepository + Send + Sync> {
+    event_repository: E,
+}
+
+impl<E: EventRepository> EventUseCase<E> {
+    pub fn new(event: E) -> Self {
+        Self {
+            event_repository: event,
+        }
+    }
+
+    // next batch of the user's events after `after`, a full batch means more are waiting
+    pub async fn replay(
+        &self,
+        user_id: Uuid,
+        after: i64,
+    ) -> Result<Vec<TodoEventResponse>, EventError> {
+        self.event_repository
+            .find_since(user_id, after, REPLAY_BATCH as i64)
+            .await
+            .map_err(EventError::from)
+            .map(|events| {
+                events
+                    .into_iter()
+                    .map(TodoEventResponse::from)
+                    .collect::<Vec<TodoEventResponse>>()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::event::{
+            error::EventError,
+            usecase::{EventUseCase, REPLAY_BATCH},
+        },
+        domain::{
+            event::{
+                model::{TodoEvent, TodoEventKind},
+                repository::MockEventRepository,
+            },
+            shared::error::ModelError,
+        },
+    };
+
+    #[tokio::test]
+    async fn replay_after_cursor() {
+        let mut repo = MockEventRepository::new();
+        let user_id = Uuid::new_v4();
+
+        repo.expect_find_since()
+            .withf(move |uid, after, limit| {
+                uid == &user_id && *after == 41 && *limit == REPLAY_BATCH as i64
+            })
+            .return_once(|uid, _, _| {
+                let events = vec![TodoEvent {
+                    id: 42,
+                    user_id: uid,
+                    todo_id: Uuid::new_v4(),
+                    kind: TodoEventKind::Toggled,
+                    payload: serde_json::json!({ "is_completed": true }),
+                    created_at: Utc::now(),
+                }];
+                Box::pin(async move { Ok(events) })
+            });
+
+        let usecase = EventUseCase::new(repo);
+
+        let events = usecase.replay(user_id, 41).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, 42);
+        assert_eq!(events[0].todo["is_completed"], true);
+    }
+
+    #[tokio::test]
+    async fn replay_failed() {
+        let mut repo = MockEventRepository::new();
+
+        repo.expect_find_since().return_once(|_, _, _| {
+            Box::pin(async { Err(ModelError::Database("connection reset".to_string())) })
+        });
+
+        let usecase = EventUseCase::new(repo);
+
+        let result = usecase.replay(Uuid::new_v4(), 0).await;
+
+        assert!(matches!(result.unwrap_err(), EventError::GeneralError));
+    }
+}
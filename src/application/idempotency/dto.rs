@@ -0,0 +1,19 @@
+use crate::domain::idempotency::model::IdempotencyRecord;
+
+// response of the first request made with a key, replayed for every retry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl StoredResponse {
+    pub fn from_record(record: IdempotencyRecord) -> Option<Self> {
+        Some(Self {
+            status: u16::try_from(record.status_code?).ok()?,
+            content_type: record.content_type,
+            body: record.body.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,16 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum IdempotencyError {
+    // the key was already used for a different request
+    Mismatch,
+    // the first request with this key hasn't finished yet
+    InProgress,
+    GeneralError,
+}
+
+impl From<ModelError> for IdempotencyError {
+    fn from(_: ModelError) -> Self {
+        Self::GeneralError
+    }
+}
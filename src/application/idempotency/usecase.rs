@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::{
+    application::idempotency::{dto::StoredResponse, error::IdempotencyError},
+    domain::idempotency::{model::IdempotencyRecord, repository::IdempotencyRepository},
+};
+
+pub struct IdempotencyUseCase<I: IdempotencyRepository + Send + Sync> {
+    idempotency_repository: I,
+    window: Duration,
+}
+
+impl<I: IdempotencyRepository> IdempotencyUseCase<I> {
+    pub fn new(idempotency: I, window: Duration) -> Self {
+        Self {
+            idempotency_repository: idempotency,
+            window,
+        }
+    }
+
+    // claim the key for this request, or hand back the response stored by the first request
+    pub async fn begin(
+        &self,
+        scope: &str,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<Option<StoredResponse>, IdempotencyError> {
+        let now = Utc::now();
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::days(1));
+
+        let record = IdempotencyRecord {
+            scope: scope.to_string(),
+            key: key.to_string(),
+            fingerprint: fingerprint.to_string(),
+            status_code: None,
+            content_type: None,
+            body: None,
+            created_at: now,
+            expires_at: now + window,
+        };
+
+        if self
+            .idempotency_repository
+            .reserve(record)
+            .await
+            .map_err(IdempotencyError::from)?
+        {
+            return Ok(None);
+        }
+
+        let existing = self
+            .idempotency_repository
+            .find(scope, key)
+            .await
+            .map_err(IdempotencyError::from)?
+            .ok_or(IdempotencyError::InProgress)?;
+
+        if existing.fingerprint != fingerprint {
+            return Err(IdempotencyError::Mismatch);
+        }
+
+        StoredResponse::from_record(existing)
+            .map(Some)
+            .ok_or(IdempotencyError::InProgress)
+    }
+
+    pub async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        response: StoredResponse,
+    ) -> Result<(), IdempotencyError> {
+        self.idempotency_repository
+            .complete(
+                scope,
+                key,
+                response.status as i32,
+                response.content_type,
+                response.body,
+            )
+            .await
+            .map_err(IdempotencyError::from)
+    }
+
+    // forget the key so the request can be retried, used when it failed on our side
+    pub async fn release(&self, scope: &str, key: &str) -> Result<(), IdempotencyError> {
+        self.idempotency_repository
+            .release(scope, key)
+            .await
+            .map_err(IdempotencyError::from)
+    }
+
+    pub async fn purge_expired(&self) -> Result<u64, IdempotencyError> {
+        self.idempotency_repository
+            .purge_expired()
+            .await
+            .map_err(IdempotencyError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use crate::{
+        application::idempotency::{
+            dto::StoredResponse, error::IdempotencyError, usecase::IdempotencyUseCase,
+        },
+        domain::idempotency::{model::IdempotencyRecord, repository::MockIdempotencyRepository},
+    };
+
+    const WINDOW: Duration = Duration::from_secs(3600);
+
+    fn stored(fingerprint: &str, status_code: Option<i32>) -> IdempotencyRecord {
+        IdempotencyRecord {
+            scope: "anonymous".to_string(),
+            key: "key-1".to_string(),
+            fingerprint: fingerprint.to_string(),
+            status_code,
+            content_type: Some("application/json".to_string()),
+            body: status_code.map(|_| br#"{"code":"20000"}"#.to_vec()),
+            created_at: Utc::now(),
+            expires_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn begin_reserves_new_key() {
+        let mut repo = MockIdempotencyRepository::new();
+
+        repo.expect_reserve()
+            .withf(|record| {
+                record.key == "key-1"
+                    && record.status_code.is_none()
+                    && record.expires_at - record.created_at == chrono::Duration::hours(1)
+            })
+            .return_once(|_| Box::pin(async { Ok(true) }));
+        repo.expect_find().never();
+
+        let usecase = IdempotencyUseCase::new(repo, WINDOW);
+
+        let result = usecase.begin("anonymous", "key-1", "abc").await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn begin_replays_stored_response() {
+        let mut repo = MockIdempotencyRepository::new();
+
+        repo.expect_reserve()
+            .return_once(|_| Box::pin(async { Ok(false) }));
+        repo.expect_find()
+            .return_once(|_, _| Box::pin(async { Ok(Some(stored("abc", Some(200)))) }));
+
+        let usecase = IdempotencyUseCase::new(repo, WINDOW);
+
+        let result = usecase.begin("anonymous", "key-1", "abc").await.unwrap();
+
+        assert_eq!(
+            result,
+            Some(StoredResponse {
+                status: 200,
+                content_type: Some("application/json".to_string()),
+                body: br#"{"code":"20000"}"#.to_vec(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn begin_rejects_different_request() {
+        let mut repo = MockIdempotencyRepository::new();
+
+        repo.expect_reserve()
+            .return_once(|_| Box::pin(async { Ok(false) }));
+        repo.expect_find()
+            .return_once(|_, _| Box::pin(async { Ok(Some(stored("abc", Some(200)))) }));
+
+        let usecase = IdempotencyUseCase::new(repo, WINDOW);
+
+        let result = usecase.begin("anonymous", "key-1", "other").await;
+
+        assert!(matches!(result.unwrap_err(), IdempotencyError::Mismatch));
+    }
+
+    #[tokio::test]
+    async fn begin_while_first_request_runs() {
+        let mut repo = MockIdempotencyRepository::new();
+
+        repo.expect_reserve()
+            .return_once(|_| Box::pin(async { Ok(false) }));
+        repo.expect_find()
+            .return_once(|_, _| Box::pin(async { Ok(Some(stored("abc", None))) }));
+
+        let usecase = IdempotencyUseCase::new(repo, WINDOW);
+
+        let result = usecase.begin("anonymous", "key-1", "abc").await;
+
+        assert!(matches!(result.unwrap_err(), IdempotencyError::InProgress));
+    }
+}
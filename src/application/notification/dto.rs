@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::domain::notification::model::Notification;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationQuery {
+    /// only return notifications that have not been read yet
+    #[serde(default)]
+    pub unread: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    pub todo_id: Option<Uuid>,
+    pub title: String,
+    pub message: String,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(value: Notification) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            title: value.title,
+            message: value.message,
+            read_at: value.read_at,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum NotificationError {
+    NotFound,
+    GeneralError,
+}
+
+impl From<ModelError> for NotificationError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            _ => Self::GeneralError,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use uuid::Uuid;
+
+use crate::{
+    application::notification::{
+        dto::{NotificationQuery, NotificationResponse},
+        error::NotificationError,
+    },
+    domain::notification::repository::NotificationRepository,
+};
+
+pub struct NotificationUseCase<N: NotificationRepository + Send + Sync> {
+    notification_repository: N,
+}
+
+impl<N: NotificationRepository> NotificationUseCase<N> {
+    pub fn new(notification: N) -> Self {
+        Self {
+            notification_repository: notification,
+        }
+    }
+
+    pub async fn find_all(
+        &self,
+        user_id: Uuid,
+        query: NotificationQuery,
+    ) -> Result<Vec<NotificationResponse>, NotificationError> {
+        self.notification_repository
+            .find_all(user_id, query.unread)
+            .await
+            .map_err(NotificationError::from)
+            .map(|notifications| {
+                notifications
+                    .into_iter()
+                    .map(NotificationResponse::from)
+                    .collect::<Vec<NotificationResponse>>()
+            })
+    }
+
+    pub async fn mark_read(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<NotificationResponse, NotificationError> {
+        self.notification_repository
+            .mark_read(user_id, id)
+            .await
+            .map_err(NotificationError::from)
+            .map(NotificationResponse::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::notification::{
+            dto::NotificationQuery, error::NotificationError, usecase::NotificationUseCase,
+        },
+        domain::{
+            notification::{model::Notification, repository::MockNotificationRepository},
+            shared::error::ModelError,
+        },
+    };
+
+    #[tokio::test]
+    async fn find_unread_notifications() {
+        let mut repo = MockNotificationRepository::new();
+        let user_id = Uuid::new_v4();
+
+        repo.expect_find_all()
+            .withf(move |uid, unread| uid == &user_id && *unread)
+            .return_once(|uid, _| {
+                let notifications = vec![Notification {
+                    id: Uuid::new_v4(),
+                    user_id: uid,
+                    todo_id: Some(Uuid::new_v4()),
+                    reminder_id: Some(Uuid::new_v4()),
+                    title: "Pay invoice".to_string(),
+                    message: "Reminder".to_string(),
+                    read_at: None,
+                    created_at: Utc::now(),
+                }];
+                Box::pin(async move { Ok(notifications) })
+            });
+
+        let usecase = NotificationUseCase::new(repo);
+
+        let result = usecase
+            .find_all(user_id, NotificationQuery { unread: true })
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn mark_read_not_found() {
+        let mut repo = MockNotificationRepository::new();
+
+        repo.expect_mark_read()
+            .return_once(|_, _| Box::pin(async { Err(ModelError::NotFound) }));
+
+        let usecase = NotificationUseCase::new(repo);
+
+        let result = usecase.mark_read(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result.unwrap_err(), NotificationError::NotFound));
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordRequest {
+    #[serde(default)]
+    #[validate(email, length(min = 1, message = "email is required"))]
+    #[schema(example = "demo@demo.com")]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PasswordResetRequest {
+    #[serde(default)]
+    #[validate(length(min = 1, message = "token is required"))]
+    pub token: String,
+
+    #[serde(default)]
+    #[validate(length(min = 1, message = "password is required"))]
+    pub password: String,
+}
@@ -0,0 +1,15 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum PasswordResetError {
+    // unknown, expired or already used
+    InvalidToken,
+    GeneralError,
+}
+
+impl From<ModelError> for PasswordResetError {
+    fn from(err: ModelError) -> Self {
+        tracing::error!("password reset error : {}", err);
+        Self::GeneralError
+    }
+}
@@ -0,0 +1,359 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    application::password_reset::{
+        dto::{ForgotPasswordRequest, PasswordResetRequest},
+        error::PasswordResetError,
+    },
+    domain::{
+        mail::mailer::{Mail, Mailer},
+        password_reset::{model::PasswordReset, repository::PasswordResetRepository},
+        shared::error::ModelError,
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::{database::replica, security::token::Token},
+};
+
+// a reset token is valid for `ttl`, the mail links to `url` with the token appended, or only
+// carries the token while no url is configured
+#[derive(Debug, Clone)]
+pub struct PasswordResetPolicy {
+    pub ttl: std::time::Duration,
+    pub url: String,
+}
+
+pub struct PasswordResetUseCase<U, P, M>
+where
+    U: UserRepository + Send + Sync,
+    P: PasswordResetRepository + Send + Sync,
+    M: Mailer + Send + Sync,
+{
+    user_repository: U,
+    password_reset_repository: P,
+    mailer: M,
+    policy: PasswordResetPolicy,
+    secret: String,
+}
+
+impl<U: UserRepository, P: PasswordResetRepository, M: Mailer> PasswordResetUseCase<U, P, M> {
+    pub fn new(
+        user: U,
+        password_reset: P,
+        mailer: M,
+        policy: PasswordResetPolicy,
+        secret: String,
+    ) -> Self {
+        Self {
+            user_repository: user,
+            password_reset_repository: password_reset,
+            mailer,
+            policy,
+            secret,
+        }
+    }
+
+    // an unknown address is not an error, the caller must not learn who has an account
+    pub async fn forgot_password(
+        &self,
+        dto: ForgotPasswordRequest,
+    ) -> Result<(), PasswordResetError> {
+        let user = match self
+            .user_repository
+            .find_by_email(&dto.email.to_lowercase())
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) | Err(ModelError::NotFound) => return Ok(()),
+            Err(err) => return Err(PasswordResetError::from(err)),
+        };
+
+        let ttl =
+            Duration::from_std(self.policy.ttl).map_err(|_| PasswordResetError::GeneralError)?;
+        let token = Token::new(&self.secret, &Uuid::new_v4().to_string())
+            .map_err(|_| PasswordResetError::GeneralError)?;
+
+        let reset = PasswordReset {
+            token_hash: token.encrypted,
+            user_id: user.id,
+            expires_at: Utc::now() + ttl,
+            created_at: Utc::now(),
+        };
+        let expires_at = reset.expires_at;
+
+        self.password_reset_repository.create(reset).await?;
+
+        let instructions = match self.policy.url.is_empty() {
+            true => format!(
+                "use this code to choose a new password:\n\n{}",
+                token.decrypted
+            ),
+            false => {
+                let separator = match self.policy.url.contains('?') {
+                    true => '&',
+                    false => '?',
+                };
+                format!(
+                    "open the link below to choose a new password:\n\n{}{}token={}",
+                    self.policy.url, separator, token.decrypted
+                )
+            }
+        };
+
+        let mail = Mail {
+            to: user.email,
+            subject: "Reset your password".to_string(),
+            body: format!(
+                "Hi {},\n\nsomeone asked to reset the password of your account, if it was not \
+                 you there is nothing to do. Otherwise {}\n\nIt can be used once until {}.\n",
+                user.name,
+                instructions,
+                expires_at.format("%Y-%m-%d %H:%M UTC"),
+            ),
+        };
+
+        self.mailer.send(mail).await.map_err(|err| {
+            tracing::error!("failed send password reset to {} : {}", user.id, err);
+            PasswordResetError::GeneralError
+        })
+    }
+
+    // the token is used up even when it turns out to be expired, a new password signs the user
+    // out everywhere and revokes the access tokens issued before
+    pub async fn reset_password(
+        &self,
+        dto: PasswordResetRequest,
+    ) -> Result<(), PasswordResetError> {
+        let token =
+            Token::new(&self.secret, &dto.token).map_err(|_| PasswordResetError::GeneralError)?;
+
+        let reset = self
+            .password_reset_repository
+            .take(&token.encrypted)
+            .await?
+            .filter(|reset| !reset.is_expired(Utc::now()))
+            .ok_or(PasswordResetError::InvalidToken)?;
+
+        // the primary has the latest password, the whole user is written back below
+        let mut user = match replica::scope(true, self.user_repository.find_by_id(reset.user_id))
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) | Err(ModelError::NotFound) => return Err(PasswordResetError::InvalidToken),
+            Err(err) => return Err(PasswordResetError::from(err)),
+        };
+
+        user.password = User::hash_password(&dto.password).map_err(|err| {
+            tracing::error!("failed hash password : {}", err);
+            PasswordResetError::GeneralError
+        })?;
+        user.token = None;
+        user.password_changed_at = Some(Utc::now());
+        // the mail reached its owner, so the address is confirmed as well
+        user.email_verified_at.get_or_insert_with(Utc::now);
+        user.updated_at = Utc::now();
+
+        self.user_repository
+            .update(&user)
+            .await
+            .map(|_| ())
+            .map_err(PasswordResetError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::password_reset::{
+            dto::{ForgotPasswordRequest, PasswordResetRequest},
+            error::PasswordResetError,
+            usecase::{PasswordResetPolicy, PasswordResetUseCase},
+        },
+        domain::{
+            mail::mailer::MockMailer,
+            password_reset::{model::PasswordReset, repository::MockPasswordResetRepository},
+            shared::error::ModelError,
+            user::{model::User, repository::MockUserRepository},
+        },
+        infrastructure::security::token::Token,
+    };
+
+    const SECRET: &str = "secret";
+
+    fn policy() -> PasswordResetPolicy {
+        PasswordResetPolicy {
+            ttl: Duration::from_secs(60),
+            url: "http://localhost/reset".to_string(),
+        }
+    }
+
+    fn user(id: Uuid) -> User {
+        User {
+            id,
+            name: "test".to_string(),
+            email: "test@domain.com".to_string(),
+            password: User::hash_password("demo123").unwrap(),
+            token: Some("refresh".to_string()),
+            email_verified_at: None,
+            password_changed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn reset(user_id: Uuid, token: &str, expires_in: chrono::Duration) -> PasswordReset {
+        PasswordReset {
+            token_hash: Token::new(SECRET, token).unwrap().encrypted,
+            user_id,
+            expires_at: Utc::now() + expires_in,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn forgot_password_unknown_email_sends_nothing() {
+        let mut users = MockUserRepository::new();
+        let mut resets = MockPasswordResetRepository::new();
+        let mut mailer = MockMailer::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
+        resets.expect_create().never();
+        mailer.expect_send().never();
+
+        let usecase =
+            PasswordResetUseCase::new(users, resets, mailer, policy(), SECRET.to_string());
+        let result = usecase
+            .forgot_password(ForgotPasswordRequest {
+                email: "nobody@domain.com".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn forgot_password_mails_token_and_stores_its_hash() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut resets = MockPasswordResetRepository::new();
+        let mut mailer = MockMailer::new();
+
+        users
+            .expect_find_by_email()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        let stored = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+        let hash = stored.clone();
+        resets.expect_create().return_once(move |reset| {
+            *hash.lock().unwrap() = reset.token_hash;
+            Box::pin(async { Ok(()) })
+        });
+
+        let sent = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+        let body = sent.clone();
+        mailer.expect_send().return_once(move |mail| {
+            *body.lock().unwrap() = mail.body;
+            Box::pin(async { Ok(()) })
+        });
+
+        let usecase =
+            PasswordResetUseCase::new(users, resets, mailer, policy(), SECRET.to_string());
+        let result = usecase
+            .forgot_password(ForgotPasswordRequest {
+                email: "TEST@domain.com".to_string(),
+            })
+            .await;
+        assert!(result.is_ok());
+
+        let body = sent.lock().unwrap().clone();
+        let token = body
+            .split("http://localhost/reset?token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap();
+        let hash = stored.lock().unwrap().clone();
+
+        assert!(!body.contains(&hash), "the stored hash must not be mailed");
+        assert_eq!(Token::new(SECRET, token).unwrap().encrypted, hash);
+    }
+
+    #[tokio::test]
+    async fn reset_password_revokes_sessions() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut resets = MockPasswordResetRepository::new();
+
+        resets.expect_take().return_once(move |_| {
+            Box::pin(async move { Ok(Some(reset(id, "token", chrono::Duration::minutes(1)))) })
+        });
+
+        users
+            .expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        users
+            .expect_update()
+            .withf(|user| {
+                user.token.is_none()
+                    && user.password_changed_at.is_some()
+                    && user.email_verified_at.is_some()
+                    && user.verify_password("new-secret".to_string())
+            })
+            .times(1)
+            .return_once(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let usecase = PasswordResetUseCase::new(
+            users,
+            resets,
+            MockMailer::new(),
+            policy(),
+            SECRET.to_string(),
+        );
+        let result = usecase
+            .reset_password(PasswordResetRequest {
+                token: "token".to_string(),
+                password: "new-secret".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reset_password_expired_token() {
+        let id = Uuid::new_v4();
+        let mut users = MockUserRepository::new();
+        let mut resets = MockPasswordResetRepository::new();
+
+        resets.expect_take().return_once(move |_| {
+            Box::pin(async move { Ok(Some(reset(id, "token", chrono::Duration::minutes(-1)))) })
+        });
+        users.expect_update().never();
+
+        let usecase = PasswordResetUseCase::new(
+            users,
+            resets,
+            MockMailer::new(),
+            policy(),
+            SECRET.to_string(),
+        );
+        let result = usecase
+            .reset_password(PasswordResetRequest {
+                token: "token".to_string(),
+                password: "new-secret".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(PasswordResetError::InvalidToken)));
+    }
+}
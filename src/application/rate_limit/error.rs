@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum RateLimitError {
+    // too many attempts, the next one is allowed after `retry_after`
+    Exceeded { retry_after: Duration },
+    GeneralError,
+}
+
+impl From<ModelError> for RateLimitError {
+    fn from(_: ModelError) -> Self {
+        Self::GeneralError
+    }
+}
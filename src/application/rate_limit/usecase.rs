@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::{
+    application::rate_limit::error::RateLimitError,
+    domain::rate_limit::repository::RateLimitRepository,
+};
+
+// attempts allowed per window, by client address and by the email an attempt targets
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitPolicy {
+    pub per_ip: u32,
+    pub per_email: u32,
+    pub window: Duration,
+}
+
+pub struct RateLimitUseCase<R: RateLimitRepository + Send + Sync> {
+    rate_limit_repository: R,
+    policy: RateLimitPolicy,
+}
+
+impl<R: RateLimitRepository> RateLimitUseCase<R> {
+    pub fn new(rate_limit: R, policy: RateLimitPolicy) -> Self {
+        Self {
+            rate_limit_repository: rate_limit,
+            policy,
+        }
+    }
+
+    // count an attempt at `action`, the email is only counted once the address is within its limit
+    pub async fn attempt(
+        &self,
+        action: &str,
+        ip: &str,
+        email: Option<&str>,
+    ) -> Result<(), RateLimitError> {
+        self.hit(format!("{}:ip:{}", action, ip), self.policy.per_ip)
+            .await?;
+
+        let email = email
+            .map(|email| email.trim().to_lowercase())
+            .filter(|email| !email.is_empty());
+
+        if let Some(email) = email {
+            self.hit(format!("{}:email:{}", action, email), self.policy.per_email)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn purge_expired(&self) -> Result<u64, RateLimitError> {
+        self.rate_limit_repository
+            .purge_expired()
+            .await
+            .map_err(RateLimitError::from)
+    }
+
+    async fn hit(&self, key: String, limit: u32) -> Result<(), RateLimitError> {
+        let window =
+            chrono::Duration::from_std(self.policy.window).unwrap_or(chrono::Duration::minutes(1));
+
+        let counter = self
+            .rate_limit_repository
+            .hit(&key, window)
+            .await
+            .map_err(RateLimitError::from)?;
+
+        if i64::from(counter.hits) <= i64::from(limit) {
+            return Ok(());
+        }
+
+        tracing::warn!("rate limit exceeded for {}", counter.key);
+
+        // whole seconds for `Retry-After`, never telling the client to retry right away
+        let remaining = (counter.expires_at - Utc::now()).num_seconds().max(1);
+
+        Err(RateLimitError::Exceeded {
+            retry_after: Duration::from_secs(remaining as u64),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use crate::{
+        application::rate_limit::{
+            error::RateLimitError,
+            usecase::{RateLimitPolicy, RateLimitUseCase},
+        },
+        domain::{
+            rate_limit::{model::RateLimitCounter, repository::MockRateLimitRepository},
+            shared::error::ModelError,
+        },
+    };
+
+    const POLICY: RateLimitPolicy = RateLimitPolicy {
+        per_ip: 10,
+        per_email: 3,
+        window: Duration::from_secs(60),
+    };
+
+    fn counter(key: &str, hits: i32) -> RateLimitCounter {
+        RateLimitCounter {
+            key: key.to_string(),
+            hits,
+            expires_at: Utc::now() + chrono::Duration::seconds(30),
+        }
+    }
+
+    #[tokio::test]
+    async fn attempt_within_limits() {
+        let mut repo = MockRateLimitRepository::new();
+
+        repo.expect_hit()
+            .withf(|key, window| {
+                key == "login:ip:10.0.0.1" && *window == chrono::Duration::minutes(1)
+            })
+            .return_once(|key, _| {
+                let counter = counter(key, 10);
+                Box::pin(async move { Ok(counter) })
+            });
+        repo.expect_hit()
+            .withf(|key, _| key == "login:email:alice@domain.com")
+            .return_once(|key, _| {
+                let counter = counter(key, 1);
+                Box::pin(async move { Ok(counter) })
+            });
+
+        let usecase = RateLimitUseCase::new(repo, POLICY);
+
+        let result = usecase
+            .attempt("login", "10.0.0.1", Some(" Alice@Domain.com "))
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn attempt_over_ip_limit_skips_email() {
+        let mut repo = MockRateLimitRepository::new();
+
+        repo.expect_hit().times(1).return_once(|key, _| {
+            let counter = counter(key, 11);
+            Box::pin(async move { Ok(counter) })
+        });
+
+        let usecase = RateLimitUseCase::new(repo, POLICY);
+
+        let result = usecase
+            .attempt("login", "10.0.0.1", Some("alice@domain.com"))
+            .await;
+
+        match result.unwrap_err() {
+            RateLimitError::Exceeded { retry_after } => {
+                assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_secs(30))
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn attempt_over_email_limit() {
+        let mut repo = MockRateLimitRepository::new();
+
+        repo.expect_hit()
+            .withf(|key, _| key.starts_with("register:ip:"))
+            .return_once(|key, _| {
+                let counter = counter(key, 1);
+                Box::pin(async move { Ok(counter) })
+            });
+        repo.expect_hit()
+            .withf(|key, _| key.starts_with("register:email:"))
+            .return_once(|key, _| {
+                let counter = counter(key, 4);
+                Box::pin(async move { Ok(counter) })
+            });
+
+        let usecase = RateLimitUseCase::new(repo, POLICY);
+
+        let result = usecase
+            .attempt("register", "10.0.0.1", Some("alice@domain.com"))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            RateLimitError::Exceeded { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn attempt_fails_with_store() {
+        let mut repo = MockRateLimitRepository::new();
+
+        repo.expect_hit()
+            .return_once(|_, _| Box::pin(async { Err(ModelError::Database("down".to_string())) }));
+
+        let usecase = RateLimitUseCase::new(repo, POLICY);
+
+        let result = usecase.attempt("refresh", "10.0.0.1", None).await;
+
+        assert!(matches!(result.unwrap_err(), RateLimitError::GeneralError));
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::reminder::model::Reminder;
+
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+pub struct CreateReminderRequest {
+    /// absolute time of the reminder, cannot be combined with `minutes_before_due`
+    #[serde(default)]
+    pub remind_at: Option<DateTime<Utc>>,
+
+    /// fire this many minutes before the todo is due, follows later due date changes
+    #[serde(default)]
+    #[validate(range(min = 0, max = 525600, message = "must be between 0 and 525600"))]
+    pub minutes_before_due: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReminderResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub minutes_before_due: Option<i32>,
+    pub fire_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReminderResponse {
+    pub fn new(value: Reminder, due_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            fire_at: value.fire_at(due_at),
+            remind_at: value.remind_at,
+            minutes_before_due: value.minutes_before_due,
+            delivered_at: value.delivered_at,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum ReminderError {
+    NotFound,
+    InvalidTrigger,
+    MissingDueDate,
+    GeneralError,
+}
+
+impl From<ModelError> for ReminderError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            _ => Self::GeneralError,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    application::reminder::{
+        dto::{CreateReminderRequest, ReminderResponse},
+        error::ReminderError,
+    },
+    domain::{
+        reminder::{model::Reminder, repository::ReminderRepository},
+        todo::repository::TodoRepository,
+    },
+};
+
+pub struct ReminderUseCase<R, T>
+where
+    R: ReminderRepository + Send + Sync,
+    T: TodoRepository + Send + Sync,
+{
+    reminder_repository: R,
+    todo_repository: T,
+}
+
+impl<R: ReminderRepository, T: TodoRepository> ReminderUseCase<R, T> {
+    pub fn new(reminder: R, todo: T) -> Self {
+        Self {
+            reminder_repository: reminder,
+            todo_repository: todo,
+        }
+    }
+
+    pub async fn create_reminder(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        dto: CreateReminderRequest,
+    ) -> Result<ReminderResponse, ReminderError> {
+        let todo = self
+            .todo_repository
+            .find_by_id(user_id, todo_id)
+            .await
+            .map_err(ReminderError::from)?
+            .ok_or(ReminderError::NotFound)?;
+
+        match (dto.remind_at, dto.minutes_before_due) {
+            (Some(_), None) => {}
+            (None, Some(_)) if todo.due_at.is_none() => return Err(ReminderError::MissingDueDate),
+            (None, Some(_)) => {}
+            _ => return Err(ReminderError::InvalidTrigger),
+        }
+
+        let reminder = Reminder {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id: todo.id,
+            remind_at: dto.remind_at,
+            minutes_before_due: dto.minutes_before_due,
+            delivered_at: None,
+            created_at: Utc::now(),
+        };
+
+        self.reminder_repository
+            .create(reminder)
+            .await
+            .map_err(ReminderError::from)
+            .map(|reminder| ReminderResponse::new(reminder, todo.due_at))
+    }
+
+    pub async fn find_reminders(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<ReminderResponse>, ReminderError> {
+        let todo = self
+            .todo_repository
+            .find_by_id(user_id, todo_id)
+            .await
+            .map_err(ReminderError::from)?
+            .ok_or(ReminderError::NotFound)?;
+
+        self.reminder_repository
+            .find_by_todo(user_id, todo.id)
+            .await
+            .map_err(ReminderError::from)
+            .map(|reminders| {
+                reminders
+                    .into_iter()
+                    .map(|reminder| ReminderResponse::new(reminder, todo.due_at))
+                    .collect::<Vec<ReminderResponse>>()
+            })
+    }
+
+    pub async fn delete_reminder(&self, user_id: Uuid, id: Uuid) -> Result<(), ReminderError> {
+        let reminder = self
+            .reminder_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(ReminderError::from)?
+            .ok_or(ReminderError::NotFound)?;
+
+        self.reminder_repository
+            .delete(reminder.id)
+            .await
+            .map_err(ReminderError::from)
+    }
+
+    // deliver every due reminder in batches, returns how many notifications were created
+    pub async fn dispatch_due(&self, batch: i64) -> Result<usize, ReminderError> {
+        let mut delivered = 0;
+
+        loop {
+            let fired = self
+                .reminder_repository
+                .fire_due(batch)
+                .await
+                .map_err(ReminderError::from)?
+                .len();
+
+            delivered += fired;
+
+            if (fired as i64) < batch {
+                return Ok(delivered);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        application::reminder::{
+            dto::CreateReminderRequest, error::ReminderError, usecase::ReminderUseCase,
+        },
+        domain::{
+            notification::model::Notification,
+            reminder::repository::MockReminderRepository,
+            todo::{model::Todo, repository::MockTodoRepository},
+        },
+    };
+
+    fn todo(user_id: Uuid, id: Uuid, due_at: Option<chrono::DateTime<Utc>>) -> Todo {
+        Todo {
+            id,
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn notification() -> Notification {
+        Notification {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            todo_id: None,
+            reminder_id: None,
+            title: "test".to_string(),
+            message: "Reminder".to_string(),
+            read_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_relative_reminder() {
+        let mut repo = MockReminderRepository::new();
+        let mut todos = MockTodoRepository::new();
+        let due_at = Utc::now() + Duration::days(1);
+
+        todos.expect_find_by_id().return_once(move |uid, tid| {
+            Box::pin(async move { Ok(Some(todo(uid, tid, Some(due_at)))) })
+        });
+
+        repo.expect_create()
+            .withf(|r| r.remind_at.is_none() && r.minutes_before_due == Some(30))
+            .return_once(|r| Box::pin(async move { Ok(r) }));
+
+        let usecase = ReminderUseCase::new(repo, todos);
+
+        let dto = CreateReminderRequest {
+            remind_at: None,
+            minutes_before_due: Some(30),
+        };
+
+        let reminder = usecase
+            .create_reminder(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await
+            .unwrap();
+
+        assert_eq!(reminder.fire_at, Some(due_at - Duration::minutes(30)));
+    }
+
+    #[tokio::test]
+    async fn create_relative_reminder_without_due_date() {
+        let mut todos = MockTodoRepository::new();
+
+        todos
+            .expect_find_by_id()
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid, None))) }));
+
+        let usecase = ReminderUseCase::new(MockReminderRepository::new(), todos);
+
+        let dto = CreateReminderRequest {
+            remind_at: None,
+            minutes_before_due: Some(30),
+        };
+
+        let result = usecase
+            .create_reminder(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ReminderError::MissingDueDate));
+    }
+
+    #[tokio::test]
+    async fn create_reminder_with_both_triggers() {
+        let mut todos = MockTodoRepository::new();
+
+        todos
+            .expect_find_by_id()
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid, None))) }));
+
+        let usecase = ReminderUseCase::new(MockReminderRepository::new(), todos);
+
+        let dto = CreateReminderRequest {
+            remind_at: Some(Utc::now()),
+            minutes_before_due: Some(30),
+        };
+
+        let result = usecase
+            .create_reminder(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ReminderError::InvalidTrigger));
+    }
+
+    #[tokio::test]
+    async fn dispatch_due_drains_batches() {
+        let mut repo = MockReminderRepository::new();
+        let mut calls = 0;
+
+        repo.expect_fire_due().times(3).returning(move |batch| {
+            calls += 1;
+            let fired = if calls < 3 { batch } else { 1 };
+            let notifications = (0..fired).map(|_| notification()).collect::<Vec<_>>();
+            Box::pin(async move { Ok(notifications) })
+        });
+
+        let usecase = ReminderUseCase::new(repo, MockTodoRepository::new());
+
+        assert_eq!(usecase.dispatch_due(2).await.unwrap(), 5);
+    }
+}
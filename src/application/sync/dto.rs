@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::todo::dto::TodoResponse,
+    domain::{
+        event::model::{TodoEvent, TodoEventKind},
+        todo::model::Todo,
+    },
+};
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct SyncQuery {
+    /// cursor returned by the previous sync, omit it for a full snapshot
+    pub since: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncChange {
+    pub todo_id: Uuid,
+    /// tombstone of a deleted todo, `todo` is empty
+    pub deleted: bool,
+    pub todo: Option<TodoResponse>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<Todo> for SyncChange {
+    fn from(value: Todo) -> Self {
+        Self {
+            todo_id: value.id,
+            deleted: false,
+            changed_at: value.updated_at,
+            todo: Some(TodoResponse::from(value)),
+        }
+    }
+}
+
+impl From<TodoEvent> for SyncChange {
+    fn from(value: TodoEvent) -> Self {
+        let deleted = value.kind == TodoEventKind::Deleted;
+
+        Self {
+            todo_id: value.todo_id,
+            deleted,
+            todo: match deleted {
+                true => None,
+                false => serde_json::from_value::<Todo>(value.payload)
+                    .ok()
+                    .map(TodoResponse::from),
+            },
+            changed_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncResponse {
+    pub changes: Vec<SyncChange>,
+    /// pass as `since` on the next sync, event ids become visible in commit order so no change
+    /// committed later can land behind it
+    pub cursor: i64,
+    /// more changes are waiting after `cursor`
+    pub has_more: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncOperation {
+    Upsert,
+    Delete,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SyncMutation {
+    /// client generated id for new todos
+    pub id: Uuid,
+
+    pub op: SyncOperation,
+
+    /// when the change was made on the client, later than now counts as now
+    pub changed_at: DateTime<Utc>,
+
+    #[validate(length(min = 1, message = "title is required"))]
+    pub title: Option<String>,
+
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+
+    pub is_completed: Option<bool>,
+
+    #[validate(range(min = 0, max = 3, message = "priority must be between 0 and 3"))]
+    pub priority: Option<i16>,
+
+    pub tags: Option<Vec<String>>,
+
+    #[validate(length(min = 1, max = 100, message = "project must be 1 to 100 characters"))]
+    pub project: Option<String>,
+
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+/// Mutations are applied oldest `changed_at` first, each in its own transaction. Conflicts are
+/// resolved per todo, not per field: a mutation carries only the fields it changed and wins
+/// over the server copy when its `changed_at` is later than the copy's `updated_at`, ties keep
+/// the server copy. A losing mutation is dropped as a whole, even when it touched other fields
+/// than the newer server change, and comes back as `stale` with the server copy so the client
+/// can reapply it on top. A delete older than the latest write is stale, and a todo that was
+/// deleted is never brought back by an upsert.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SyncRequest {
+    #[validate(length(max = 500), nested)]
+    /// at most 500 per request
+    pub mutations: Vec<SyncMutation>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MutationStatus {
+    Applied,
+    /// the server copy is newer, `todo` holds it
+    Stale,
+    /// the todo was deleted on the server
+    Deleted,
+    /// the mutation can't be applied, e.g. a new todo without a title
+    Rejected,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MutationResult {
+    pub id: Uuid,
+    pub status: MutationStatus,
+    /// server copy after the mutation, empty once deleted
+    pub todo: Option<TodoResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncResult {
+    /// one result per mutation, in request order
+    pub results: Vec<MutationResult>,
+}
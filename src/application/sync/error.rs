@@ -0,0 +1,12 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum SyncError {
+    GeneralError,
+}
+
+impl From<ModelError> for SyncError {
+    fn from(_: ModelError) -> Self {
+        Self::GeneralError
+    }
+}
@@ -0,0 +1,526 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        sync::{
+            dto::{
+                MutationResult, MutationStatus, SyncChange, SyncMutation, SyncOperation,
+                SyncRequest, SyncResponse, SyncResult,
+            },
+            error::SyncError,
+        },
+        todo::dto::TodoResponse,
+    },
+    domain::{
+        event::{
+            model::{TodoEvent, TodoEventKind},
+            repository::EventRepository,
+        },
+        shared::{
+            error::ModelError,
+            transaction::{TransactionManager, UnitOfWork},
+        },
+        todo::{model::Todo, repository::TodoRepository},
+    },
+};
+
+pub const PULL_LIMIT: i64 = 500;
+
+pub struct SyncUseCase<T, E, X>
+where
+    T: TodoRepository + Send + Sync,
+    E: EventRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+{
+    todo_repository: T,
+    event_repository: E,
+    transactions: X,
+}
+
+impl<T: TodoRepository, E: EventRepository, X: TransactionManager> SyncUseCase<T, E, X> {
+    pub fn new(todo: T, event: E, transactions: X) -> Self {
+        Self {
+            todo_repository: todo,
+            event_repository: event,
+            transactions,
+        }
+    }
+
+    // without a cursor the client gets every todo it owns, otherwise the latest change of each
+    // todo touched since the cursor, read from the event log
+    pub async fn pull(&self, user_id: Uuid, since: Option<i64>) -> Result<SyncResponse, SyncError> {
+        let Some(after) = since.filter(|cursor| *cursor > 0) else {
+            // the cursor is taken first, a change racing the snapshot is sent again next time
+            let cursor = self
+                .event_repository
+                .last_id()
+                .await
+                .map_err(SyncError::from)?;
+
+            let todos = self
+                .todo_repository
+                .find_all_including_snoozed(user_id)
+                .await
+                .map_err(SyncError::from)?;
+
+            return Ok(SyncResponse {
+                changes: todos.into_iter().map(SyncChange::from).collect(),
+                cursor,
+                has_more: false,
+            });
+        };
+
+        let events = self
+            .event_repository
+            .find_changes(user_id, after, PULL_LIMIT)
+            .await
+            .map_err(SyncError::from)?;
+
+        let cursor = events.iter().map(|event| event.id).max().unwrap_or(after);
+
+        let has_more = !self
+            .event_repository
+            .find_since(user_id, cursor, 1)
+            .await
+            .map_err(SyncError::from)?
+            .is_empty();
+
+        Ok(SyncResponse {
+            changes: events.into_iter().map(SyncChange::from).collect(),
+            cursor,
+            has_more,
+        })
+    }
+
+    // see `SyncRequest` for the conflict rules
+    pub async fn push(&self, user_id: Uuid, dto: SyncRequest) -> Result<SyncResult, SyncError> {
+        let mut mutations = dto.mutations.into_iter().enumerate().collect::<Vec<_>>();
+        mutations.sort_by_key(|(index, mutation)| (mutation.changed_at, *index));
+
+        let mut results = Vec::with_capacity(mutations.len());
+        for (index, mutation) in mutations {
+            results.push((index, self.apply(user_id, mutation).await?));
+        }
+
+        results.sort_by_key(|(index, _)| *index);
+
+        Ok(SyncResult {
+            results: results.into_iter().map(|(_, result)| result).collect(),
+        })
+    }
+
+    async fn apply(
+        &self,
+        user_id: Uuid,
+        mutation: SyncMutation,
+    ) -> Result<MutationResult, SyncError> {
+        // the lookups, the change and its event run in one transaction
+        let uow = self.transactions.begin().await.map_err(SyncError::from)?;
+
+        let result = match Self::apply_mutation(uow.as_ref(), user_id, mutation).await {
+            Ok(result) => result,
+            Err(err) => {
+                if let Err(err) = uow.rollback().await {
+                    tracing::error!("failed to roll back sync mutation : {}", err);
+                }
+                return Err(err);
+            }
+        };
+
+        uow.commit().await.map_err(SyncError::from)?;
+
+        Ok(result)
+    }
+
+    async fn apply_mutation(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        mutation: SyncMutation,
+    ) -> Result<MutationResult, SyncError> {
+        let id = mutation.id;
+        let changed_at = mutation.changed_at.min(Utc::now());
+
+        let current = match uow.todos().find_by_id(user_id, id).await {
+            Ok(todo) => todo,
+            Err(ModelError::NotFound) => None,
+            Err(err) => return Err(SyncError::from(err)),
+        };
+
+        let result = |status: MutationStatus, todo: Option<Todo>| MutationResult {
+            id,
+            status,
+            todo: todo.map(TodoResponse::from),
+        };
+
+        let Some(mut todo) = current else {
+            if mutation.op == SyncOperation::Delete {
+                return Ok(result(MutationStatus::Applied, None));
+            }
+
+            let tombstone = uow
+                .events()
+                .find_latest(user_id, id)
+                .await
+                .map_err(SyncError::from)?
+                .is_some_and(|event| event.kind == TodoEventKind::Deleted);
+
+            if tombstone {
+                return Ok(result(MutationStatus::Deleted, None));
+            }
+
+            let Some(title) = mutation.title else {
+                return Ok(result(MutationStatus::Rejected, None));
+            };
+
+            let todo = Todo {
+                id,
+                user_id,
+                title,
+                description: mutation.description.unwrap_or_default(),
+                is_completed: mutation.is_completed.unwrap_or_default(),
+                state_id: None,
+                priority: mutation.priority.unwrap_or_default(),
+                tags: mutation.tags.unwrap_or_default(),
+                project: mutation.project,
+                due_at: mutation.due_at,
+                snoozed_until: None,
+                tracked_seconds: 0,
+                created_at: changed_at,
+                updated_at: changed_at,
+            };
+
+            return match uow.todos().create(todo).await {
+                Ok(todo) => {
+                    Self::record(uow, TodoEventKind::Created, &todo).await?;
+                    Ok(result(MutationStatus::Applied, Some(todo)))
+                }
+                // the id belongs to somebody else's todo
+                Err(ModelError::Conflict) => Ok(result(MutationStatus::Rejected, None)),
+                Err(err) => Err(SyncError::from(err)),
+            };
+        };
+
+        if changed_at <= todo.updated_at {
+            return Ok(result(MutationStatus::Stale, Some(todo)));
+        }
+
+        if mutation.op == SyncOperation::Delete {
+            uow.todos()
+                .delete(user_id, todo.id)
+                .await
+                .map_err(SyncError::from)?;
+
+            Self::record(uow, TodoEventKind::Deleted, &todo).await?;
+
+            return Ok(result(MutationStatus::Applied, None));
+        }
+
+        let toggled = mutation
+            .is_completed
+            .is_some_and(|is_completed| is_completed != todo.is_completed);
+
+        if toggled {
+            uow.todos()
+                .toggle(user_id, todo.id)
+                .await
+                .map_err(SyncError::from)?;
+        }
+
+        if let Some(title) = mutation.title {
+            todo.title = title;
+        }
+        if let Some(description) = mutation.description {
+            todo.description = description;
+        }
+        if let Some(priority) = mutation.priority {
+            todo.priority = priority;
+        }
+        if let Some(tags) = mutation.tags {
+            todo.tags = tags;
+        }
+        if mutation.project.is_some() {
+            todo.project = mutation.project;
+        }
+        if mutation.due_at.is_some() {
+            todo.due_at = mutation.due_at;
+        }
+
+        // the client's clock becomes the version later mutations are compared against
+        todo.updated_at = changed_at;
+
+        let todo = uow.todos().update(todo).await.map_err(SyncError::from)?;
+
+        let kind = match toggled {
+            true => TodoEventKind::Toggled,
+            false => TodoEventKind::Updated,
+        };
+        Self::record(uow, kind, &todo).await?;
+
+        Ok(result(MutationStatus::Applied, Some(todo)))
+    }
+
+    async fn record(
+        uow: &dyn UnitOfWork,
+        kind: TodoEventKind,
+        todo: &Todo,
+    ) -> Result<(), SyncError> {
+        uow.events()
+            .append(TodoEvent::new(kind, todo))
+            .await
+            .map(|_| ())
+            .map_err(SyncError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        application::sync::{
+            dto::{MutationStatus, SyncMutation, SyncOperation, SyncRequest},
+            usecase::{PULL_LIMIT, SyncUseCase},
+        },
+        domain::{
+            event::{
+                model::{TodoEvent, TodoEventKind},
+                repository::MockEventRepository,
+            },
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork, UnitOfWork},
+            },
+            todo::{model::Todo, repository::MockTodoRepository},
+        },
+    };
+
+    // one unit of work per mutation, handed out in order
+    fn transactions(uows: Vec<MockUnitOfWork>) -> MockTransactionManager {
+        let mut uows = uows.into_iter();
+        let mut transactions = MockTransactionManager::new();
+
+        transactions
+            .expect_begin()
+            .times(uows.len())
+            .returning(move || {
+                let uow = uows.next().unwrap();
+                Box::pin(async move { Ok(Box::new(uow) as Box<dyn UnitOfWork>) })
+            });
+
+        transactions
+    }
+
+    fn todo(user_id: Uuid, updated_at: DateTime<Utc>) -> Todo {
+        Todo {
+            id: Uuid::new_v4(),
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: updated_at,
+            updated_at,
+        }
+    }
+
+    fn mutation(id: Uuid, op: SyncOperation, changed_at: DateTime<Utc>) -> SyncMutation {
+        SyncMutation {
+            id,
+            op,
+            changed_at,
+            title: None,
+            description: None,
+            is_completed: None,
+            priority: None,
+            tags: None,
+            project: None,
+            due_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn pull_without_cursor_returns_snapshot() {
+        let mut todos = MockTodoRepository::new();
+        let mut events = MockEventRepository::new();
+        let user_id = Uuid::new_v4();
+
+        events
+            .expect_last_id()
+            .return_once(|| Box::pin(async { Ok(42) }));
+        todos
+            .expect_find_all_including_snoozed()
+            .return_once(|uid| {
+                let todos = vec![todo(uid, Utc::now()), todo(uid, Utc::now())];
+                Box::pin(async move { Ok(todos) })
+            });
+
+        let usecase = SyncUseCase::new(todos, events, MockTransactionManager::new());
+
+        let response = usecase.pull(user_id, None).await.unwrap();
+
+        assert_eq!(response.cursor, 42);
+        assert_eq!(response.changes.len(), 2);
+        assert!(!response.has_more);
+    }
+
+    #[tokio::test]
+    async fn pull_since_cursor_returns_tombstones() {
+        let mut events = MockEventRepository::new();
+        let user_id = Uuid::new_v4();
+        let kept = todo(user_id, Utc::now());
+        let deleted = todo(user_id, Utc::now());
+        let changes = vec![
+            TodoEvent {
+                id: 11,
+                ..TodoEvent::new(TodoEventKind::Updated, &kept)
+            },
+            TodoEvent {
+                id: 12,
+                ..TodoEvent::new(TodoEventKind::Deleted, &deleted)
+            },
+        ];
+
+        events
+            .expect_find_changes()
+            .withf(|_, after, limit| *after == 10 && *limit == PULL_LIMIT)
+            .return_once(move |_, _, _| Box::pin(async move { Ok(changes) }));
+        events
+            .expect_find_since()
+            .withf(|_, after, _| *after == 12)
+            .return_once(|_, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let usecase = SyncUseCase::new(
+            MockTodoRepository::new(),
+            events,
+            MockTransactionManager::new(),
+        );
+
+        let response = usecase.pull(user_id, Some(10)).await.unwrap();
+
+        assert_eq!(response.cursor, 12);
+        assert!(!response.changes[0].deleted);
+        assert_eq!(response.changes[0].todo.as_ref().unwrap().id, kept.id);
+        assert!(response.changes[1].deleted);
+        assert!(response.changes[1].todo.is_none());
+    }
+
+    #[tokio::test]
+    async fn push_resolves_last_writer_wins() {
+        let user_id = Uuid::new_v4();
+        let id = Uuid::new_v4();
+        let server = move |uid| Todo {
+            id,
+            title: "server".to_string(),
+            ..todo(uid, Utc::now() - Duration::minutes(5))
+        };
+
+        // mutations are applied oldest first, the older one loses against the server copy
+        let mut stale = MockUnitOfWork::new();
+        stale
+            .todos
+            .expect_find_by_id()
+            .return_once(move |uid, _| Box::pin(async move { Ok(Some(server(uid))) }));
+        stale.todos.expect_update().never();
+
+        let mut applied = MockUnitOfWork::new();
+        applied
+            .todos
+            .expect_find_by_id()
+            .return_once(move |uid, _| Box::pin(async move { Ok(Some(server(uid))) }));
+        applied
+            .todos
+            .expect_update()
+            .times(1)
+            .returning(|todo| Box::pin(async move { Ok(todo) }));
+        applied
+            .events
+            .expect_append()
+            .times(1)
+            .returning(|event| Box::pin(async move { Ok(event) }));
+        let committed = applied.finished.clone();
+
+        let usecase = SyncUseCase::new(
+            MockTodoRepository::new(),
+            MockEventRepository::new(),
+            transactions(vec![stale, applied]),
+        );
+
+        let older = SyncMutation {
+            title: Some("older".to_string()),
+            ..mutation(
+                id,
+                SyncOperation::Upsert,
+                Utc::now() - Duration::minutes(10),
+            )
+        };
+        let newer = SyncMutation {
+            description: Some("from phone".to_string()),
+            ..mutation(id, SyncOperation::Upsert, Utc::now() - Duration::minutes(1))
+        };
+
+        let result = usecase
+            .push(
+                user_id,
+                SyncRequest {
+                    mutations: vec![newer, older],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.results[0].status, MutationStatus::Applied);
+        let applied = result.results[0].todo.as_ref().unwrap();
+        assert_eq!(applied.title, "server");
+        assert_eq!(applied.description, "from phone");
+        assert_eq!(result.results[1].status, MutationStatus::Stale);
+        assert_eq!(*committed.lock().unwrap(), Some(Finished::Committed));
+    }
+
+    #[tokio::test]
+    async fn push_does_not_resurrect_deleted_todo() {
+        let mut uow = MockUnitOfWork::new();
+        let user_id = Uuid::new_v4();
+        let gone = todo(user_id, Utc::now());
+
+        uow.todos
+            .expect_find_by_id()
+            .return_once(|_, _| Box::pin(async { Err(ModelError::NotFound) }));
+        uow.todos.expect_create().never();
+        uow.events.expect_find_latest().return_once(move |_, _| {
+            let event = TodoEvent::new(TodoEventKind::Deleted, &gone);
+            Box::pin(async move { Ok(Some(event)) })
+        });
+
+        let usecase = SyncUseCase::new(
+            MockTodoRepository::new(),
+            MockEventRepository::new(),
+            uow.begins(),
+        );
+
+        let upsert = SyncMutation {
+            title: Some("offline edit".to_string()),
+            ..mutation(Uuid::new_v4(), SyncOperation::Upsert, Utc::now())
+        };
+
+        let result = usecase
+            .push(
+                user_id,
+                SyncRequest {
+                    mutations: vec![upsert],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.results[0].status, MutationStatus::Deleted);
+        assert!(result.results[0].todo.is_none());
+    }
+}
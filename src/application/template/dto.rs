@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::template::model::Template;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTemplateRequest {
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100, message = "name is required"))]
+    #[schema(example = "Weekly release")]
+    pub name: String,
+
+    #[serde(default)]
+    #[validate(length(min = 1, max = 255, message = "title is required"))]
+    #[schema(example = "Release {{date}}")]
+    pub title: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "priority must be between 0 and 3"))]
+    pub priority: i16,
+
+    #[serde(default)]
+    #[validate(range(min = 0, message = "due offset must be positive"))]
+    #[schema(example = 2880)]
+    pub due_offset_minutes: Option<i64>,
+
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTemplateRequest {
+    #[validate(length(min = 1, max = 100, message = "name is required"))]
+    pub name: String,
+
+    #[validate(length(min = 1, max = 255, message = "title is required"))]
+    pub title: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "priority must be between 0 and 3"))]
+    pub priority: i16,
+
+    #[serde(default)]
+    #[validate(range(min = 0, message = "due offset must be positive"))]
+    pub due_offset_minutes: Option<i64>,
+
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+pub struct InstantiateTemplateRequest {
+    /// extra values for `{{name}}` placeholders, these take precedence over the built in ones
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+
+    /// reference time for `{{date}}` and the due offset, defaults to now
+    #[serde(default)]
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub priority: i16,
+    pub due_offset_minutes: Option<i64>,
+    pub checklist: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Template> for TemplateResponse {
+    fn from(value: Template) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            title: value.title,
+            description: value.description,
+            tags: value.tags,
+            priority: value.priority,
+            due_offset_minutes: value.due_offset_minutes,
+            checklist: value.checklist,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound,
+    BussinerError,
+    GeneralError,
+}
+
+impl From<ModelError> for TemplateError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            ModelError::Database(_) => Self::GeneralError,
+            _ => Self::BussinerError,
+        }
+    }
+}
@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        template::{
+            dto::{
+                CreateTemplateRequest, InstantiateTemplateRequest, TemplateResponse,
+                UpdateTemplateRequest,
+            },
+            error::TemplateError,
+        },
+        todo::dto::TodoResponse,
+    },
+    domain::{
+        event::model::{TodoEvent, TodoEventKind},
+        shared::transaction::{TransactionManager, UnitOfWork},
+        template::{model::Template, repository::TemplateRepository},
+        todo::model::Todo,
+    },
+};
+
+pub struct TemplateUseCase<T, X>
+where
+    T: TemplateRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+{
+    template_repository: T,
+    transactions: X,
+}
+
+impl<T: TemplateRepository, X: TransactionManager> TemplateUseCase<T, X> {
+    pub fn new(template: T, transactions: X) -> Self {
+        Self {
+            template_repository: template,
+            transactions,
+        }
+    }
+
+    pub async fn create_template(
+        &self,
+        user_id: Uuid,
+        dto: CreateTemplateRequest,
+    ) -> Result<TemplateResponse, TemplateError> {
+        let template = Template {
+            id: Uuid::new_v4(),
+            user_id,
+            name: dto.name,
+            title: dto.title,
+            description: dto.description,
+            tags: dto.tags,
+            priority: dto.priority,
+            due_offset_minutes: dto.due_offset_minutes,
+            checklist: dto.checklist,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.template_repository
+            .create(template)
+            .await
+            .map_err(TemplateError::from)
+            .map(TemplateResponse::from)
+    }
+
+    pub async fn update_template(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateTemplateRequest,
+    ) -> Result<TemplateResponse, TemplateError> {
+        let mut template = self
+            .template_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TemplateError::from)?
+            .ok_or(TemplateError::NotFound)?;
+
+        template.name = dto.name;
+        template.title = dto.title;
+        template.description = dto.description;
+        template.tags = dto.tags;
+        template.priority = dto.priority;
+        template.due_offset_minutes = dto.due_offset_minutes;
+        template.checklist = dto.checklist;
+        template.updated_at = Utc::now();
+
+        self.template_repository
+            .update(template)
+            .await
+            .map_err(TemplateError::from)
+            .map(TemplateResponse::from)
+    }
+
+    pub async fn delete_template(&self, user_id: Uuid, id: Uuid) -> Result<(), TemplateError> {
+        let template = self
+            .template_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TemplateError::from)?
+            .ok_or(TemplateError::NotFound)?;
+
+        self.template_repository
+            .delete(template.id)
+            .await
+            .map_err(TemplateError::from)
+    }
+
+    pub async fn find_all(&self, user_id: Uuid) -> Result<Vec<TemplateResponse>, TemplateError> {
+        self.template_repository
+            .find_all(user_id)
+            .await
+            .map_err(TemplateError::from)
+            .map(|templates| {
+                templates
+                    .into_iter()
+                    .map(TemplateResponse::from)
+                    .collect::<Vec<TemplateResponse>>()
+            })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<TemplateResponse>, TemplateError> {
+        self.template_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TemplateError::from)
+            .map(|template| template.map(TemplateResponse::from))
+    }
+
+    // create the todo described by the template, every checklist item becomes its own todo
+    pub async fn instantiate(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: InstantiateTemplateRequest,
+    ) -> Result<Vec<TodoResponse>, TemplateError> {
+        let template = self
+            .template_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TemplateError::from)?
+            .ok_or(TemplateError::NotFound)?;
+
+        let start_at = dto.start_at.unwrap_or_else(Utc::now);
+        let due_at = template
+            .due_offset_minutes
+            .map(|offset| start_at + Duration::minutes(offset));
+
+        let mut vars = placeholders(start_at, due_at);
+        vars.extend(dto.variables);
+
+        let title = Template::render(&template.title, &vars);
+        let description = Template::render(&template.description, &vars);
+
+        let mut todos = Vec::with_capacity(template.checklist.len() + 1);
+        todos.push((title.clone(), description));
+        for item in &template.checklist {
+            todos.push((Template::render(item, &vars), title.clone()));
+        }
+
+        let todos = todos
+            .into_iter()
+            .map(|(title, description)| Todo {
+                id: Uuid::new_v4(),
+                user_id,
+                title,
+                description,
+                is_completed: false,
+                state_id: None,
+                priority: template.priority,
+                tags: template.tags.clone(),
+                project: None,
+                due_at,
+                snoozed_until: None,
+                tracked_seconds: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+            .collect();
+
+        // the whole checklist and its events are stored together or not at all
+        let uow = self
+            .transactions
+            .begin()
+            .await
+            .map_err(TemplateError::from)?;
+
+        let created = match Self::create_todos(uow.as_ref(), todos).await {
+            Ok(created) => created,
+            Err(err) => {
+                if let Err(err) = uow.rollback().await {
+                    tracing::error!("failed to roll back template instantiation : {}", err);
+                }
+                return Err(err);
+            }
+        };
+
+        uow.commit().await.map_err(TemplateError::from)?;
+
+        Ok(created)
+    }
+
+    async fn create_todos(
+        uow: &dyn UnitOfWork,
+        todos: Vec<Todo>,
+    ) -> Result<Vec<TodoResponse>, TemplateError> {
+        let mut created = Vec::with_capacity(todos.len());
+
+        for todo in todos {
+            let todo = uow
+                .todos()
+                .create(todo)
+                .await
+                .map_err(TemplateError::from)?;
+
+            uow.events()
+                .append(TodoEvent::new(TodoEventKind::Created, &todo))
+                .await
+                .map_err(TemplateError::from)?;
+
+            created.push(TodoResponse::from(todo));
+        }
+
+        Ok(created)
+    }
+}
+
+fn placeholders(start_at: DateTime<Utc>, due_at: Option<DateTime<Utc>>) -> HashMap<String, String> {
+    let mut vars = HashMap::from([
+        ("date".to_string(), start_at.format("%Y-%m-%d").to_string()),
+        ("time".to_string(), start_at.format("%H:%M").to_string()),
+        ("weekday".to_string(), start_at.format("%A").to_string()),
+        ("week".to_string(), start_at.iso_week().week().to_string()),
+        ("month".to_string(), start_at.format("%B").to_string()),
+        ("year".to_string(), start_at.year().to_string()),
+    ]);
+
+    if let Some(due_at) = due_at {
+        vars.insert(
+            "due_date".to_string(),
+            due_at.format("%Y-%m-%d").to_string(),
+        );
+    }
+
+    vars
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        application::template::{
+            dto::{CreateTemplateRequest, InstantiateTemplateRequest, UpdateTemplateRequest},
+            error::TemplateError,
+            usecase::TemplateUseCase,
+        },
+        domain::{
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork},
+            },
+            template::{model::Template, repository::MockTemplateRepository},
+        },
+    };
+
+    // a unit of work storing `todos` todos and their events
+    fn unit_of_work(todos: usize) -> MockUnitOfWork {
+        let mut uow = MockUnitOfWork::new();
+
+        uow.todos
+            .expect_create()
+            .times(todos)
+            .returning(|t| Box::pin(async move { Ok(t) }));
+        uow.events
+            .expect_append()
+            .times(todos)
+            .returning(|e| Box::pin(async move { Ok(e) }));
+
+        uow
+    }
+
+    fn template(user_id: Uuid, id: Uuid, checklist: Vec<String>) -> Template {
+        Template {
+            id,
+            user_id,
+            name: "release".to_string(),
+            title: "Release {{date}}".to_string(),
+            description: "Release for week {{week}} by {{owner}}".to_string(),
+            tags: vec!["release".to_string()],
+            priority: 2,
+            due_offset_minutes: Some(60 * 24),
+            checklist,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_template_success() {
+        let mut repo = MockTemplateRepository::new();
+
+        repo.expect_create()
+            .return_once(|t| Box::pin(async move { Ok(t) }));
+
+        let usecase = TemplateUseCase::new(repo, MockTransactionManager::new());
+
+        let dto = CreateTemplateRequest {
+            name: "onboarding".to_string(),
+            title: "Onboard {{name}}".to_string(),
+            description: String::new(),
+            tags: vec!["hr".to_string()],
+            priority: 1,
+            due_offset_minutes: None,
+            checklist: vec!["Create account".to_string()],
+        };
+
+        let result = usecase.create_template(Uuid::new_v4(), dto).await;
+
+        assert!(result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn update_template_not_found() {
+        let mut repo = MockTemplateRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+
+        let usecase = TemplateUseCase::new(repo, MockTransactionManager::new());
+
+        let dto = UpdateTemplateRequest {
+            name: "onboarding".to_string(),
+            title: "Onboard".to_string(),
+            description: String::new(),
+            tags: vec![],
+            priority: 0,
+            due_offset_minutes: None,
+            checklist: vec![],
+        };
+
+        let result = usecase
+            .update_template(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await;
+
+        assert!(matches!(result.unwrap_err(), TemplateError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn delete_template_success() {
+        let mut repo = MockTemplateRepository::new();
+        let user_id = Uuid::new_v4();
+        let template_id = Uuid::new_v4();
+
+        repo.expect_find_by_id()
+            .withf(move |uid, tid| uid == &user_id && tid == &template_id)
+            .return_once(|uid, tid| {
+                let template = template(uid, tid, vec![]);
+                Box::pin(async move { Ok(Some(template)) })
+            });
+
+        repo.expect_delete()
+            .withf(move |tid| tid == &template_id)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let usecase = TemplateUseCase::new(repo, MockTransactionManager::new());
+
+        assert!(usecase.delete_template(user_id, template_id).await.is_ok())
+    }
+
+    #[tokio::test]
+    async fn instantiate_renders_placeholders() {
+        let mut repo = MockTemplateRepository::new();
+        let user_id = Uuid::new_v4();
+        let template_id = Uuid::new_v4();
+
+        repo.expect_find_by_id().return_once(|uid, tid| {
+            let template = template(uid, tid, vec![]);
+            Box::pin(async move { Ok(Some(template)) })
+        });
+
+        let usecase = TemplateUseCase::new(repo, unit_of_work(1).begins());
+
+        let dto = InstantiateTemplateRequest {
+            variables: HashMap::from([("owner".to_string(), "ops".to_string())]),
+            start_at: Some(Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap()),
+        };
+
+        let todos = usecase
+            .instantiate(user_id, template_id, dto)
+            .await
+            .unwrap();
+
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].title, "Release 2025-01-06");
+        assert_eq!(todos[0].description, "Release for week 2 by ops");
+        assert_eq!(todos[0].priority, 2);
+        assert_eq!(
+            todos[0].due_at,
+            Some(Utc.with_ymd_and_hms(2025, 1, 7, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn instantiate_creates_checklist_todos() {
+        let mut repo = MockTemplateRepository::new();
+
+        repo.expect_find_by_id().return_once(|uid, tid| {
+            let template = template(
+                uid,
+                tid,
+                vec!["Tag {{date}}".to_string(), "Publish notes".to_string()],
+            );
+            Box::pin(async move { Ok(Some(template)) })
+        });
+
+        let uow = unit_of_work(3);
+        let finished = uow.finished.clone();
+        let usecase = TemplateUseCase::new(repo, uow.begins());
+
+        let dto = InstantiateTemplateRequest {
+            variables: HashMap::new(),
+            start_at: Some(Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap()),
+        };
+
+        let todos = usecase
+            .instantiate(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await
+            .unwrap();
+
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos[1].title, "Tag 2025-01-06");
+        assert_eq!(todos[2].description, "Release 2025-01-06");
+        assert!(todos.iter().all(|t| t.tags == vec!["release".to_string()]));
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
+    }
+
+    #[tokio::test]
+    async fn instantiate_rolls_back_partial_checklist() {
+        let mut repo = MockTemplateRepository::new();
+        let mut uow = MockUnitOfWork::new();
+
+        repo.expect_find_by_id().return_once(|uid, tid| {
+            let template = template(uid, tid, vec!["Tag".to_string(), "Publish".to_string()]);
+            Box::pin(async move { Ok(Some(template)) })
+        });
+
+        // the last checklist item fails after the todo and the first item were stored
+        let mut created = 0;
+        uow.todos.expect_create().times(3).returning(move |t| {
+            created += 1;
+            let result = match created {
+                3 => Err(ModelError::Database("connection reset".to_string())),
+                _ => Ok(t),
+            };
+            Box::pin(async move { result })
+        });
+        uow.events
+            .expect_append()
+            .times(2)
+            .returning(|e| Box::pin(async move { Ok(e) }));
+
+        let finished = uow.finished.clone();
+        let usecase = TemplateUseCase::new(repo, uow.begins());
+
+        let result = usecase
+            .instantiate(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                InstantiateTemplateRequest::default(),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+
+    #[tokio::test]
+    async fn instantiate_template_not_found() {
+        let mut repo = MockTemplateRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(|_, _| Box::pin(async { Err(ModelError::NotFound) }));
+
+        let usecase = TemplateUseCase::new(repo, MockTransactionManager::new());
+
+        let result = usecase
+            .instantiate(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                InstantiateTemplateRequest::default(),
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), TemplateError::NotFound));
+    }
+}
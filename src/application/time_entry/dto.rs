@@ -0,0 +1,101 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::time_entry::model::TimeEntry;
+
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+pub struct StartTimerRequest {
+    #[serde(default)]
+    #[validate(length(max = 255))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTimeEntryRequest {
+    pub started_at: DateTime<Utc>,
+
+    pub ended_at: DateTime<Utc>,
+
+    #[serde(default)]
+    #[validate(length(max = 255))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTimeEntryRequest {
+    pub started_at: DateTime<Utc>,
+
+    /// leave empty to keep the timer running
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    #[validate(length(max = 255))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimeReportQuery {
+    /// start of the range, inclusive
+    pub from: DateTime<Utc>,
+
+    /// end of the range, exclusive
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeEntryResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: String,
+    pub duration_seconds: i64,
+}
+
+impl From<TimeEntry> for TimeEntryResponse {
+    fn from(value: TimeEntry) -> Self {
+        let duration_seconds = value.duration(Utc::now()).num_seconds();
+
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            started_at: value.started_at,
+            ended_at: value.ended_at,
+            note: value.note,
+            duration_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+pub struct DailyTime {
+    pub date: NaiveDate,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+pub struct TagTime {
+    pub tag: String,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+pub struct ProjectTime {
+    pub project: String,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeReportResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub total_seconds: i64,
+    pub by_day: Vec<DailyTime>,
+    pub by_tag: Vec<TagTime>,
+    pub by_project: Vec<ProjectTime>,
+}
@@ -0,0 +1,19 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum TimeEntryError {
+    NotFound,
+    TimerRunning,
+    InvalidRange,
+    GeneralError,
+}
+
+impl From<ModelError> for TimeEntryError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            ModelError::Conflict => Self::TimerRunning,
+            ModelError::Database(_) => Self::GeneralError,
+        }
+    }
+}
@@ -0,0 +1,689 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    application::time_entry::{
+        dto::{
+            CreateTimeEntryRequest, DailyTime, ProjectTime, StartTimerRequest, TagTime,
+            TimeEntryResponse, TimeReportResponse, UpdateTimeEntryRequest,
+        },
+        error::TimeEntryError,
+    },
+    domain::{
+        time_entry::{model::TimeEntry, repository::TimeEntryRepository},
+        todo::repository::TodoRepository,
+    },
+};
+
+// longest range a single report may cover
+const MAX_REPORT_DAYS: i64 = 366;
+
+pub struct TimeEntryUseCase<E, T>
+where
+    E: TimeEntryRepository + Send + Sync,
+    T: TodoRepository + Send + Sync,
+{
+    time_entry_repository: E,
+    todo_repository: T,
+}
+
+impl<E: TimeEntryRepository, T: TodoRepository> TimeEntryUseCase<E, T> {
+    pub fn new(time_entry: E, todo: T) -> Self {
+        Self {
+            time_entry_repository: time_entry,
+            todo_repository: todo,
+        }
+    }
+
+    pub async fn start_timer(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        dto: StartTimerRequest,
+    ) -> Result<TimeEntryResponse, TimeEntryError> {
+        self.ensure_todo(user_id, todo_id).await?;
+
+        if self
+            .time_entry_repository
+            .find_running(user_id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .is_some()
+        {
+            return Err(TimeEntryError::TimerRunning);
+        }
+
+        let now = Utc::now();
+        let entry = TimeEntry {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id,
+            started_at: now,
+            ended_at: None,
+            note: dto.note,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.time_entry_repository
+            .create(entry)
+            .await
+            .map_err(TimeEntryError::from)
+            .map(TimeEntryResponse::from)
+    }
+
+    pub async fn stop_timer(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<TimeEntryResponse, TimeEntryError> {
+        let mut entry = self
+            .time_entry_repository
+            .find_running(user_id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .filter(|entry| entry.todo_id == todo_id)
+            .ok_or(TimeEntryError::NotFound)?;
+
+        let now = Utc::now();
+        entry.ended_at = Some(now);
+        entry.updated_at = now;
+
+        self.time_entry_repository
+            .update(entry)
+            .await
+            .map_err(TimeEntryError::from)
+            .map(TimeEntryResponse::from)
+    }
+
+    pub async fn create_entry(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        dto: CreateTimeEntryRequest,
+    ) -> Result<TimeEntryResponse, TimeEntryError> {
+        if dto.ended_at <= dto.started_at {
+            return Err(TimeEntryError::InvalidRange);
+        }
+
+        self.ensure_todo(user_id, todo_id).await?;
+
+        let entry = TimeEntry {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id,
+            started_at: dto.started_at,
+            ended_at: Some(dto.ended_at),
+            note: dto.note,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.time_entry_repository
+            .create(entry)
+            .await
+            .map_err(TimeEntryError::from)
+            .map(TimeEntryResponse::from)
+    }
+
+    pub async fn update_entry(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateTimeEntryRequest,
+    ) -> Result<TimeEntryResponse, TimeEntryError> {
+        if dto
+            .ended_at
+            .is_some_and(|ended_at| ended_at <= dto.started_at)
+        {
+            return Err(TimeEntryError::InvalidRange);
+        }
+
+        let mut entry = self
+            .time_entry_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .ok_or(TimeEntryError::NotFound)?;
+
+        // reopening a finished entry starts its timer again, only one may run per user
+        if entry.ended_at.is_some()
+            && dto.ended_at.is_none()
+            && self
+                .time_entry_repository
+                .find_running(user_id)
+                .await
+                .map_err(TimeEntryError::from)?
+                .is_some_and(|running| running.id != entry.id)
+        {
+            return Err(TimeEntryError::TimerRunning);
+        }
+
+        entry.started_at = dto.started_at;
+        entry.ended_at = dto.ended_at;
+        entry.note = dto.note;
+        entry.updated_at = Utc::now();
+
+        self.time_entry_repository
+            .update(entry)
+            .await
+            .map_err(TimeEntryError::from)
+            .map(TimeEntryResponse::from)
+    }
+
+    pub async fn delete_entry(&self, user_id: Uuid, id: Uuid) -> Result<(), TimeEntryError> {
+        let entry = self
+            .time_entry_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .ok_or(TimeEntryError::NotFound)?;
+
+        self.time_entry_repository
+            .delete(user_id, entry.id)
+            .await
+            .map_err(TimeEntryError::from)
+    }
+
+    pub async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TimeEntryResponse>, TimeEntryError> {
+        self.ensure_todo(user_id, todo_id).await?;
+
+        self.time_entry_repository
+            .find_by_todo(user_id, todo_id)
+            .await
+            .map_err(TimeEntryError::from)
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .map(TimeEntryResponse::from)
+                    .collect::<Vec<TimeEntryResponse>>()
+            })
+    }
+
+    // aggregate tracked time per UTC day and per tag, an entry counts towards every tag of its todo
+    pub async fn report(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<TimeReportResponse, TimeEntryError> {
+        if to <= from || to - from > Duration::days(MAX_REPORT_DAYS) {
+            return Err(TimeEntryError::InvalidRange);
+        }
+
+        let entries = self
+            .time_entry_repository
+            .find_between(user_id, from, to)
+            .await
+            .map_err(TimeEntryError::from)?;
+
+        // snoozed todos still carry tracked time
+        let todos = self
+            .todo_repository
+            .find_all_including_snoozed(user_id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .into_iter()
+            .map(|todo| (todo.id, (todo.tags, todo.project)))
+            .collect::<HashMap<Uuid, (Vec<String>, Option<String>)>>();
+
+        let now = Utc::now();
+        let mut total_seconds = 0;
+        let mut by_day: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+        let mut by_tag: BTreeMap<String, i64> = BTreeMap::new();
+        let mut by_project: BTreeMap<String, i64> = BTreeMap::new();
+
+        for entry in &entries {
+            let seconds = entry.overlap(from, to, now).num_seconds();
+            if seconds == 0 {
+                continue;
+            }
+
+            total_seconds += seconds;
+
+            let mut cursor = entry.started_at.max(from);
+            let end = entry.ended_at.unwrap_or(now).min(to);
+            while cursor < end {
+                let day = cursor.date_naive();
+                let midnight = day
+                    .succ_opt()
+                    .and_then(|next| next.and_hms_opt(0, 0, 0))
+                    .map(|next| next.and_utc())
+                    .unwrap_or(end);
+                let segment_end = midnight.min(end);
+
+                *by_day.entry(day).or_default() += (segment_end - cursor).num_seconds();
+                cursor = segment_end;
+            }
+
+            let Some((tags, project)) = todos.get(&entry.todo_id) else {
+                continue;
+            };
+
+            for tag in tags {
+                *by_tag.entry(tag.clone()).or_default() += seconds;
+            }
+
+            // todos without a project only count towards the totals
+            if let Some(project) = project {
+                *by_project.entry(project.clone()).or_default() += seconds;
+            }
+        }
+
+        Ok(TimeReportResponse {
+            from,
+            to,
+            total_seconds,
+            by_day: by_day
+                .into_iter()
+                .map(|(date, seconds)| DailyTime { date, seconds })
+                .collect(),
+            by_tag: by_tag
+                .into_iter()
+                .map(|(tag, seconds)| TagTime { tag, seconds })
+                .collect(),
+            by_project: by_project
+                .into_iter()
+                .map(|(project, seconds)| ProjectTime { project, seconds })
+                .collect(),
+        })
+    }
+
+    async fn ensure_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<(), TimeEntryError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await
+            .map_err(TimeEntryError::from)?
+            .ok_or(TimeEntryError::NotFound)
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        application::time_entry::{
+            dto::{
+                CreateTimeEntryRequest, DailyTime, ProjectTime, StartTimerRequest, TagTime,
+                UpdateTimeEntryRequest,
+            },
+            error::TimeEntryError,
+            usecase::TimeEntryUseCase,
+        },
+        domain::{
+            shared::error::ModelError,
+            time_entry::{model::TimeEntry, repository::MockTimeEntryRepository},
+            todo::{model::Todo, repository::MockTodoRepository},
+        },
+    };
+
+    fn todo(user_id: Uuid, id: Uuid, tags: Vec<String>) -> Todo {
+        Todo {
+            id,
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags,
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn entry(
+        user_id: Uuid,
+        todo_id: Uuid,
+        started_at: DateTime<Utc>,
+        ended_at: Option<DateTime<Utc>>,
+    ) -> TimeEntry {
+        TimeEntry {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id,
+            started_at,
+            ended_at,
+            note: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn start_timer_success() {
+        let mut repo = MockTimeEntryRepository::new();
+        let mut todos = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        todos
+            .expect_find_by_id()
+            .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid, vec![]))) }));
+
+        repo.expect_find_running()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        repo.expect_create()
+            .withf(|e| e.ended_at.is_none())
+            .return_once(|e| Box::pin(async move { Ok(e) }));
+
+        let usecase = TimeEntryUseCase::new(repo, todos);
+
+        let result = usecase
+            .start_timer(user_id, todo_id, StartTimerRequest::default())
+            .await;
+
+        assert!(result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn start_timer_already_running() {
+        let mut repo = MockTimeEntryRepository::new();
+        let mut todos = MockTodoRepository::new();
+
+        todos
+            .expect_find_by_id()
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid, vec![]))) }));
+
+        repo.expect_find_running().return_once(|uid| {
+            let running = entry(uid, Uuid::new_v4(), Utc::now(), None);
+            Box::pin(async move { Ok(Some(running)) })
+        });
+
+        let usecase = TimeEntryUseCase::new(repo, todos);
+
+        let result = usecase
+            .start_timer(Uuid::new_v4(), Uuid::new_v4(), StartTimerRequest::default())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), TimeEntryError::TimerRunning));
+    }
+
+    #[tokio::test]
+    async fn start_timer_race_maps_conflict() {
+        let mut repo = MockTimeEntryRepository::new();
+        let mut todos = MockTodoRepository::new();
+
+        todos
+            .expect_find_by_id()
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid, vec![]))) }));
+
+        repo.expect_find_running()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        repo.expect_create()
+            .return_once(|_| Box::pin(async { Err(ModelError::Conflict) }));
+
+        let usecase = TimeEntryUseCase::new(repo, todos);
+
+        let result = usecase
+            .start_timer(Uuid::new_v4(), Uuid::new_v4(), StartTimerRequest::default())
+            .await;
+
+        assert!(matches!(result.unwrap_err(), TimeEntryError::TimerRunning));
+    }
+
+    #[tokio::test]
+    async fn stop_timer_success() {
+        let mut repo = MockTimeEntryRepository::new();
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        repo.expect_find_running().return_once(move |uid| {
+            let running = entry(uid, todo_id, Utc::now(), None);
+            Box::pin(async move { Ok(Some(running)) })
+        });
+
+        repo.expect_update()
+            .withf(|e| e.ended_at.is_some())
+            .return_once(|e| Box::pin(async move { Ok(e) }));
+
+        let usecase = TimeEntryUseCase::new(repo, MockTodoRepository::new());
+
+        assert!(usecase.stop_timer(user_id, todo_id).await.is_ok())
+    }
+
+    #[tokio::test]
+    async fn stop_timer_other_todo() {
+        let mut repo = MockTimeEntryRepository::new();
+
+        repo.expect_find_running().return_once(|uid| {
+            let running = entry(uid, Uuid::new_v4(), Utc::now(), None);
+            Box::pin(async move { Ok(Some(running)) })
+        });
+
+        let usecase = TimeEntryUseCase::new(repo, MockTodoRepository::new());
+
+        let result = usecase.stop_timer(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result.unwrap_err(), TimeEntryError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn create_entry_invalid_range() {
+        let usecase =
+            TimeEntryUseCase::new(MockTimeEntryRepository::new(), MockTodoRepository::new());
+
+        let dto = CreateTimeEntryRequest {
+            started_at: at(2, 10),
+            ended_at: at(2, 9),
+            note: String::new(),
+        };
+
+        let result = usecase
+            .create_entry(Uuid::new_v4(), Uuid::new_v4(), dto)
+            .await;
+
+        assert!(matches!(result.unwrap_err(), TimeEntryError::InvalidRange));
+    }
+
+    #[tokio::test]
+    async fn update_entry_success() {
+        let mut repo = MockTimeEntryRepository::new();
+        let user_id = Uuid::new_v4();
+        let entry_id = Uuid::new_v4();
+
+        repo.expect_find_by_id()
+            .withf(move |uid, eid| uid == &user_id && eid == &entry_id)
+            .return_once(|uid, _| {
+                let found = entry(uid, Uuid::new_v4(), at(2, 9), Some(at(2, 10)));
+                Box::pin(async move { Ok(Some(found)) })
+            });
+
+        repo.expect_update()
+            .withf(|e| e.ended_at == Some(at(2, 11)))
+            .return_once(|e| Box::pin(async move { Ok(e) }));
+
+        let usecase = TimeEntryUseCase::new(repo, MockTodoRepository::new());
+
+        let dto = UpdateTimeEntryRequest {
+            started_at: at(2, 9),
+            ended_at: Some(at(2, 11)),
+            note: "billing".to_string(),
+        };
+
+        let result = usecase.update_entry(user_id, entry_id, dto).await.unwrap();
+
+        assert_eq!(result.duration_seconds, 7200);
+    }
+
+    #[tokio::test]
+    async fn update_entry_reopen_while_timer_running() {
+        let mut repo = MockTimeEntryRepository::new();
+        let user_id = Uuid::new_v4();
+        let entry_id = Uuid::new_v4();
+
+        repo.expect_find_by_id().return_once(|uid, _| {
+            let found = entry(uid, Uuid::new_v4(), at(2, 9), Some(at(2, 10)));
+            Box::pin(async move { Ok(Some(found)) })
+        });
+        repo.expect_find_running().return_once(|uid| {
+            let running = entry(uid, Uuid::new_v4(), at(3, 9), None);
+            Box::pin(async move { Ok(Some(running)) })
+        });
+        repo.expect_update().never();
+
+        let usecase = TimeEntryUseCase::new(repo, MockTodoRepository::new());
+
+        let dto = UpdateTimeEntryRequest {
+            started_at: at(2, 9),
+            ended_at: None,
+            note: String::new(),
+        };
+
+        assert!(matches!(
+            usecase.update_entry(user_id, entry_id, dto).await,
+            Err(TimeEntryError::TimerRunning)
+        ));
+    }
+
+    #[tokio::test]
+    async fn report_aggregates_by_day_and_tag() {
+        let mut repo = MockTimeEntryRepository::new();
+        let mut todos = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+        let client = Uuid::new_v4();
+        let internal = Uuid::new_v4();
+
+        repo.expect_find_between().return_once(move |uid, _, _| {
+            let entries = vec![
+                // crosses midnight: 1h on the 2nd and 2h on the 3rd
+                entry(uid, client, at(2, 23), Some(at(3, 2))),
+                entry(uid, internal, at(3, 9), Some(at(3, 10))),
+                // starts before the range, only the last hour counts
+                entry(uid, client, at(1, 22), Some(at(2, 1))),
+            ];
+            Box::pin(async move { Ok(entries) })
+        });
+
+        todos
+            .expect_find_all_including_snoozed()
+            .return_once(move |uid| {
+                let found = vec![
+                    Todo {
+                        project: Some("acme".to_string()),
+                        ..todo(
+                            uid,
+                            client,
+                            vec!["acme".to_string(), "billable".to_string()],
+                        )
+                    },
+                    todo(uid, internal, vec![]),
+                ];
+                Box::pin(async move { Ok(found) })
+            });
+
+        let usecase = TimeEntryUseCase::new(repo, todos);
+
+        let report = usecase.report(user_id, at(2, 0), at(4, 0)).await.unwrap();
+
+        assert_eq!(report.total_seconds, 5 * 3600);
+        assert_eq!(
+            report.by_day,
+            vec![
+                DailyTime {
+                    date: NaiveDate::from_ymd_opt(2025, 3, 2).unwrap(),
+                    seconds: 2 * 3600,
+                },
+                DailyTime {
+                    date: NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+                    seconds: 3 * 3600,
+                },
+            ]
+        );
+        assert_eq!(
+            report.by_tag,
+            vec![
+                TagTime {
+                    tag: "acme".to_string(),
+                    seconds: 4 * 3600,
+                },
+                TagTime {
+                    tag: "billable".to_string(),
+                    seconds: 4 * 3600,
+                },
+            ]
+        );
+        assert_eq!(
+            report.by_project,
+            vec![ProjectTime {
+                project: "acme".to_string(),
+                seconds: 4 * 3600,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_counts_snoozed_todo() {
+        let mut repo = MockTimeEntryRepository::new();
+        let mut todos = MockTodoRepository::new();
+        let snoozed = Uuid::new_v4();
+
+        repo.expect_find_between().return_once(move |uid, _, _| {
+            let entries = vec![entry(uid, snoozed, at(2, 9), Some(at(2, 11)))];
+            Box::pin(async move { Ok(entries) })
+        });
+
+        todos
+            .expect_find_all_including_snoozed()
+            .return_once(move |uid| {
+                let found = vec![Todo {
+                    snoozed_until: Some(Utc::now() + chrono::Duration::days(1)),
+                    project: Some("acme".to_string()),
+                    ..todo(uid, snoozed, vec!["billable".to_string()])
+                }];
+                Box::pin(async move { Ok(found) })
+            });
+
+        let usecase = TimeEntryUseCase::new(repo, todos);
+
+        let report = usecase
+            .report(Uuid::new_v4(), at(2, 0), at(3, 0))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.by_tag,
+            vec![TagTime {
+                tag: "billable".to_string(),
+                seconds: 2 * 3600,
+            }]
+        );
+        assert_eq!(
+            report.by_project,
+            vec![ProjectTime {
+                project: "acme".to_string(),
+                seconds: 2 * 3600,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_invalid_range() {
+        let usecase =
+            TimeEntryUseCase::new(MockTimeEntryRepository::new(), MockTodoRepository::new());
+
+        let result = usecase.report(Uuid::new_v4(), at(4, 0), at(2, 0)).await;
+
+        assert!(matches!(result.unwrap_err(), TimeEntryError::InvalidRange));
+    }
+}
@@ -15,6 +15,20 @@ pub struct CreateTodoRequest {
     #[serde(default)]
     #[validate(length(max = 255))]
     pub description: String,
+
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "priority must be between 0 and 3"))]
+    pub priority: i16,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100, message = "project must be 1 to 100 characters"))]
+    pub project: Option<String>,
+
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -24,6 +38,20 @@ pub struct UpdateTodoRequest {
 
     #[validate(length(max = 255))]
     pub description: String,
+
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "priority must be between 0 and 3"))]
+    pub priority: i16,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100, message = "project must be 1 to 100 characters"))]
+    pub project: Option<String>,
+
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -32,6 +60,13 @@ pub struct TodoResponse {
     pub title: String,
     pub description: String,
     pub is_completed: bool,
+    pub state_id: Option<Uuid>,
+    pub priority: i16,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub tracked_seconds: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -43,8 +78,22 @@ impl From<Todo> for TodoResponse {
             title: value.title,
             description: value.description,
             is_completed: value.is_completed,
+            state_id: value.state_id,
+            priority: value.priority,
+            tags: value.tags,
+            project: value.project,
+            due_at: value.due_at,
+            snoozed_until: value.snoozed_until,
+            tracked_seconds: value.tracked_seconds,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
 }
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnoozeTodoRequest {
+    /// send null to clear the snooze
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+}
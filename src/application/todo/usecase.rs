@@ -1,22 +1,33 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
     application::todo::{
-        dto::{CreateTodoRequest, TodoResponse, UpdateTodoRequest},
+        dto::{CreateTodoRequest, SnoozeTodoRequest, TodoResponse, UpdateTodoRequest},
         error::TodoError,
     },
-    domain::todo::{model::Todo, repository::TodoRepository},
+    domain::{
+        event::model::{TodoEvent, TodoEventKind},
+        shared::transaction::{TransactionManager, UnitOfWork},
+        todo::{model::Todo, repository::TodoRepository},
+    },
 };
 
-pub struct TodoUseCase<T: TodoRepository + Send + Sync> {
+pub struct TodoUseCase<T, X>
+where
+    T: TodoRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+{
     todo_repository: T,
+    transactions: X,
 }
 
-impl<T: TodoRepository> TodoUseCase<T> {
-    pub fn new(todo: T) -> Self {
+// every change is stored together with its event or not at all
+impl<T: TodoRepository, X: TransactionManager> TodoUseCase<T, X> {
+    pub fn new(todo: T, transactions: X) -> Self {
         Self {
             todo_repository: todo,
+            transactions,
         }
     }
 
@@ -31,14 +42,22 @@ impl<T: TodoRepository> TodoUseCase<T> {
             title: dto.title,
             description: dto.description,
             is_completed: false,
+            state_id: None,
+            priority: dto.priority,
+            tags: dto.tags,
+            project: dto.project,
+            due_at: dto.due_at,
+            snoozed_until: None,
+            tracked_seconds: 0,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
 
-        self.todo_repository
-            .create(todo)
+        let uow = self.transactions.begin().await.map_err(TodoError::from)?;
+        let result = Self::apply_create(uow.as_ref(), todo).await;
+
+        Self::finish(uow, result, "create")
             .await
-            .map_err(TodoError::from)
             .map(TodoResponse::from)
     }
 
@@ -48,43 +67,40 @@ impl<T: TodoRepository> TodoUseCase<T> {
         id: Uuid,
         dto: UpdateTodoRequest,
     ) -> Result<TodoResponse, TodoError> {
-        let mut todo = self
-            .todo_repository
-            .find_by_id(user_id, id)
-            .await
-            .map_err(TodoError::from)?
-            .ok_or(TodoError::NotFound)?;
-
-        todo.title = dto.title;
-        todo.description = dto.description;
-        todo.updated_at = Utc::now();
+        let uow = self.transactions.begin().await.map_err(TodoError::from)?;
+        let result = Self::apply_update(uow.as_ref(), user_id, id, dto).await;
 
-        self.todo_repository
-            .update(todo)
+        Self::finish(uow, result, "update")
             .await
-            .map_err(TodoError::from)
             .map(TodoResponse::from)
     }
 
     pub async fn toggle_todo(&self, user_id: Uuid, id: Uuid) -> Result<(), TodoError> {
-        self.todo_repository
-            .toggle(user_id, id)
+        let uow = self.transactions.begin().await.map_err(TodoError::from)?;
+        let result = Self::apply_toggle(uow.as_ref(), user_id, id).await;
+
+        Self::finish(uow, result, "toggle").await.map(|_| ())
+    }
+
+    pub async fn snooze_todo(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: SnoozeTodoRequest,
+    ) -> Result<TodoResponse, TodoError> {
+        let uow = self.transactions.begin().await.map_err(TodoError::from)?;
+        let result = Self::apply_snooze(uow.as_ref(), user_id, id, dto.until).await;
+
+        Self::finish(uow, result, "snooze")
             .await
-            .map_err(TodoError::from)
+            .map(TodoResponse::from)
     }
 
     pub async fn delete_todo(&self, user_id: Uuid, id: Uuid) -> Result<(), TodoError> {
-        let todo = self
-            .todo_repository
-            .find_by_id(user_id, id)
-            .await
-            .map_err(TodoError::from)?
-            .ok_or(TodoError::NotFound)?;
+        let uow = self.transactions.begin().await.map_err(TodoError::from)?;
+        let result = Self::apply_delete(uow.as_ref(), user_id, id).await;
 
-        self.todo_repository
-            .delete(todo.id)
-            .await
-            .map_err(TodoError::from)
+        Self::finish(uow, result, "delete").await
     }
 
     pub async fn find_all(&self, user_id: Uuid) -> Result<Vec<TodoResponse>, TodoError> {
@@ -111,6 +127,129 @@ impl<T: TodoRepository> TodoUseCase<T> {
             .map_err(TodoError::from)
             .map(|todo| todo.map(TodoResponse::from))
     }
+
+    // commits the unit of work when the change went through, otherwise rolls it back
+    async fn finish<R>(
+        uow: Box<dyn UnitOfWork>,
+        result: Result<R, TodoError>,
+        action: &str,
+    ) -> Result<R, TodoError> {
+        match result {
+            Ok(value) => {
+                uow.commit().await.map_err(TodoError::from)?;
+                Ok(value)
+            }
+            Err(err) => {
+                if let Err(err) = uow.rollback().await {
+                    tracing::error!("failed to roll back todo {} : {}", action, err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn apply_create(uow: &dyn UnitOfWork, todo: Todo) -> Result<Todo, TodoError> {
+        let todo = uow.todos().create(todo).await.map_err(TodoError::from)?;
+
+        Self::record(uow, TodoEventKind::Created, &todo).await?;
+
+        Ok(todo)
+    }
+
+    async fn apply_update(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateTodoRequest,
+    ) -> Result<Todo, TodoError> {
+        let mut todo = uow
+            .todos()
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TodoError::from)?
+            .ok_or(TodoError::NotFound)?;
+
+        todo.title = dto.title;
+        todo.description = dto.description;
+        todo.priority = dto.priority;
+        todo.tags = dto.tags;
+        todo.project = dto.project;
+        todo.due_at = dto.due_at;
+        todo.updated_at = Utc::now();
+
+        let todo = uow.todos().update(todo).await.map_err(TodoError::from)?;
+
+        Self::record(uow, TodoEventKind::Updated, &todo).await?;
+
+        Ok(todo)
+    }
+
+    async fn apply_toggle(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Todo, TodoError> {
+        uow.todos()
+            .toggle(user_id, id)
+            .await
+            .map_err(TodoError::from)?;
+
+        let todo = uow
+            .todos()
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TodoError::from)?
+            .ok_or(TodoError::NotFound)?;
+
+        Self::record(uow, TodoEventKind::Toggled, &todo).await?;
+
+        Ok(todo)
+    }
+
+    async fn apply_snooze(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, TodoError> {
+        let todo = uow
+            .todos()
+            .snooze(user_id, id, until)
+            .await
+            .map_err(TodoError::from)?;
+
+        Self::record(uow, TodoEventKind::Updated, &todo).await?;
+
+        Ok(todo)
+    }
+
+    async fn apply_delete(uow: &dyn UnitOfWork, user_id: Uuid, id: Uuid) -> Result<(), TodoError> {
+        let todo = uow
+            .todos()
+            .find_by_id(user_id, id)
+            .await
+            .map_err(TodoError::from)?
+            .ok_or(TodoError::NotFound)?;
+
+        uow.todos()
+            .delete(user_id, todo.id)
+            .await
+            .map_err(TodoError::from)?;
+
+        Self::record(uow, TodoEventKind::Deleted, &todo).await
+    }
+
+    async fn record(
+        uow: &dyn UnitOfWork,
+        kind: TodoEventKind,
+        todo: &Todo,
+    ) -> Result<(), TodoError> {
+        uow.events()
+            .append(TodoEvent::new(kind, todo))
+            .await
+            .map(|_| ())
+            .map_err(TodoError::from)
+    }
 }
 
 #[cfg(test)]
@@ -120,71 +259,107 @@ mod tests {
 
     use crate::{
         application::todo::{
-            dto::{CreateTodoRequest, UpdateTodoRequest},
+            dto::{CreateTodoRequest, SnoozeTodoRequest, UpdateTodoRequest},
             usecase::TodoUseCase,
         },
         domain::{
-            shared::error::ModelError,
+            event::model::TodoEventKind,
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork},
+            },
             todo::{model::Todo, repository::MockTodoRepository},
         },
     };
 
+    fn todo(user_id: Uuid, id: Uuid) -> Todo {
+        Todo {
+            id,
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
     #[tokio::test]
     async fn create_todo_success() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
 
-        repo.expect_create()
+        uow.todos
+            .expect_create()
             .return_once(|t| Box::pin(async move { Ok(t) }));
 
-        let usecase = TodoUseCase::new(repo);
+        uow.events
+            .expect_append()
+            .withf(|e| e.kind == TodoEventKind::Created)
+            .return_once(|e| Box::pin(async move { Ok(e) }));
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let dto = CreateTodoRequest {
             title: "test".to_string(),
             description: "hell world".to_string(),
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
         };
 
         let user_id = Uuid::new_v4();
 
         let result = usecase.create_todo(user_id, dto);
 
-        assert!(result.await.is_ok())
+        assert!(result.await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
     }
 
     #[tokio::test]
     async fn create_todo_failed() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
 
-        repo.expect_create().return_once(|t| {
-            if t.user_id.is_nil() {
-                return Box::pin(async {
-                    Err(ModelError::Database("missing field user_id".to_string()))
-                });
-            }
-
-            Box::pin(async move { Ok(t) })
+        uow.todos.expect_create().return_once(|_| {
+            Box::pin(async { Err(ModelError::Database("missing field user_id".to_string())) })
         });
 
-        let usecase = TodoUseCase::new(repo);
+        uow.events.expect_append().never();
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let dto = CreateTodoRequest {
             title: "test".to_string(),
             description: "hello world".to_string(),
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
         };
 
-        let user_id = Uuid::nil();
-
-        let result = usecase.create_todo(user_id, dto);
+        let result = usecase.create_todo(Uuid::nil(), dto);
 
-        assert!(result.await.is_err())
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
     }
 
     #[tokio::test]
     async fn update_todo_success() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
         let todo_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
 
-        repo.expect_find_by_id()
+        uow.todos
+            .expect_find_by_id()
             .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
             .return_once(|uid, tid| {
                 let todo = Todo {
@@ -193,103 +368,193 @@ mod tests {
                     title: "test".to_string(),
                     description: "hello world".to_string(),
                     is_completed: false,
+                    state_id: None,
+                    priority: 0,
+                    tags: vec![],
+                    project: None,
+                    due_at: None,
+                    snoozed_until: None,
+                    tracked_seconds: 0,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
                 Box::pin(async move { Ok(Some(todo)) })
             });
 
-        repo.expect_update()
+        uow.todos
+            .expect_update()
             .withf(move |t| t.id == todo_id)
             .return_once(|t| Box::pin(async move { Ok(t) }));
 
-        let usecase = TodoUseCase::new(repo);
+        uow.events
+            .expect_append()
+            .withf(|e| e.kind == TodoEventKind::Updated)
+            .return_once(|e| Box::pin(async move { Ok(e) }));
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let dto = UpdateTodoRequest {
             title: "test".to_string(),
             description: "Lorem ipsum dolor sit amet, qui minim labore adipisicing minim sint cillum sint consectetur cupidatat.".to_string(),
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
         };
 
         let result = usecase.update_todo(user_id, todo_id, dto);
 
-        assert!(result.await.is_ok())
+        assert!(result.await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
     }
 
     #[tokio::test]
     async fn update_todo_failed() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
         let todo_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
 
-        repo.expect_find_by_id()
+        uow.todos
+            .expect_find_by_id()
             .return_once(|_, _| Box::pin(async move { Err(ModelError::NotFound) }));
 
-        repo.expect_update()
-            .withf(move |t| t.id == todo_id && t.user_id == user_id)
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
+
+        let dto = UpdateTodoRequest {
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+        };
+
+        let result = usecase.update_todo(user_id, todo_id, dto);
+
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+
+    #[tokio::test]
+    async fn update_todo_rolls_back_when_event_fails() {
+        let mut uow = MockUnitOfWork::new();
+
+        uow.todos.expect_find_by_id().return_once(|uid, tid| {
+            let todo = Todo {
+                id: tid,
+                user_id: uid,
+                title: "test".to_string(),
+                description: "hello world".to_string(),
+                is_completed: false,
+                state_id: None,
+                priority: 0,
+                tags: vec![],
+                project: None,
+                due_at: None,
+                snoozed_until: None,
+                tracked_seconds: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            };
+            Box::pin(async move { Ok(Some(todo)) })
+        });
+
+        uow.todos
+            .expect_update()
             .return_once(|t| Box::pin(async move { Ok(t) }));
 
-        let usecase = TodoUseCase::new(repo);
+        uow.events.expect_append().return_once(|_| {
+            Box::pin(async move { Err(ModelError::Database("connection reset".to_string())) })
+        });
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let dto = UpdateTodoRequest {
             title: "test".to_string(),
             description: "hello world".to_string(),
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
         };
 
-        let result = usecase.update_todo(user_id, todo_id, dto);
+        let result = usecase.update_todo(Uuid::new_v4(), Uuid::new_v4(), dto);
 
-        assert!(result.await.is_err())
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
     }
 
     #[tokio::test]
     async fn delete_todo_success() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
         let todo_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
 
-        repo.expect_find_by_id()
+        uow.todos
+            .expect_find_by_id()
             .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
-            .return_once(|uid, tid| {
-                let todo = Todo {
-                    id: tid,
-                    user_id: uid,
-                    title: "test".to_string(),
-                    description: "hello world".to_string(),
-                    is_completed: false,
-                    created_at: Utc::now(),
-                    updated_at: Utc::now(),
-                };
-                Box::pin(async move { Ok(Some(todo)) })
-            });
+            .return_once(|uid, tid| Box::pin(async move { Ok(Some(todo(uid, tid))) }));
 
-        repo.expect_delete()
-            .withf(move |tid| tid == &todo_id)
-            .return_once(|_| Box::pin(async { Ok(()) }));
+        uow.todos
+            .expect_delete()
+            .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
+            .return_once(|_, _| Box::pin(async { Ok(()) }));
+
+        uow.events
+            .expect_append()
+            .withf(move |e| e.kind == TodoEventKind::Deleted && e.todo_id == todo_id)
+            .return_once(|e| Box::pin(async move { Ok(e) }));
 
-        let usecase = TodoUseCase::new(repo);
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let result = usecase.delete_todo(user_id, todo_id);
 
-        assert!(result.await.is_ok())
+        assert!(result.await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
     }
 
     #[tokio::test]
     async fn delete_todo_failed() {
-        let mut repo = MockTodoRepository::new();
-        let todo_id = Uuid::new_v4();
-        let user_id = Uuid::new_v4();
+        let mut uow = MockUnitOfWork::new();
 
-        repo.expect_find_by_id()
+        uow.todos
+            .expect_find_by_id()
             .return_once(|_, _| Box::pin(async move { Err(ModelError::NotFound) }));
 
-        repo.expect_delete()
-            .withf(move |tid| tid == &todo_id)
-            .return_once(|_| Box::pin(async { Ok(()) }));
+        uow.todos.expect_delete().never();
 
-        let usecase = TodoUseCase::new(repo);
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
-        let result = usecase.delete_todo(user_id, todo_id);
+        let result = usecase.delete_todo(Uuid::new_v4(), Uuid::new_v4());
 
-        assert!(result.await.is_err())
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+
+    #[tokio::test]
+    async fn snooze_todo_not_found() {
+        let mut uow = MockUnitOfWork::new();
+
+        uow.todos
+            .expect_snooze()
+            .return_once(|_, _, _| Box::pin(async { Err(ModelError::NotFound) }));
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
+
+        let dto = SnoozeTodoRequest {
+            until: Some(Utc::now()),
+        };
+
+        let result = usecase.snooze_todo(Uuid::new_v4(), Uuid::new_v4(), dto);
+
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
     }
 
     #[tokio::test]
@@ -307,6 +572,13 @@ mod tests {
                         title: "Buy groceries".to_string(),
                         description: "Milk, eggs, bread, and fruits".to_string(),
                         is_completed: false,
+                        state_id: None,
+                        priority: 0,
+                        tags: vec![],
+                        project: None,
+                        due_at: None,
+                        snoozed_until: None,
+                        tracked_seconds: 0,
                         created_at: Utc::now(),
                         updated_at: Utc::now(),
                     },
@@ -316,6 +588,13 @@ mod tests {
                         title: "Finish project".to_string(),
                         description: "Complete the Rust backend implementation".to_string(),
                         is_completed: false,
+                        state_id: None,
+                        priority: 0,
+                        tags: vec![],
+                        project: None,
+                        due_at: None,
+                        snoozed_until: None,
+                        tracked_seconds: 0,
                         created_at: Utc::now(),
                         updated_at: Utc::now(),
                     },
@@ -325,6 +604,13 @@ mod tests {
                         title: "Call mom".to_string(),
                         description: "Wish her happy birthday".to_string(),
                         is_completed: true,
+                        state_id: None,
+                        priority: 0,
+                        tags: vec![],
+                        project: None,
+                        due_at: None,
+                        snoozed_until: None,
+                        tracked_seconds: 0,
                         created_at: Utc::now(),
                         updated_at: Utc::now(),
                     },
@@ -332,7 +618,7 @@ mod tests {
                 Box::pin(async move { Ok(todos) })
             });
 
-        let usecase = TodoUseCase::new(repo);
+        let usecase = TodoUseCase::new(repo, MockTransactionManager::new());
 
         let result = usecase.find_all(user_id);
 
@@ -348,7 +634,7 @@ mod tests {
             .withf(move |uid| uid == &user_id)
             .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
 
-        let usecase = TodoUseCase::new(repo);
+        let usecase = TodoUseCase::new(repo, MockTransactionManager::new());
 
         let result = usecase.find_all(user_id);
 
@@ -370,13 +656,20 @@ mod tests {
                     title: "test".to_string(),
                     description: "hello world".to_string(),
                     is_completed: false,
+                    state_id: None,
+                    priority: 0,
+                    tags: vec![],
+                    project: None,
+                    due_at: None,
+                    snoozed_until: None,
+                    tracked_seconds: 0,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
                 Box::pin(async move { Ok(Some(todo)) })
             });
 
-        let usecase = TodoUseCase::new(repo);
+        let usecase = TodoUseCase::new(repo, MockTransactionManager::new());
 
         let result = usecase.find_by_id(user_id, todo_id);
 
@@ -393,7 +686,7 @@ mod tests {
             .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
             .return_once(|_, _| Box::pin(async { Err(ModelError::NotFound) }));
 
-        let usecase = TodoUseCase::new(repo);
+        let usecase = TodoUseCase::new(repo, MockTransactionManager::new());
 
         let result = usecase.find_by_id(user_id, todo_id);
 
@@ -402,35 +695,57 @@ mod tests {
 
     #[tokio::test]
     async fn toggle_todo_success() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
         let todo_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
 
-        repo.expect_toggle()
+        uow.todos
+            .expect_toggle()
             .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
             .return_once(|_, _| Box::pin(async { Ok(()) }));
 
-        let usecase = TodoUseCase::new(repo);
+        uow.todos.expect_find_by_id().return_once(|uid, tid| {
+            let todo = Todo {
+                is_completed: true,
+                ..todo(uid, tid)
+            };
+            Box::pin(async move { Ok(Some(todo)) })
+        });
+
+        uow.events
+            .expect_append()
+            .withf(move |e| e.kind == TodoEventKind::Toggled && e.todo_id == todo_id)
+            .times(1)
+            .returning(|e| Box::pin(async move { Ok(e) }));
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let result = usecase.toggle_todo(user_id, todo_id);
 
-        assert!(result.await.is_ok())
+        assert!(result.await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
     }
 
     #[tokio::test]
     async fn toggle_todo_failed() {
-        let mut repo = MockTodoRepository::new();
+        let mut uow = MockUnitOfWork::new();
         let todo_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
 
-        repo.expect_toggle()
+        uow.todos
+            .expect_toggle()
             .withf(move |uid, tid| uid == &user_id && tid == &todo_id)
             .return_once(|_, _| Box::pin(async { Err(ModelError::NotFound) }));
 
-        let usecase = TodoUseCase::new(repo);
+        uow.events.expect_append().never();
+
+        let finished = uow.finished.clone();
+        let usecase = TodoUseCase::new(MockTodoRepository::new(), uow.begins());
 
         let result = usecase.toggle_todo(user_id, todo_id);
 
-        assert!(result.await.is_err())
+        assert!(result.await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
     }
 }
@@ -1,6 +1,8 @@
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::domain::user::model::User;
 
@@ -9,6 +11,7 @@ pub struct UserResponse {
     pub id: Uuid,
     pub name: String,
     pub email: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl From<User> for UserResponse {
@@ -17,6 +20,36 @@ impl From<User> for UserResponse {
             id: value.id,
             name: value.name,
             email: value.email,
+            email_verified_at: value.email_verified_at,
         }
     }
 }
+
+// fields left out keep their value
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateProfileRequest {
+    #[validate(length(min = 1, message = "name is required"))]
+    #[schema(example = "demo")]
+    pub name: Option<String>,
+
+    #[validate(email(message = "Please enter a valid email address"))]
+    #[schema(example = "demo@demo.com")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ChangePasswordRequest {
+    #[serde(default)]
+    #[validate(length(min = 1, message = "current password is required"))]
+    pub current_password: String,
+
+    #[serde(default)]
+    #[validate(length(min = 1, message = "new password is required"))]
+    pub new_password: String,
+}
+
+// replaces the refresh token of the caller, every other one stops working
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChangePasswordResponse {
+    pub refresh_token: String,
+}
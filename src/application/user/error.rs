@@ -1,9 +1,15 @@
+use chrono::{DateTime, Utc};
+
 use crate::domain::shared::error::ModelError;
 
 #[derive(Debug)]
 pub enum UserError {
     NotFound,
-    BussinerError,
+    // the email belongs to another user
+    Conflict,
+    InvalidPassword,
+    // too many wrong current passwords, shared with the login lockout
+    Locked(DateTime<Utc>),
     GeneralError,
 }
 
@@ -11,8 +17,8 @@ impl From<ModelError> for UserError {
     fn from(err: ModelError) -> Self {
         match err {
             ModelError::NotFound => Self::NotFound,
+            ModelError::Conflict => Self::Conflict,
             ModelError::Database(_) => Self::GeneralError,
-            _ => Self::BussinerError,
         }
     }
 }
@@ -1,33 +1,183 @@
+use chrono::Utc;
 use uuid::Uuid;
 
 use crate::{
-    application::user::{dto::UserResponse, error::UserError},
-    domain::user::repository::UserRepository,
+    application::{
+        auth::usecase::{EmailVerification, LockoutPolicy},
+        user::{
+            dto::{
+                ChangePasswordRequest, ChangePasswordResponse, UpdateProfileRequest, UserResponse,
+            },
+            error::UserError,
+        },
+    },
+    domain::{
+        lockout::repository::LockoutRepository,
+        mail::mailer::Mailer,
+        shared::transaction::{TransactionManager, UnitOfWork},
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::{database::replica, security::token::Token},
 };
 
-pub struct UserUseCase<T: UserRepository + Send + Sync> {
+pub struct UserUseCase<T, X, L, M>
+where
+    T: UserRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+    L: LockoutRepository + Send + Sync,
+    M: Mailer + Send + Sync,
+{
     user_repository: T,
+    transactions: X,
+    lockout_repository: L,
+    mailer: M,
+    lockout_policy: LockoutPolicy,
+    verification: EmailVerification,
+    jwt_secret: String,
 }
 
-impl<T: UserRepository> UserUseCase<T> {
-    pub fn new(user: T) -> Self {
+impl<T: UserRepository, X: TransactionManager, L: LockoutRepository, M: Mailer>
+    UserUseCase<T, X, L, M>
+{
+    pub fn new(
+        user: T,
+        transactions: X,
+        lockout: L,
+        mailer: M,
+        lockout_policy: LockoutPolicy,
+        verification: EmailVerification,
+        jwt_secret: String,
+    ) -> Self {
         Self {
             user_repository: user,
+            transactions,
+            lockout_repository: lockout,
+            mailer,
+            lockout_policy,
+            verification,
+            jwt_secret,
         }
     }
 
-    pub async fn delete_user(&self, id: Uuid) -> Result<(), UserError> {
+    // the user is written back whole, a read from a lagging replica would undo recent changes
+    async fn find_user(&self, id: Uuid) -> Result<User, UserError> {
+        replica::scope(true, self.user_repository.find_by_id(id))
+            .await
+            .map_err(UserError::from)?
+            .ok_or(UserError::NotFound)
+    }
+
+    // a taken email is reported by the repository as a conflict
+    pub async fn update_profile(
+        &self,
+        id: Uuid,
+        dto: UpdateProfileRequest,
+    ) -> Result<UserResponse, UserError> {
+        let mut user = self.find_user(id).await?;
+
+        if let Some(name) = dto.name {
+            user.name = name;
+        }
+
+        let mut email_changed = false;
+        if let Some(email) = dto.email.map(|email| email.to_lowercase())
+            && email != user.email
+        {
+            // a new address is unconfirmed until its owner follows the link mailed to it
+            user.email = email;
+            user.email_verified_at = None;
+            email_changed = true;
+        }
+
+        user.updated_at = Utc::now();
+
         let user = self
             .user_repository
-            .find_by_id(id)
+            .update(&user)
             .await
-            .map_err(UserError::from)?
-            .ok_or(UserError::NotFound)?;
+            .map_err(UserError::from)?;
+
+        // the profile is saved either way, a lost mail can be sent again
+        if email_changed
+            && let Err(err) = self
+                .verification
+                .send(&self.mailer, &self.jwt_secret, &user)
+                .await
+        {
+            tracing::error!("failed send verification to {} : {}", user.id, err);
+        }
+
+        Ok(UserResponse::from(user))
+    }
+
+    // every session is signed out and older access tokens are revoked, the caller continues with
+    // the returned refresh token.
+    // wrong current passwords count towards the same lockout as failed logins
+    pub async fn change_password(
+        &self,
+        id: Uuid,
+        dto: ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse, UserError> {
+        let mut user = self.find_user(id).await?;
+
+        let failures = self.lockout_repository.find(user.id).await?;
+        if let Some(until) = failures
+            .as_ref()
+            .filter(|failures| failures.is_locked(Utc::now()))
+            .and_then(|failures| failures.locked_until)
+        {
+            return Err(UserError::Locked(until));
+        }
+
+        if !user.verify_password(dto.current_password) {
+            return match self
+                .lockout_policy
+                .record_failure(&self.lockout_repository, user.id)
+                .await?
+            {
+                Some(until) => Err(UserError::Locked(until)),
+                None => Err(UserError::InvalidPassword),
+            };
+        }
+
+        if failures.is_some() {
+            self.lockout_repository.reset(user.id).await?;
+        }
+
+        let refresh_token = Token::new(&self.jwt_secret, &Uuid::new_v4().to_string())
+            .map_err(|_| UserError::GeneralError)?;
+
+        user.password = User::hash_password(&dto.new_password).map_err(|err| {
+            tracing::error!("failed hash password : {}", err);
+            UserError::GeneralError
+        })?;
+        user.token = Some(refresh_token.encrypted);
+        user.password_changed_at = Some(Utc::now());
+        user.updated_at = Utc::now();
 
         self.user_repository
-            .delete(user.id)
+            .update(&user)
             .await
-            .map_err(UserError::from)
+            .map_err(UserError::from)?;
+
+        Ok(ChangePasswordResponse {
+            refresh_token: refresh_token.decrypted,
+        })
+    }
+
+    // todos restrict deleting their owner, they are removed in the same transaction as the user
+    pub async fn delete_user(&self, id: Uuid) -> Result<(), UserError> {
+        let user = self.find_user(id).await?;
+        let uow = self.transactions.begin().await.map_err(UserError::from)?;
+
+        if let Err(err) = Self::delete_account(uow.as_ref(), user.id).await {
+            if let Err(err) = uow.rollback().await {
+                tracing::error!("failed to roll back user delete : {}", err);
+            }
+            return Err(err);
+        }
+
+        uow.commit().await.map_err(UserError::from)
     }
 
     pub async fn find_all(&self) -> Result<Vec<UserResponse>, UserError> {
@@ -50,48 +200,158 @@ impl<T: UserRepository> UserUseCase<T> {
             .map_err(UserError::from)
             .map(|user| user.map(UserResponse::from))
     }
+
+    async fn delete_account(uow: &dyn UnitOfWork, user_id: Uuid) -> Result<(), UserError> {
+        let todos = uow
+            .todos()
+            .find_all_including_snoozed(user_id)
+            .await
+            .map_err(UserError::from)?;
+
+        for todo in todos {
+            uow.todos()
+                .delete(user_id, todo.id)
+                .await
+                .map_err(UserError::from)?;
+        }
+
+        uow.users().delete(user_id).await.map_err(UserError::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use chrono::Utc;
     use uuid::Uuid;
 
     use crate::{
-        application::user::usecase::UserUseCase,
+        application::{
+            auth::usecase::{EmailVerification, LockoutPolicy},
+            user::{
+                dto::{ChangePasswordRequest, UpdateProfileRequest},
+                error::UserError,
+                usecase::UserUseCase,
+            },
+        },
         domain::{
-            shared::error::ModelError,
+            lockout::{model::LoginFailures, repository::MockLockoutRepository},
+            mail::mailer::MockMailer,
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork},
+            },
+            todo::model::Todo,
             user::{model::User, repository::MockUserRepository},
         },
     };
 
+    const POLICY: LockoutPolicy = LockoutPolicy {
+        threshold: 3,
+        duration: Duration::from_secs(60),
+        max_duration: Duration::from_secs(3600),
+    };
+
+    type TestUseCase =
+        UserUseCase<MockUserRepository, MockTransactionManager, MockLockoutRepository, MockMailer>;
+
+    // sends no mail, a test expecting one builds the use case with `mailing`
+    fn usecase(
+        repo: MockUserRepository,
+        transactions: MockTransactionManager,
+        lockout: MockLockoutRepository,
+    ) -> TestUseCase {
+        mailing(repo, transactions, lockout, MockMailer::new())
+    }
+
+    fn mailing(
+        repo: MockUserRepository,
+        transactions: MockTransactionManager,
+        lockout: MockLockoutRepository,
+        mailer: MockMailer,
+    ) -> TestUseCase {
+        let verification = EmailVerification {
+            required: false,
+            ttl: Duration::from_secs(60),
+            url: "http://localhost/verify".to_string(),
+        };
+
+        UserUseCase::new(
+            repo,
+            transactions,
+            lockout,
+            mailer,
+            POLICY,
+            verification,
+            "secret".to_string(),
+        )
+    }
+
+    fn user(id: Uuid) -> User {
+        User {
+            id,
+            name: "test".to_string(),
+            email: "test@domain.com".to_string(),
+            password: User::hash_password("demo123").unwrap(),
+            token: Some("refresh".to_string()),
+            email_verified_at: Some(Utc::now()),
+            password_changed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn todo(user_id: Uuid) -> Todo {
+        Todo {
+            id: Uuid::new_v4(),
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed: false,
+            state_id: None,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
     #[tokio::test]
     async fn delete_user_success() {
         let id = Uuid::new_v4();
         let mut repo = MockUserRepository::new();
+        let mut uow = MockUnitOfWork::new();
 
         repo.expect_find_by_id()
             .withf(move |user_id| *user_id == id)
-            .return_once(move |_| {
-                let password = User::hash_password("demo123").unwrap();
-                let user = User {
-                    id,
-                    name: "test".to_string(),
-                    email: "test@domain.com".to_string(),
-                    password,
-                    token: None,
-                    created_at: Utc::now(),
-                    updated_at: Utc::now(),
-                };
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
 
-                Box::pin(async move { Ok(Some(user)) })
+        uow.todos
+            .expect_find_all_including_snoozed()
+            .return_once(|uid| {
+                let todos = vec![todo(uid), todo(uid)];
+                Box::pin(async move { Ok(todos) })
             });
+        uow.todos
+            .expect_delete()
+            .withf(move |user_id, _| *user_id == id)
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        uow.users
+            .expect_delete()
+            .withf(move |user_id| *user_id == id)
+            .return_once(|_| Box::pin(async { Ok(()) }));
 
-        repo.expect_delete()
-            .return_once(|_| Box::pin(async move { Ok(()) }));
+        let finished = uow.finished.clone();
+        let usecase = usecase(repo, uow.begins(), MockLockoutRepository::new());
 
-        let usecase = UserUseCase::new(repo);
         assert!(usecase.delete_user(id).await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
     }
 
     #[tokio::test]
@@ -103,11 +363,41 @@ mod tests {
             .withf(move |user_id| *user_id == id)
             .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
 
-        repo.expect_delete()
-            .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
+        assert!(usecase.delete_user(id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn delete_user_rolls_back_when_user_delete_fails() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+        let mut uow = MockUnitOfWork::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        uow.todos
+            .expect_find_all_including_snoozed()
+            .return_once(|uid| {
+                let todos = vec![todo(uid)];
+                Box::pin(async move { Ok(todos) })
+            });
+        uow.todos
+            .expect_delete()
+            .return_once(|_, _| Box::pin(async { Ok(()) }));
+        uow.users.expect_delete().return_once(|_| {
+            Box::pin(async { Err(ModelError::Database("connection reset".to_string())) })
+        });
+
+        let finished = uow.finished.clone();
+        let usecase = usecase(repo, uow.begins(), MockLockoutRepository::new());
 
-        let usecase = UserUseCase::new(repo);
         assert!(usecase.delete_user(id).await.is_err());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
     }
 
     #[tokio::test]
@@ -122,6 +412,8 @@ mod tests {
                     email: "alice@example.com".to_string(),
                     password: User::hash_password("demo123").unwrap(),
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 },
@@ -131,6 +423,8 @@ mod tests {
                     email: "bob@example.com".to_string(),
                     password: User::hash_password("demo123").unwrap(),
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 },
@@ -140,6 +434,8 @@ mod tests {
                     email: "charlie@example.com".to_string(),
                     password: User::hash_password("demo123").unwrap(),
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 },
@@ -148,7 +444,11 @@ mod tests {
             Box::pin(async { Ok(users) })
         });
 
-        let usecase = UserUseCase::new(repo);
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
         assert!(usecase.find_all().await.is_ok());
     }
 
@@ -159,7 +459,11 @@ mod tests {
         repo.expect_find_all()
             .return_once(|| Box::pin(async { Err(ModelError::NotFound) }));
 
-        let usecase = UserUseCase::new(repo);
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
         assert!(usecase.find_all().await.is_err());
     }
 
@@ -178,6 +482,8 @@ mod tests {
                     email: "test@domain.com".to_string(),
                     password,
                     token: None,
+                    email_verified_at: Some(Utc::now()),
+                    password_changed_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
@@ -185,7 +491,11 @@ mod tests {
                 Box::pin(async move { Ok(Some(user)) })
             });
 
-        let usecase = UserUseCase::new(repo);
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
         assert!(usecase.find_by_id(id).await.is_ok());
     }
 
@@ -198,7 +508,278 @@ mod tests {
             .withf(move |user_id| *user_id == id)
             .return_once(|_| Box::pin(async { Err(ModelError::NotFound) }));
 
-        let usecase = UserUseCase::new(repo);
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
         assert!(usecase.find_by_id(id).await.is_err());
     }
+
+    #[tokio::test]
+    async fn update_profile_new_email_unverified() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        repo.expect_update()
+            .withf(|user| {
+                user.name == "renamed"
+                    && user.email == "new@domain.com"
+                    && user.email_verified_at.is_none()
+            })
+            .return_once(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let mut mailer = MockMailer::new();
+        mailer
+            .expect_send()
+            .withf(|mail| mail.to == "new@domain.com" && mail.body.contains("token="))
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let usecase = mailing(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+            mailer,
+        );
+        let result = usecase
+            .update_profile(
+                id,
+                UpdateProfileRequest {
+                    name: Some("renamed".to_string()),
+                    email: Some("NEW@domain.com".to_string()),
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap().email, "new@domain.com");
+    }
+
+    #[tokio::test]
+    async fn update_profile_same_email_sends_no_mail() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        repo.expect_update()
+            .withf(|user| user.email_verified_at.is_some())
+            .return_once(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let mut mailer = MockMailer::new();
+        mailer.expect_send().never();
+
+        let usecase = mailing(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+            mailer,
+        );
+        let result = usecase
+            .update_profile(
+                id,
+                UpdateProfileRequest {
+                    name: Some("renamed".to_string()),
+                    email: Some("Test@domain.com".to_string()),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update_profile_email_taken() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        repo.expect_update()
+            .return_once(|_| Box::pin(async { Err(ModelError::Conflict) }));
+
+        let usecase = usecase(
+            repo,
+            MockTransactionManager::new(),
+            MockLockoutRepository::new(),
+        );
+        let result = usecase
+            .update_profile(
+                id,
+                UpdateProfileRequest {
+                    name: None,
+                    email: Some("taken@domain.com".to_string()),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserError::Conflict)));
+    }
+
+    #[tokio::test]
+    async fn change_password_wrong_current_password() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+        repo.expect_update().never();
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        lockout
+            .expect_record_failure()
+            .withf(move |user_id| *user_id == id)
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(1) }));
+
+        let usecase = usecase(repo, MockTransactionManager::new(), lockout);
+        let result = usecase
+            .change_password(
+                id,
+                ChangePasswordRequest {
+                    current_password: "wrong".to_string(),
+                    new_password: "new-secret".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserError::InvalidPassword)));
+    }
+
+    #[tokio::test]
+    async fn change_password_locks_after_threshold() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+        repo.expect_update().never();
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout.expect_find().return_once(move |_| {
+            Box::pin(async move {
+                Ok(Some(LoginFailures {
+                    user_id: id,
+                    failures: 3,
+                    locked_until: None,
+                    updated_at: Utc::now(),
+                }))
+            })
+        });
+        lockout
+            .expect_record_failure()
+            .return_once(|_| Box::pin(async { Ok(4) }));
+        lockout
+            .expect_lock()
+            .times(1)
+            .return_once(|_, _| Box::pin(async { Ok(()) }));
+        lockout
+            .expect_record_event()
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let usecase = usecase(repo, MockTransactionManager::new(), lockout);
+        let result = usecase
+            .change_password(
+                id,
+                ChangePasswordRequest {
+                    current_password: "wrong".to_string(),
+                    new_password: "new-secret".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserError::Locked(_))));
+    }
+
+    #[tokio::test]
+    async fn change_password_locked_account() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+        repo.expect_update().never();
+
+        // the right password does not help while the lock lasts
+        let mut lockout = MockLockoutRepository::new();
+        lockout.expect_find().return_once(move |_| {
+            Box::pin(async move {
+                Ok(Some(LoginFailures {
+                    user_id: id,
+                    failures: 4,
+                    locked_until: Some(Utc::now() + chrono::Duration::minutes(5)),
+                    updated_at: Utc::now(),
+                }))
+            })
+        });
+        lockout.expect_record_failure().never();
+
+        let usecase = usecase(repo, MockTransactionManager::new(), lockout);
+        let result = usecase
+            .change_password(
+                id,
+                ChangePasswordRequest {
+                    current_password: "demo123".to_string(),
+                    new_password: "new-secret".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserError::Locked(_))));
+    }
+
+    #[tokio::test]
+    async fn change_password_replaces_refresh_token() {
+        let id = Uuid::new_v4();
+        let mut repo = MockUserRepository::new();
+
+        repo.expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(user(id))) }));
+
+        repo.expect_update()
+            .withf(|user| {
+                user.token
+                    .as_deref()
+                    .is_some_and(|token| token != "refresh")
+                    && user.verify_password("new-secret".to_string())
+                    && user.password_changed_at.is_some()
+            })
+            .times(1)
+            .return_once(|user| {
+                let user = user.clone();
+                Box::pin(async move { Ok(user) })
+            });
+
+        let mut lockout = MockLockoutRepository::new();
+        lockout
+            .expect_find()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        let usecase = usecase(repo, MockTransactionManager::new(), lockout);
+        let result = usecase
+            .change_password(
+                id,
+                ChangePasswordRequest {
+                    current_password: "demo123".to_string(),
+                    new_password: "new-secret".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
 }
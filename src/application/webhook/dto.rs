@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::{
+    domain::webhook::model::{DeliveryStatus, Webhook, WebhookDelivery, WebhookEvent},
+    infrastructure::http::webhook_sender::check_url,
+};
+
+fn default_active() -> bool {
+    true
+}
+
+fn validate_public_url(url: &str) -> Result<(), ValidationError> {
+    check_url(url).map(|_| ()).map_err(|err| {
+        ValidationError::new("public_url").with_message(format!("url is not allowed: {err}").into())
+    })
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateWebhookRequest {
+    #[validate(
+        url(message = "url is invalid"),
+        custom(function = "validate_public_url")
+    )]
+    pub url: String,
+
+    #[validate(length(min = 1, message = "subscribe to at least one event"))]
+    pub events: Vec<WebhookEvent>,
+
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateWebhookRequest {
+    #[validate(
+        url(message = "url is invalid"),
+        custom(function = "validate_public_url")
+    )]
+    pub url: String,
+
+    #[validate(length(min = 1, message = "subscribe to at least one event"))]
+    pub events: Vec<WebhookEvent>,
+
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    /// key for verifying the `X-Webhook-Signature` header of every delivery
+    pub secret: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(value: Webhook) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            secret: value.secret,
+            events: value.events,
+            active: value.active,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeliveryResponse {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: String,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub response_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for DeliveryResponse {
+    fn from(value: WebhookDelivery) -> Self {
+        Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            event: value.event,
+            payload: value.payload,
+            status: value.status,
+            attempts: value.attempts,
+            response_code: value.response_code,
+            last_error: value.last_error,
+            next_attempt_at: value.next_attempt_at,
+            delivered_at: value.delivered_at,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum WebhookError {
+    NotFound,
+    GeneralError,
+}
+
+impl From<ModelError> for WebhookError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            _ => Self::GeneralError,
+        }
+    }
+}
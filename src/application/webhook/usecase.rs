@@ -0,0 +1,443 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    application::webhook::{
+        dto::{CreateWebhookRequest, DeliveryResponse, UpdateWebhookRequest, WebhookResponse},
+        error::WebhookError,
+    },
+    domain::webhook::{
+        model::{DeliveryJob, DeliveryStatus, Webhook, WebhookDelivery, WebhookEvent},
+        repository::WebhookRepository,
+        sender::{WebhookRequest, WebhookSender},
+    },
+    infrastructure::security::token::Token,
+};
+
+// a delivery is given up after this many attempts, retried with exponential backoff until then
+const MAX_ATTEMPTS: i32 = 8;
+const BASE_DELAY_SECONDS: i64 = 30;
+
+pub struct WebhookUseCase<W, S>
+where
+    W: WebhookRepository + Send + Sync,
+    S: WebhookSender + Send + Sync,
+{
+    webhook_repository: W,
+    webhook_sender: S,
+}
+
+impl<W: WebhookRepository, S: WebhookSender> WebhookUseCase<W, S> {
+    pub fn new(webhook: W, sender: S) -> Self {
+        Self {
+            webhook_repository: webhook,
+            webhook_sender: sender,
+        }
+    }
+
+    pub async fn create_webhook(
+        &self,
+        user_id: Uuid,
+        dto: CreateWebhookRequest,
+    ) -> Result<WebhookResponse, WebhookError> {
+        let webhook = Webhook {
+            id: Uuid::new_v4(),
+            user_id,
+            url: dto.url,
+            secret: format!(
+                "whsec_{}{}",
+                Uuid::new_v4().simple(),
+                Uuid::new_v4().simple()
+            ),
+            events: event_names(&dto.events),
+            active: dto.active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.webhook_repository
+            .create(webhook)
+            .await
+            .map_err(WebhookError::from)
+            .map(WebhookResponse::from)
+    }
+
+    pub async fn update_webhook(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateWebhookRequest,
+    ) -> Result<WebhookResponse, WebhookError> {
+        let mut webhook = self
+            .webhook_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(WebhookError::from)?
+            .ok_or(WebhookError::NotFound)?;
+
+        webhook.url = dto.url;
+        webhook.events = event_names(&dto.events);
+        webhook.active = dto.active;
+        webhook.updated_at = Utc::now();
+
+        self.webhook_repository
+            .update(webhook)
+            .await
+            .map_err(WebhookError::from)
+            .map(WebhookResponse::from)
+    }
+
+    pub async fn delete_webhook(&self, user_id: Uuid, id: Uuid) -> Result<(), WebhookError> {
+        let webhook = self
+            .webhook_repository
+            .find_by_id(user_id, id)
+            .await
+            .map_err(WebhookError::from)?
+            .ok_or(WebhookError::NotFound)?;
+
+        self.webhook_repository
+            .delete(webhook.id)
+            .await
+            .map_err(WebhookError::from)
+    }
+
+    pub async fn find_all(&self, user_id: Uuid) -> Result<Vec<WebhookResponse>, WebhookError> {
+        self.webhook_repository
+            .find_all(user_id)
+            .await
+            .map_err(WebhookError::from)
+            .map(|webhooks| {
+                webhooks
+                    .into_iter()
+                    .map(WebhookResponse::from)
+                    .collect::<Vec<WebhookResponse>>()
+            })
+    }
+
+    pub async fn find_deliveries(
+        &self,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> Result<Vec<DeliveryResponse>, WebhookError> {
+        let webhook = self
+            .webhook_repository
+            .find_by_id(user_id, webhook_id)
+            .await
+            .map_err(WebhookError::from)?
+            .ok_or(WebhookError::NotFound)?;
+
+        self.webhook_repository
+            .find_deliveries(user_id, webhook.id)
+            .await
+            .map_err(WebhookError::from)
+            .map(|deliveries| {
+                deliveries
+                    .into_iter()
+                    .map(DeliveryResponse::from)
+                    .collect::<Vec<DeliveryResponse>>()
+            })
+    }
+
+    // queue the payload of an earlier delivery again, the original stays in the log untouched
+    pub async fn redeliver(
+        &self,
+        user_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<DeliveryResponse, WebhookError> {
+        let original = self
+            .webhook_repository
+            .find_delivery(user_id, delivery_id)
+            .await
+            .map_err(WebhookError::from)?
+            .ok_or(WebhookError::NotFound)?;
+
+        let delivery = WebhookDelivery {
+            id: Uuid::new_v4(),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Utc::now(),
+            response_code: None,
+            last_error: None,
+            delivered_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            ..original
+        };
+
+        self.webhook_repository
+            .create_delivery(delivery)
+            .await
+            .map_err(WebhookError::from)
+            .map(DeliveryResponse::from)
+    }
+
+    // send every due delivery in batches, returns how many attempts were made
+    pub async fn dispatch_due(&self, batch: i64) -> Result<usize, WebhookError> {
+        let mut attempted = 0;
+
+        loop {
+            let jobs = self
+                .webhook_repository
+                .claim_deliveries(batch)
+                .await
+                .map_err(WebhookError::from)?;
+
+            let claimed = jobs.len();
+
+            for job in jobs {
+                self.deliver(job).await?;
+            }
+
+            attempted += claimed;
+
+            if (claimed as i64) < batch {
+                return Ok(attempted);
+            }
+        }
+    }
+
+    async fn deliver(&self, job: DeliveryJob) -> Result<(), WebhookError> {
+        let mut delivery = job.delivery;
+        let body = delivery.payload.to_string();
+        let timestamp = Utc::now().timestamp();
+
+        let request = WebhookRequest {
+            url: job.url,
+            headers: vec![
+                ("X-Webhook-Id".to_string(), delivery.id.to_string()),
+                ("X-Webhook-Event".to_string(), delivery.event.clone()),
+                ("X-Webhook-Timestamp".to_string(), timestamp.to_string()),
+                (
+                    "X-Webhook-Signature".to_string(),
+                    format!("sha256={}", sign(&job.secret, timestamp, &body)?),
+                ),
+            ],
+            body,
+        };
+
+        let now = Utc::now();
+        let result = self.webhook_sender.send(request).await;
+        let succeeded = matches!(result, Ok(200..=299));
+
+        delivery.attempts += 1;
+        delivery.updated_at = now;
+
+        match result {
+            Ok(code) => {
+                delivery.response_code = Some(code.into());
+                delivery.last_error = None;
+            }
+            Err(err) => {
+                delivery.response_code = None;
+                delivery.last_error = Some(err);
+            }
+        }
+
+        if succeeded {
+            delivery.status = DeliveryStatus::Succeeded;
+            delivery.delivered_at = Some(now);
+        } else if delivery.attempts >= MAX_ATTEMPTS {
+            delivery.status = DeliveryStatus::Failed;
+        } else {
+            delivery.next_attempt_at = next_attempt(now, delivery.attempts);
+        }
+
+        self.webhook_repository
+            .update_delivery(delivery)
+            .await
+            .map_err(WebhookError::from)
+            .map(|_| ())
+    }
+}
+
+fn event_names(events: &[WebhookEvent]) -> Vec<String> {
+    let mut names = events
+        .iter()
+        .map(|event| event.as_str().to_string())
+        .collect::<Vec<String>>();
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+// receivers verify HMAC-SHA256("<timestamp>.<body>") keyed with the webhook secret
+fn sign(secret: &str, timestamp: i64, body: &str) -> Result<String, WebhookError> {
+    Token::new(secret, &format!("{timestamp}.{body}"))
+        .map(|token| token.encrypted)
+        .map_err(|_| WebhookError::GeneralError)
+}
+
+fn next_attempt(now: DateTime<Utc>, attempts: i32) -> DateTime<Utc> {
+    let exponent = (attempts - 1).clamp(0, 16) as u32;
+
+    now + Duration::seconds(BASE_DELAY_SECONDS * 2_i64.pow(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::webhook::{
+            dto::CreateWebhookRequest,
+            error::WebhookError,
+            usecase::{WebhookUseCase, sign},
+        },
+        domain::webhook::{
+            model::{DeliveryJob, DeliveryStatus, WebhookDelivery, WebhookEvent},
+            repository::MockWebhookRepository,
+            sender::MockWebhookSender,
+        },
+    };
+
+    fn job(attempts: i32) -> DeliveryJob {
+        DeliveryJob {
+            delivery: WebhookDelivery {
+                id: Uuid::new_v4(),
+                webhook_id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                event_id: Some(1),
+                event: "created".to_string(),
+                payload: serde_json::json!({ "event": "created" }),
+                status: DeliveryStatus::Pending,
+                attempts,
+                next_attempt_at: Utc::now(),
+                response_code: None,
+                last_error: None,
+                delivered_at: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            url: "https://example.com/hook".to_string(),
+            secret: "whsec_test".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_webhook_generates_secret() {
+        let mut repo = MockWebhookRepository::new();
+
+        repo.expect_create()
+            .withf(|w| w.secret.starts_with("whsec_") && w.events == vec!["completed", "created"])
+            .return_once(|w| Box::pin(async move { Ok(w) }));
+
+        let usecase = WebhookUseCase::new(repo, MockWebhookSender::new());
+
+        let dto = CreateWebhookRequest {
+            url: "https://example.com/hook".to_string(),
+            events: vec![
+                WebhookEvent::Created,
+                WebhookEvent::Completed,
+                WebhookEvent::Created,
+            ],
+            active: true,
+        };
+
+        assert!(usecase.create_webhook(Uuid::new_v4(), dto).await.is_ok())
+    }
+
+    #[tokio::test]
+    async fn dispatch_signs_and_marks_succeeded() {
+        let mut repo = MockWebhookRepository::new();
+        let mut sender = MockWebhookSender::new();
+
+        repo.expect_claim_deliveries()
+            .return_once(|_| Box::pin(async { Ok(vec![job(0)]) }));
+
+        sender
+            .expect_send()
+            .withf(|request| {
+                let header = |name: &str| {
+                    request
+                        .headers
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_default()
+                };
+                let timestamp = header("X-Webhook-Timestamp").parse::<i64>().unwrap();
+                let expected = sign("whsec_test", timestamp, &request.body).unwrap();
+
+                header("X-Webhook-Signature") == format!("sha256={expected}")
+            })
+            .return_once(|_| Box::pin(async { Ok(204) }));
+
+        repo.expect_update_delivery()
+            .withf(|d| {
+                d.status == DeliveryStatus::Succeeded
+                    && d.attempts == 1
+                    && d.response_code == Some(204)
+                    && d.delivered_at.is_some()
+            })
+            .return_once(|d| Box::pin(async move { Ok(d) }));
+
+        let usecase = WebhookUseCase::new(repo, sender);
+
+        assert_eq!(usecase.dispatch_due(10).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn dispatch_failure_backs_off() {
+        let mut repo = MockWebhookRepository::new();
+        let mut sender = MockWebhookSender::new();
+
+        repo.expect_claim_deliveries()
+            .return_once(|_| Box::pin(async { Ok(vec![job(2)]) }));
+
+        sender
+            .expect_send()
+            .return_once(|_| Box::pin(async { Ok(503) }));
+
+        repo.expect_update_delivery()
+            .withf(|d| {
+                let delay = d.next_attempt_at - d.updated_at;
+                d.status == DeliveryStatus::Pending && d.attempts == 3 && delay.num_seconds() == 120
+            })
+            .return_once(|d| Box::pin(async move { Ok(d) }));
+
+        let usecase = WebhookUseCase::new(repo, sender);
+
+        assert!(usecase.dispatch_due(10).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn dispatch_gives_up_after_last_attempt() {
+        let mut repo = MockWebhookRepository::new();
+        let mut sender = MockWebhookSender::new();
+
+        repo.expect_claim_deliveries()
+            .return_once(|_| Box::pin(async { Ok(vec![job(7)]) }));
+
+        sender
+            .expect_send()
+            .return_once(|_| Box::pin(async { Err("connection refused".to_string()) }));
+
+        repo.expect_update_delivery()
+            .withf(|d| {
+                d.status == DeliveryStatus::Failed
+                    && d.response_code.is_none()
+                    && d.last_error.as_deref() == Some("connection refused")
+            })
+            .return_once(|d| Box::pin(async move { Ok(d) }));
+
+        let usecase = WebhookUseCase::new(repo, sender);
+
+        assert!(usecase.dispatch_due(10).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn redeliver_not_found() {
+        let mut repo = MockWebhookRepository::new();
+
+        repo.expect_find_delivery()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+
+        let usecase = WebhookUseCase::new(repo, MockWebhookSender::new());
+
+        let result = usecase.redeliver(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result.unwrap_err(), WebhookError::NotFound));
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::todo::dto::TodoResponse,
+    domain::workflow::model::{StateCategory, StateTransition, WorkflowState},
+};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateStateRequest {
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50, message = "name is required"))]
+    #[schema(example = "In review")]
+    pub name: String,
+
+    pub category: StateCategory,
+
+    /// defaults to the end of the board
+    #[serde(default)]
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateStateRequest {
+    #[validate(length(min = 1, max = 50, message = "name is required"))]
+    pub name: String,
+
+    pub category: StateCategory,
+
+    pub position: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TransitionRequest {
+    pub state_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowStateResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub position: i32,
+    pub category: StateCategory,
+}
+
+impl From<WorkflowState> for WorkflowStateResponse {
+    fn from(value: WorkflowState) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            position: value.position,
+            category: value.category,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransitionResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub from_state_id: Option<Uuid>,
+    pub to_state_id: Uuid,
+    pub transitioned_at: DateTime<Utc>,
+}
+
+impl From<StateTransition> for TransitionResponse {
+    fn from(value: StateTransition) -> Self {
+        Self {
+            id: value.id,
+            todo_id: value.todo_id,
+            from_state_id: value.from_state_id,
+            to_state_id: value.to_state_id,
+            transitioned_at: value.transitioned_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardColumn {
+    pub state: WorkflowStateResponse,
+    pub todos: Vec<TodoResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardResponse {
+    pub columns: Vec<BoardColumn>,
+
+    /// todos that could not be placed because no state of their category exists
+    pub unassigned: Vec<TodoResponse>,
+}
@@ -0,0 +1,18 @@
+use crate::domain::shared::error::ModelError;
+
+#[derive(Debug)]
+pub enum WorkflowError {
+    NotFound,
+    Conflict,
+    GeneralError,
+}
+
+impl From<ModelError> for WorkflowError {
+    fn from(err: ModelError) -> Self {
+        match err {
+            ModelError::NotFound => Self::NotFound,
+            ModelError::Conflict => Self::Conflict,
+            ModelError::Database(_) => Self::GeneralError,
+        }
+    }
+}
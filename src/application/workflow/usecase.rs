@@ -0,0 +1,771 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        todo::dto::TodoResponse,
+        workflow::{
+            dto::{
+                BoardColumn, BoardResponse, CreateStateRequest, TransitionRequest,
+                TransitionResponse, UpdateStateRequest, WorkflowStateResponse,
+            },
+            error::WorkflowError,
+        },
+    },
+    domain::{
+        event::model::{TodoEvent, TodoEventKind},
+        shared::transaction::{TransactionManager, UnitOfWork},
+        todo::repository::TodoRepository,
+        workflow::{
+            model::{StateCategory, StateTransition, WorkflowState},
+            repository::WorkflowRepository,
+        },
+    },
+};
+
+pub struct WorkflowUseCase<W, T, X>
+where
+    W: WorkflowRepository + Send + Sync,
+    T: TodoRepository + Send + Sync,
+    X: TransactionManager + Send + Sync,
+{
+    workflow_repository: W,
+    todo_repository: T,
+    transactions: X,
+}
+
+// writes that move todos are stored together with their events or not at all
+impl<W: WorkflowRepository, T: TodoRepository, X: TransactionManager> WorkflowUseCase<W, T, X> {
+    pub fn new(workflow: W, todo: T, transactions: X) -> Self {
+        Self {
+            workflow_repository: workflow,
+            todo_repository: todo,
+            transactions,
+        }
+    }
+
+    pub async fn create_state(
+        &self,
+        user_id: Uuid,
+        dto: CreateStateRequest,
+    ) -> Result<WorkflowStateResponse, WorkflowError> {
+        let position = match dto.position {
+            Some(position) => position,
+            None => self
+                .workflow_repository
+                .find_states(user_id)
+                .await
+                .map_err(WorkflowError::from)?
+                .iter()
+                .map(|state| state.position + 1)
+                .max()
+                .unwrap_or_default(),
+        };
+
+        let state = WorkflowState {
+            id: Uuid::new_v4(),
+            user_id,
+            name: dto.name,
+            position,
+            category: dto.category,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.workflow_repository
+            .create_state(state)
+            .await
+            .map_err(WorkflowError::from)
+            .map(WorkflowStateResponse::from)
+    }
+
+    pub async fn update_state(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateStateRequest,
+    ) -> Result<WorkflowStateResponse, WorkflowError> {
+        let uow = self
+            .transactions
+            .begin()
+            .await
+            .map_err(WorkflowError::from)?;
+        let result = Self::apply_update_state(uow.as_ref(), user_id, id, dto).await;
+
+        Self::finish(uow, result, "state update")
+            .await
+            .map(WorkflowStateResponse::from)
+    }
+
+    pub async fn delete_state(&self, user_id: Uuid, id: Uuid) -> Result<(), WorkflowError> {
+        let state = self
+            .workflow_repository
+            .find_state(user_id, id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        self.workflow_repository
+            .delete_state(state.id)
+            .await
+            .map_err(WorkflowError::from)
+    }
+
+    pub async fn find_states(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<WorkflowStateResponse>, WorkflowError> {
+        self.workflow_repository
+            .find_states(user_id)
+            .await
+            .map_err(WorkflowError::from)
+            .map(|states| {
+                states
+                    .into_iter()
+                    .map(WorkflowStateResponse::from)
+                    .collect::<Vec<WorkflowStateResponse>>()
+            })
+    }
+
+    // renumber the states in the given order, states left out keep their relative order after them
+    pub async fn reorder_states(
+        &self,
+        user_id: Uuid,
+        order: Vec<Uuid>,
+    ) -> Result<Vec<WorkflowStateResponse>, WorkflowError> {
+        let mut states = self
+            .workflow_repository
+            .find_states(user_id)
+            .await
+            .map_err(WorkflowError::from)?;
+
+        if order
+            .iter()
+            .any(|id| !states.iter().any(|state| &state.id == id))
+        {
+            return Err(WorkflowError::NotFound);
+        }
+
+        states.sort_by_key(|state| {
+            (
+                order
+                    .iter()
+                    .position(|id| id == &state.id)
+                    .unwrap_or(order.len()),
+                state.position,
+            )
+        });
+
+        let mut reordered = Vec::with_capacity(states.len());
+        for (position, mut state) in states.into_iter().enumerate() {
+            if state.position != position as i32 {
+                state.position = position as i32;
+                state.updated_at = Utc::now();
+                state = self
+                    .workflow_repository
+                    .update_state(state)
+                    .await
+                    .map_err(WorkflowError::from)?;
+            }
+
+            reordered.push(WorkflowStateResponse::from(state));
+        }
+
+        Ok(reordered)
+    }
+
+    // group todos by state, todos without a state land in the first state of their derived category
+    pub async fn board(&self, user_id: Uuid) -> Result<BoardResponse, WorkflowError> {
+        let states = self
+            .workflow_repository
+            .find_states(user_id)
+            .await
+            .map_err(WorkflowError::from)?;
+
+        let todos = self
+            .todo_repository
+            .find_all(user_id)
+            .await
+            .map_err(WorkflowError::from)?;
+
+        let mut columns = states
+            .into_iter()
+            .map(|state| BoardColumn {
+                state: WorkflowStateResponse::from(state),
+                todos: vec![],
+            })
+            .collect::<Vec<BoardColumn>>();
+        let mut unassigned = vec![];
+
+        for todo in todos {
+            let category = if todo.is_completed {
+                StateCategory::Done
+            } else {
+                StateCategory::Todo
+            };
+
+            let column = todo
+                .state_id
+                .and_then(|state_id| columns.iter().position(|c| c.state.id == state_id))
+                .or_else(|| columns.iter().position(|c| c.state.category == category));
+
+            match column {
+                Some(index) => columns[index].todos.push(TodoResponse::from(todo)),
+                None => unassigned.push(TodoResponse::from(todo)),
+            }
+        }
+
+        Ok(BoardResponse {
+            columns,
+            unassigned,
+        })
+    }
+
+    pub async fn transition(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        dto: TransitionRequest,
+    ) -> Result<TransitionResponse, WorkflowError> {
+        let uow = self
+            .transactions
+            .begin()
+            .await
+            .map_err(WorkflowError::from)?;
+        let result = Self::apply_transition(uow.as_ref(), user_id, todo_id, dto).await;
+
+        Self::finish(uow, result, "transition")
+            .await
+            .map(TransitionResponse::from)
+    }
+
+    pub async fn find_transitions(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TransitionResponse>, WorkflowError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        self.workflow_repository
+            .find_transitions(user_id, todo_id)
+            .await
+            .map_err(WorkflowError::from)
+            .map(|transitions| {
+                transitions
+                    .into_iter()
+                    .map(TransitionResponse::from)
+                    .collect::<Vec<TransitionResponse>>()
+            })
+    }
+
+    // commits the unit of work when the change went through, otherwise rolls it back
+    async fn finish<R>(
+        uow: Box<dyn UnitOfWork>,
+        result: Result<R, WorkflowError>,
+        action: &str,
+    ) -> Result<R, WorkflowError> {
+        match result {
+            Ok(value) => {
+                uow.commit().await.map_err(WorkflowError::from)?;
+                Ok(value)
+            }
+            Err(err) => {
+                if let Err(err) = uow.rollback().await {
+                    tracing::error!("failed to roll back workflow {} : {}", action, err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    fn workflows(uow: &dyn UnitOfWork) -> Result<&dyn WorkflowRepository, WorkflowError> {
+        uow.workflows().ok_or_else(|| {
+            tracing::error!("workflows are not supported by this database backend");
+            WorkflowError::GeneralError
+        })
+    }
+
+    async fn apply_update_state(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        id: Uuid,
+        dto: UpdateStateRequest,
+    ) -> Result<WorkflowState, WorkflowError> {
+        let workflows = Self::workflows(uow)?;
+
+        let mut state = workflows
+            .find_state(user_id, id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        state.name = dto.name;
+        state.category = dto.category;
+        state.position = dto.position;
+        state.updated_at = Utc::now();
+
+        let state = workflows
+            .update_state(state)
+            .await
+            .map_err(WorkflowError::from)?;
+
+        // a new category completes or reopens the todos in this state
+        for todo in workflows
+            .align_todos(&state)
+            .await
+            .map_err(WorkflowError::from)?
+        {
+            uow.events()
+                .append(TodoEvent::new(TodoEventKind::Toggled, &todo))
+                .await
+                .map_err(WorkflowError::from)?;
+        }
+
+        Ok(state)
+    }
+
+    async fn apply_transition(
+        uow: &dyn UnitOfWork,
+        user_id: Uuid,
+        todo_id: Uuid,
+        dto: TransitionRequest,
+    ) -> Result<StateTransition, WorkflowError> {
+        let workflows = Self::workflows(uow)?;
+
+        let todo = uow
+            .todos()
+            .find_by_id(user_id, todo_id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        let state = workflows
+            .find_state(user_id, dto.state_id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        if todo.state_id == Some(state.id) {
+            return Err(WorkflowError::Conflict);
+        }
+
+        let transition = StateTransition {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id: todo.id,
+            from_state_id: todo.state_id,
+            to_state_id: state.id,
+            transitioned_at: Utc::now(),
+        };
+
+        let transition = workflows
+            .transition(transition, state.category.is_completed())
+            .await
+            .map_err(WorkflowError::from)?;
+
+        let moved = uow
+            .todos()
+            .find_by_id(user_id, todo.id)
+            .await
+            .map_err(WorkflowError::from)?
+            .ok_or(WorkflowError::NotFound)?;
+
+        let kind = if moved.is_completed != todo.is_completed {
+            TodoEventKind::Toggled
+        } else {
+            TodoEventKind::Updated
+        };
+
+        uow.events()
+            .append(TodoEvent::new(kind, &moved))
+            .await
+            .map_err(WorkflowError::from)?;
+
+        Ok(transition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        application::workflow::{
+            dto::{CreateStateRequest, TransitionRequest, UpdateStateRequest},
+            error::WorkflowError,
+            usecase::WorkflowUseCase,
+        },
+        domain::{
+            event::model::TodoEventKind,
+            shared::{
+                error::ModelError,
+                transaction::{Finished, MockTransactionManager, MockUnitOfWork},
+            },
+            todo::{model::Todo, repository::MockTodoRepository},
+            workflow::{
+                model::{StateCategory, WorkflowState},
+                repository::MockWorkflowRepository,
+            },
+        },
+    };
+
+    fn state(user_id: Uuid, name: &str, position: i32, category: StateCategory) -> WorkflowState {
+        WorkflowState {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            position,
+            category,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn todo(user_id: Uuid, is_completed: bool, state_id: Option<Uuid>) -> Todo {
+        Todo {
+            id: Uuid::new_v4(),
+            user_id,
+            title: "test".to_string(),
+            description: "hello world".to_string(),
+            is_completed,
+            state_id,
+            priority: 0,
+            tags: vec![],
+            project: None,
+            due_at: None,
+            snoozed_until: None,
+            tracked_seconds: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_state_appends_to_board() {
+        let mut repo = MockWorkflowRepository::new();
+        let user_id = Uuid::new_v4();
+
+        repo.expect_find_states().return_once(|uid| {
+            let states = vec![
+                state(uid, "Todo", 0, StateCategory::Todo),
+                state(uid, "Done", 4, StateCategory::Done),
+            ];
+            Box::pin(async move { Ok(states) })
+        });
+
+        repo.expect_create_state()
+            .withf(|s| s.position == 5)
+            .return_once(|s| Box::pin(async move { Ok(s) }));
+
+        let usecase = WorkflowUseCase::new(
+            repo,
+            MockTodoRepository::new(),
+            MockTransactionManager::new(),
+        );
+
+        let dto = CreateStateRequest {
+            name: "Blocked".to_string(),
+            category: StateCategory::Doing,
+            position: None,
+        };
+
+        assert!(usecase.create_state(user_id, dto).await.is_ok())
+    }
+
+    #[tokio::test]
+    async fn reorder_states_renumbers_positions() {
+        let mut repo = MockWorkflowRepository::new();
+        let user_id = Uuid::new_v4();
+        let states = vec![
+            state(user_id, "Todo", 0, StateCategory::Todo),
+            state(user_id, "Doing", 1, StateCategory::Doing),
+            state(user_id, "Done", 2, StateCategory::Done),
+        ];
+        let order = vec![states[2].id, states[0].id];
+
+        repo.expect_find_states()
+            .return_once(move |_| Box::pin(async move { Ok(states) }));
+        repo.expect_update_state()
+            .times(3)
+            .returning(|state| Box::pin(async move { Ok(state) }));
+
+        let usecase = WorkflowUseCase::new(
+            repo,
+            MockTodoRepository::new(),
+            MockTransactionManager::new(),
+        );
+
+        let states = usecase.reorder_states(user_id, order).await.unwrap();
+
+        let names = states
+            .iter()
+            .map(|state| (state.name.as_str(), state.position))
+            .collect::<Vec<(&str, i32)>>();
+        assert_eq!(names, vec![("Done", 0), ("Todo", 1), ("Doing", 2)]);
+    }
+
+    #[tokio::test]
+    async fn reorder_unknown_state() {
+        let mut repo = MockWorkflowRepository::new();
+        let user_id = Uuid::new_v4();
+
+        repo.expect_find_states().return_once(|uid| {
+            let states = vec![state(uid, "Todo", 0, StateCategory::Todo)];
+            Box::pin(async move { Ok(states) })
+        });
+        repo.expect_update_state().never();
+
+        let usecase = WorkflowUseCase::new(
+            repo,
+            MockTodoRepository::new(),
+            MockTransactionManager::new(),
+        );
+
+        let result = usecase.reorder_states(user_id, vec![Uuid::new_v4()]).await;
+
+        assert!(matches!(result.unwrap_err(), WorkflowError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn board_groups_todos_by_state() {
+        let mut repo = MockWorkflowRepository::new();
+        let mut todos = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+
+        let backlog = state(user_id, "Backlog", 0, StateCategory::Todo);
+        let review = state(user_id, "In review", 1, StateCategory::Doing);
+        let review_id = review.id;
+        let states = vec![backlog, review];
+
+        repo.expect_find_states()
+            .return_once(move |_| Box::pin(async move { Ok(states) }));
+
+        todos.expect_find_all().return_once(move |uid| {
+            let found = vec![
+                todo(uid, false, Some(review_id)),
+                todo(uid, false, None),
+                todo(uid, true, None),
+            ];
+            Box::pin(async move { Ok(found) })
+        });
+
+        let usecase = WorkflowUseCase::new(repo, todos, MockTransactionManager::new());
+
+        let board = usecase.board(user_id).await.unwrap();
+
+        assert_eq!(board.columns[0].todos.len(), 1);
+        assert_eq!(board.columns[1].todos.len(), 1);
+        assert_eq!(board.unassigned.len(), 1);
+        assert!(board.unassigned[0].is_completed);
+    }
+
+    #[tokio::test]
+    async fn update_state_records_toggled_todos() {
+        let mut uow = MockUnitOfWork::new();
+        let user_id = Uuid::new_v4();
+        let doing = state(user_id, "Doing", 1, StateCategory::Doing);
+        let doing_id = doing.id;
+
+        uow.workflows
+            .expect_find_state()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(doing)) }));
+        uow.workflows
+            .expect_update_state()
+            .withf(|s| s.category == StateCategory::Done)
+            .return_once(|s| Box::pin(async move { Ok(s) }));
+        uow.workflows.expect_align_todos().return_once(move |s| {
+            let toggled = vec![
+                todo(s.user_id, true, Some(doing_id)),
+                todo(s.user_id, true, Some(doing_id)),
+            ];
+            Box::pin(async move { Ok(toggled) })
+        });
+        uow.events
+            .expect_append()
+            .withf(|e| e.kind == TodoEventKind::Toggled)
+            .times(2)
+            .returning(|e| Box::pin(async move { Ok(e) }));
+
+        let finished = uow.finished.clone();
+        let usecase = WorkflowUseCase::new(
+            MockWorkflowRepository::new(),
+            MockTodoRepository::new(),
+            uow.begins(),
+        );
+
+        let dto = UpdateStateRequest {
+            name: "Shipped".to_string(),
+            category: StateCategory::Done,
+            position: 1,
+        };
+
+        assert!(usecase.update_state(user_id, doing_id, dto).await.is_ok());
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
+    }
+
+    #[tokio::test]
+    async fn transition_derives_completed_flag() {
+        let mut uow = MockUnitOfWork::new();
+        let user_id = Uuid::new_v4();
+        let done = state(user_id, "Shipped", 3, StateCategory::Done);
+        let done_id = done.id;
+
+        // read once before the move and once afterwards for the change event
+        let mut reads = 0;
+        uow.todos
+            .expect_find_by_id()
+            .times(2)
+            .returning(move |uid, _| {
+                reads += 1;
+                let found = todo(uid, reads > 1, None);
+                Box::pin(async move { Ok(Some(found)) })
+            });
+
+        uow.events
+            .expect_append()
+            .withf(|e| e.kind == TodoEventKind::Toggled)
+            .times(1)
+            .returning(|e| Box::pin(async move { Ok(e) }));
+
+        uow.workflows
+            .expect_find_state()
+            .withf(move |_, sid| sid == &done_id)
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(done)) }));
+
+        uow.workflows
+            .expect_transition()
+            .withf(move |t, is_completed| t.to_state_id == done_id && *is_completed)
+            .return_once(|t, _| Box::pin(async move { Ok(t) }));
+
+        let finished = uow.finished.clone();
+        let usecase = WorkflowUseCase::new(
+            MockWorkflowRepository::new(),
+            MockTodoRepository::new(),
+            uow.begins(),
+        );
+
+        let result = usecase
+            .transition(
+                user_id,
+                Uuid::new_v4(),
+                TransitionRequest { state_id: done_id },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.from_state_id, None);
+        assert_eq!(result.to_state_id, done_id);
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::Committed));
+    }
+
+    #[tokio::test]
+    async fn transition_rolls_back_without_event() {
+        let mut uow = MockUnitOfWork::new();
+        let user_id = Uuid::new_v4();
+        let doing = state(user_id, "Doing", 1, StateCategory::Doing);
+        let doing_id = doing.id;
+
+        uow.todos
+            .expect_find_by_id()
+            .times(2)
+            .returning(|uid, _| Box::pin(async move { Ok(Some(todo(uid, false, None))) }));
+        uow.workflows
+            .expect_find_state()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(doing)) }));
+        uow.workflows
+            .expect_transition()
+            .return_once(|t, _| Box::pin(async move { Ok(t) }));
+        uow.events.expect_append().return_once(|_| {
+            Box::pin(async { Err(ModelError::Database("connection lost".to_string())) })
+        });
+
+        let finished = uow.finished.clone();
+        let usecase = WorkflowUseCase::new(
+            MockWorkflowRepository::new(),
+            MockTodoRepository::new(),
+            uow.begins(),
+        );
+
+        let result = usecase
+            .transition(
+                user_id,
+                Uuid::new_v4(),
+                TransitionRequest { state_id: doing_id },
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), WorkflowError::GeneralError));
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+
+    #[tokio::test]
+    async fn transition_to_same_state() {
+        let mut uow = MockUnitOfWork::new();
+        let user_id = Uuid::new_v4();
+        let doing = state(user_id, "Doing", 1, StateCategory::Doing);
+        let doing_id = doing.id;
+
+        uow.todos.expect_find_by_id().return_once(move |uid, _| {
+            Box::pin(async move { Ok(Some(todo(uid, false, Some(doing_id)))) })
+        });
+
+        uow.workflows
+            .expect_find_state()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(doing)) }));
+        uow.workflows.expect_transition().never();
+
+        let finished = uow.finished.clone();
+        let usecase = WorkflowUseCase::new(
+            MockWorkflowRepository::new(),
+            MockTodoRepository::new(),
+            uow.begins(),
+        );
+
+        let result = usecase
+            .transition(
+                user_id,
+                Uuid::new_v4(),
+                TransitionRequest { state_id: doing_id },
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), WorkflowError::Conflict));
+        assert_eq!(*finished.lock().unwrap(), Some(Finished::RolledBack));
+    }
+
+    #[tokio::test]
+    async fn transition_unknown_state() {
+        let mut uow = MockUnitOfWork::new();
+
+        uow.todos
+            .expect_find_by_id()
+            .return_once(|uid, _| Box::pin(async move { Ok(Some(todo(uid, false, None))) }));
+
+        uow.workflows
+            .expect_find_state()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+
+        let usecase = WorkflowUseCase::new(
+            MockWorkflowRepository::new(),
+            MockTodoRepository::new(),
+            uow.begins(),
+        );
+
+        let result = usecase
+            .transition(
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                TransitionRequest {
+                    state_id: Uuid::new_v4(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result.unwrap_err(), WorkflowError::NotFound));
+    }
+}
@@ -0,0 +1,164 @@
+use reqwest::{Method, Response, StatusCode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+use crate::{error::CliError, session::Session};
+
+pub const DEFAULT_SERVER: &str = "http://127.0.0.1:3000/api/v1";
+
+// every rest response is wrapped in this envelope
+#[derive(Debug, Deserialize)]
+struct Envelope<T> {
+    message: String,
+    data: Option<T>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+pub struct ApiClient {
+    http: reqwest::Client,
+    server: String,
+    session: Option<Session>,
+}
+
+impl ApiClient {
+    // the server flag wins, otherwise the server of the last login is used
+    pub fn new(server: Option<String>) -> Result<Self, CliError> {
+        let session = Session::load()?;
+
+        let server = server
+            .or_else(|| session.as_ref().map(|session| session.server.clone()))
+            .unwrap_or_else(|| DEFAULT_SERVER.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            server,
+            session,
+        })
+    }
+
+    pub async fn login(&mut self, email: &str, password: &str) -> Result<AuthResponse, CliError> {
+        let response = self
+            .http
+            .post(self.url("/auth/login"))
+            .json(&json!({ "email": email, "password": password }))
+            .send()
+            .await?;
+
+        let auth: AuthResponse = parse(response).await?.ok_or(CliError::NotLoggedIn)?;
+        self.store(&auth)?;
+
+        Ok(auth)
+    }
+
+    // the refresh token is revoked on the server when possible, the local session is always removed
+    pub async fn logout(&mut self) -> Result<(), CliError> {
+        if self.session.is_some() {
+            let _ = self
+                .send::<Value>(Method::DELETE, "/auth/logout", None)
+                .await;
+        }
+
+        self.session = None;
+        Session::clear()
+    }
+
+    pub async fn get<T: DeserializeOwned>(&mut self, path: &str) -> Result<Option<T>, CliError> {
+        self.send(Method::GET, path, None).await
+    }
+
+    pub async fn send<T: DeserializeOwned>(
+        &mut self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<Option<T>, CliError> {
+        let response = self.request(method.clone(), path, body.as_ref()).await?;
+
+        // access tokens are short lived, refresh once and replay the request
+        if response.status() == StatusCode::UNAUTHORIZED {
+            self.refresh().await?;
+
+            let response = self.request(method, path, body.as_ref()).await?;
+            return parse(response).await;
+        }
+
+        parse(response).await
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Response, CliError> {
+        let session = self.session.as_ref().ok_or(CliError::NotLoggedIn)?;
+
+        let mut request = self
+            .http
+            .request(method, self.url(path))
+            .bearer_auth(&session.access_token);
+
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        Ok(request.send().await?)
+    }
+
+    async fn refresh(&mut self) -> Result<(), CliError> {
+        let session = self.session.as_ref().ok_or(CliError::NotLoggedIn)?;
+
+        let response = self
+            .http
+            .post(self.url("/auth/refresh"))
+            .json(&json!({ "token": session.refresh_token }))
+            .send()
+            .await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(CliError::SessionExpired);
+        }
+
+        let auth: AuthResponse = parse(response).await?.ok_or(CliError::SessionExpired)?;
+        self.store(&auth)
+    }
+
+    fn store(&mut self, auth: &AuthResponse) -> Result<(), CliError> {
+        let session = Session {
+            server: self.server.clone(),
+            access_token: auth.access_token.clone(),
+            refresh_token: auth.refresh_token.clone(),
+        };
+
+        session.save()?;
+        self.session = Some(session);
+
+        Ok(())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.server, path)
+    }
+}
+
+async fn parse<T: DeserializeOwned>(response: Response) -> Result<Option<T>, CliError> {
+    let status = response.status();
+    let body = response.text().await?;
+
+    match serde_json::from_str::<Envelope<T>>(&body) {
+        Ok(envelope) if status.is_success() => Ok(envelope.data),
+        Ok(envelope) => Err(CliError::Api(envelope.message)),
+        // rejections from axum extractors are plain text
+        Err(_) if !status.is_success() && !body.is_empty() => Err(CliError::Api(body)),
+        Err(_) => Err(CliError::Api(format!("unexpected response ({})", status))),
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("not logged in, run `todo-cli login` first")]
+    NotLoggedIn,
+
+    #[error("session expired, run `todo-cli login` again")]
+    SessionExpired,
+
+    #[error("{0}")]
+    Api(String),
+
+    #[error("no todo matches `{0}`")]
+    NoMatch(String),
+
+    #[error("`{0}` matches more than one todo, use a longer id")]
+    Ambiguous(String),
+
+    #[error("{0}")]
+    Invalid(String),
+
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("session file error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("session file error: {0}")]
+    Json(#[from] serde_json::Error),
+}
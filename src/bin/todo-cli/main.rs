@@ -0,0 +1,293 @@
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand};
+use reqwest::Method;
+use serde_json::json;
+
+use crate::{
+    client::ApiClient,
+    error::CliError,
+    todo::{Filter, Todo},
+};
+
+mod client;
+mod error;
+mod output;
+mod session;
+mod todo;
+
+/// Command line client for the todo-rs REST API
+#[derive(Debug, Parser)]
+#[command(name = "todo-cli", version)]
+struct Cli {
+    /// Base url of the api, defaults to the server of the last login
+    #[arg(long, global = true, env = "TODO_API_URL")]
+    server: Option<String>,
+
+    /// Print JSON instead of a table
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Log in and keep the tokens for the next commands
+    Login {
+        #[arg(long)]
+        email: String,
+
+        /// Prompted for when not given
+        #[arg(long, env = "TODO_PASSWORD", hide_env_values = true)]
+        password: Option<String>,
+    },
+
+    /// Revoke the refresh token and forget the stored session
+    Logout,
+
+    /// Create a todo
+    Add {
+        title: String,
+
+        #[arg(short, long, default_value = "")]
+        description: String,
+
+        #[arg(short, long, default_value_t = 0)]
+        priority: i16,
+
+        /// Can be repeated
+        #[arg(short, long = "tag")]
+        tags: Vec<String>,
+
+        #[arg(long)]
+        project: Option<String>,
+
+        /// RFC 3339, e.g. 2025-01-31T17:00:00Z
+        #[arg(long)]
+        due: Option<DateTime<Utc>>,
+    },
+
+    /// List todos
+    List(ListArgs),
+
+    /// Mark a todo as done
+    Done { id: String },
+
+    /// Change a todo, fields that are not given are kept
+    Edit {
+        id: String,
+
+        #[arg(long)]
+        title: Option<String>,
+
+        #[arg(short, long)]
+        description: Option<String>,
+
+        #[arg(short, long)]
+        priority: Option<i16>,
+
+        /// Replaces the tags, can be repeated
+        #[arg(short, long = "tag")]
+        tags: Option<Vec<String>>,
+
+        #[arg(long, conflicts_with = "clear_project")]
+        project: Option<String>,
+
+        #[arg(long)]
+        clear_project: bool,
+
+        #[arg(long, conflicts_with = "clear_due")]
+        due: Option<DateTime<Utc>>,
+
+        #[arg(long)]
+        clear_due: bool,
+    },
+
+    /// Delete a todo
+    Remove { id: String },
+}
+
+#[derive(Debug, Args)]
+struct ListArgs {
+    /// Only completed todos
+    #[arg(long, conflicts_with = "pending")]
+    done: bool,
+
+    /// Only todos that are not completed
+    #[arg(long)]
+    pending: bool,
+
+    /// Only todos having every given tag
+    #[arg(short, long = "tag")]
+    tags: Vec<String>,
+
+    #[arg(short, long)]
+    priority: Option<i16>,
+
+    /// Case insensitive match on title and description
+    #[arg(short, long)]
+    search: Option<String>,
+
+    /// Only pending todos past their due date
+    #[arg(long)]
+    overdue: bool,
+}
+
+impl From<ListArgs> for Filter {
+    fn from(value: ListArgs) -> Self {
+        Self {
+            completed: match (value.done, value.pending) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            },
+            tags: value.tags,
+            priority: value.priority,
+            search: value.search,
+            overdue: value.overdue,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    if let Err(err) = run(cli).await {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), CliError> {
+    let mut client = ApiClient::new(cli.server)?;
+    let json = cli.json;
+
+    match cli.command {
+        Command::Login { email, password } => {
+            let password = match password {
+                Some(password) => password,
+                None => rpassword::prompt_password("password: ")?,
+            };
+
+            let auth = client.login(&email, &password).await?;
+            output::message(&format!("logged in as {}", auth.name), json)
+        }
+        Command::Logout => {
+            client.logout().await?;
+            output::message("logged out", json)
+        }
+        Command::Add {
+            title,
+            description,
+            priority,
+            tags,
+            project,
+            due,
+        } => {
+            let body = json!({
+                "title": title,
+                "description": description,
+                "priority": priority,
+                "tags": tags,
+                "project": project,
+                "due_at": due,
+            });
+
+            let todo: Option<Todo> = client.send(Method::POST, "/todo", Some(body)).await?;
+
+            match todo {
+                Some(todo) => output::todo(&todo, json),
+                None => output::message("todo created", json),
+            }
+        }
+        Command::List(args) => {
+            let filter = Filter::from(args);
+            let todos = find_all(&mut client).await?;
+
+            let todos = todos
+                .into_iter()
+                .filter(|todo| filter.matches(todo))
+                .collect::<Vec<Todo>>();
+
+            output::todos(&todos, json)
+        }
+        Command::Done { id } => {
+            let todo = find(&mut client, &id).await?;
+
+            // the api only toggles, so a completed todo is left alone
+            if !todo.is_completed {
+                client
+                    .send::<serde_json::Value>(
+                        Method::PATCH,
+                        &format!("/todo/{}/toggle", todo.id),
+                        None,
+                    )
+                    .await?;
+            }
+
+            let todo = find(&mut client, &todo.id.to_string()).await?;
+            output::todo(&todo, json)
+        }
+        Command::Edit {
+            id,
+            title,
+            description,
+            priority,
+            tags,
+            project,
+            clear_project,
+            due,
+            clear_due,
+        } => {
+            let todo = find(&mut client, &id).await?;
+
+            let project = match (project, clear_project) {
+                (_, true) => None,
+                (Some(project), _) => Some(project),
+                _ => todo.project,
+            };
+
+            let due_at = match (due, clear_due) {
+                (_, true) => None,
+                (Some(due), _) => Some(due),
+                _ => todo.due_at,
+            };
+
+            let body = json!({
+                "title": title.unwrap_or(todo.title),
+                "description": description.unwrap_or(todo.description),
+                "priority": priority.unwrap_or(todo.priority),
+                "tags": tags.unwrap_or(todo.tags),
+                "project": project,
+                "due_at": due_at,
+            });
+
+            client
+                .send::<serde_json::Value>(Method::PUT, &format!("/todo/{}", todo.id), Some(body))
+                .await?;
+
+            let todo = find(&mut client, &todo.id.to_string()).await?;
+            output::todo(&todo, json)
+        }
+        Command::Remove { id } => {
+            let todo = find(&mut client, &id).await?;
+
+            client
+                .send::<serde_json::Value>(Method::DELETE, &format!("/todo/{}", todo.id), None)
+                .await?;
+
+            output::message(&format!("removed {}", todo.id), json)
+        }
+    }
+}
+
+async fn find_all(client: &mut ApiClient) -> Result<Vec<Todo>, CliError> {
+    Ok(client.get::<Vec<Todo>>("/todo").await?.unwrap_or_default())
+}
+
+async fn find(client: &mut ApiClient, id: &str) -> Result<Todo, CliError> {
+    let todos = find_all(client).await?;
+    todo::resolve(&todos, id).cloned()
+}
@@ -0,0 +1,60 @@
+use comfy_table::{Table, presets::UTF8_FULL_CONDENSED};
+use serde::Serialize;
+
+use crate::{error::CliError, todo::Todo};
+
+const SHORT_ID: usize = 8;
+
+pub fn todos(todos: &[Todo], json: bool) -> Result<(), CliError> {
+    if json {
+        return print_json(&todos);
+    }
+
+    if todos.is_empty() {
+        println!("no todos");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_header(vec!["ID", "DONE", "PRIORITY", "TITLE", "TAGS", "DUE"]);
+
+    for todo in todos {
+        table.add_row(vec![
+            todo.id.to_string()[..SHORT_ID].to_string(),
+            if todo.is_completed { "x" } else { "" }.to_string(),
+            todo.priority.to_string(),
+            todo.title.clone(),
+            todo.tags.join(", "),
+            todo.due_at
+                .map(|due| due.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default(),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+pub fn todo(todo: &Todo, json: bool) -> Result<(), CliError> {
+    if json {
+        return print_json(todo);
+    }
+
+    todos(std::slice::from_ref(todo), false)
+}
+
+pub fn message(message: &str, json: bool) -> Result<(), CliError> {
+    if json {
+        return print_json(&serde_json::json!({ "message": message }));
+    }
+
+    println!("{message}");
+    Ok(())
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), CliError> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::CliError;
+
+// tokens from the last login, kept in the user's config directory
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Session {
+    pub server: String,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+impl Session {
+    pub fn load() -> Result<Option<Self>, CliError> {
+        let path = path()?;
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    pub fn save(&self) -> Result<(), CliError> {
+        let path = path()?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+
+        // the refresh token is as good as a password, keep it private to the user
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn clear() -> Result<(), CliError> {
+        let path = path()?;
+
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+// `TODO_CLI_SESSION` overrides the default `<config dir>/todo-cli/session.json`
+fn path() -> Result<PathBuf, CliError> {
+    if let Ok(path) = std::env::var("TODO_CLI_SESSION") {
+        return Ok(PathBuf::from(path));
+    }
+
+    dirs::config_dir()
+        .map(|dir| dir.join("todo-cli").join("session.json"))
+        .ok_or_else(|| CliError::Invalid("unable to find the config directory".to_string()))
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::CliError;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Todo {
+    pub id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub is_completed: bool,
+    pub state_id: Option<Uuid>,
+    pub priority: i16,
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub tracked_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// the list endpoint returns everything, filters are applied on this side
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub completed: Option<bool>,
+    pub tags: Vec<String>,
+    pub priority: Option<i16>,
+    pub search: Option<String>,
+    pub overdue: bool,
+}
+
+impl Filter {
+    pub fn matches(&self, todo: &Todo) -> bool {
+        if self
+            .completed
+            .is_some_and(|completed| completed != todo.is_completed)
+        {
+            return false;
+        }
+
+        if !self.tags.iter().all(|tag| todo.tags.contains(tag)) {
+            return false;
+        }
+
+        if self
+            .priority
+            .is_some_and(|priority| priority != todo.priority)
+        {
+            return false;
+        }
+
+        if let Some(search) = &self.search {
+            let search = search.to_lowercase();
+
+            if !todo.title.to_lowercase().contains(&search)
+                && !todo.description.to_lowercase().contains(&search)
+            {
+                return false;
+            }
+        }
+
+        if self.overdue
+            && !todo
+                .due_at
+                .is_some_and(|due| !todo.is_completed && due < Utc::now())
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+// ids may be shortened to any unique prefix, as printed by `list`
+pub fn resolve<'t>(todos: &'t [Todo], id: &str) -> Result<&'t Todo, CliError> {
+    let id = id.to_lowercase();
+    let mut found = todos
+        .iter()
+        .filter(|todo| todo.id.to_string().starts_with(&id));
+
+    match (found.next(), found.next()) {
+        (Some(todo), None) => Ok(todo),
+        (Some(_), Some(_)) => Err(CliError::Ambiguous(id)),
+        (None, _) => Err(CliError::NoMatch(id)),
+    }
+}
@@ -1,3 +1,15 @@
+pub mod event;
+pub mod idempotency;
+pub mod lockout;
+pub mod mail;
+pub mod notification;
+pub mod password_reset;
+pub mod rate_limit;
+pub mod reminder;
 pub mod shared;
+pub mod template;
+pub mod time_entry;
 pub mod todo;
 pub mod user;
+pub mod webhook;
+pub mod workflow;
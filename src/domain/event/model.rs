@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::todo::model::Todo;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum TodoEventKind {
+    Created,
+    Updated,
+    Toggled,
+    Deleted,
+}
+
+impl TodoEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Toggled => "toggled",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+// append-only change log of todos, the id is assigned by the store and only ever grows
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct TodoEvent {
+    pub id: i64,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub kind: TodoEventKind,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TodoEvent {
+    // the payload is a snapshot of the todo right after the change
+    pub fn new(kind: TodoEventKind, todo: &Todo) -> Self {
+        Self {
+            id: 0,
+            user_id: todo.user_id,
+            todo_id: todo.id,
+            kind,
+            payload: serde_json::to_value(todo).unwrap_or_default(),
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{event::model::TodoEvent, shared::error::ModelError};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait EventRepository: Send + Sync {
+    async fn append(&self, event: TodoEvent) -> Result<TodoEvent, ModelError>;
+
+    // events of a single user with an id greater than `after`, oldest first
+    async fn find_since(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError>;
+
+    // events of every user with an id greater than `after`, oldest first
+    async fn find_after(&self, after: i64, limit: i64) -> Result<Vec<TodoEvent>, ModelError>;
+    async fn last_id(&self) -> Result<i64, ModelError>;
+
+    // latest event of each todo among the user's next `limit` events after `after`
+    async fn find_changes(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError>;
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Option<TodoEvent>, ModelError>;
+}
+
+// lets use cases hold whichever backend was configured behind an `Arc<dyn EventRepository>`
+#[async_trait]
+impl<T: EventRepository + ?Sized> EventRepository for Arc<T> {
+    async fn append(&self, event: TodoEvent) -> Result<TodoEvent, ModelError> {
+        (**self).append(event).await
+    }
+
+    async fn find_since(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        (**self).find_since(user_id, after, limit).await
+    }
+
+    async fn find_after(&self, after: i64, limit: i64) -> Result<Vec<TodoEvent>, ModelError> {
+        (**self).find_after(after, limit).await
+    }
+
+    async fn last_id(&self) -> Result<i64, ModelError> {
+        (**self).last_id().await
+    }
+
+    async fn find_changes(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        (**self).find_changes(user_id, after, limit).await
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Option<TodoEvent>, ModelError> {
+        (**self).find_latest(user_id, todo_id).await
+    }
+}
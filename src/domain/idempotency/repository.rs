@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::{idempotency::model::IdempotencyRecord, shared::error::ModelError};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait IdempotencyRepository: Send + Sync {
+    // claim the key for a new request, false when a live record already holds it
+    async fn reserve(&self, record: IdempotencyRecord) -> Result<bool, ModelError>;
+    async fn find(&self, scope: &str, key: &str) -> Result<Option<IdempotencyRecord>, ModelError>;
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        status_code: i32,
+        content_type: Option<String>,
+        body: Vec<u8>,
+    ) -> Result<(), ModelError>;
+    async fn release(&self, scope: &str, key: &str) -> Result<(), ModelError>;
+    async fn purge_expired(&self) -> Result<u64, ModelError>;
+}
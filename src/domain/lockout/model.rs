@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// failed logins since the last successful one, locked once they reach the configured threshold
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LoginFailures {
+    pub user_id: Uuid,
+    pub failures: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoginFailures {
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum LockoutEventKind {
+    Locked,
+    Unlocked,
+}
+
+// audit trail of locks and unlocks, `locked_until` is empty for an unlock
+#[derive(Debug, Clone)]
+pub struct LockoutEvent {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: LockoutEventKind,
+    pub failures: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LockoutEvent {
+    pub fn new(
+        kind: LockoutEventKind,
+        user_id: Uuid,
+        failures: i32,
+        locked_until: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            kind,
+            failures,
+            locked_until,
+            created_at: Utc::now(),
+        }
+    }
+}
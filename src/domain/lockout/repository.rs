@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    lockout::model::{LockoutEvent, LoginFailures},
+    shared::error::ModelError,
+};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait LockoutRepository: Send + Sync {
+    // none while the user has no failed login
+    async fn find(&self, user_id: Uuid) -> Result<Option<LoginFailures>, ModelError>;
+    // count a failed login, returns the failures including this one
+    async fn record_failure(&self, user_id: Uuid) -> Result<i32, ModelError>;
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<(), ModelError>;
+    // forget the failures and any lock
+    async fn reset(&self, user_id: Uuid) -> Result<(), ModelError>;
+    async fn record_event(&self, event: LockoutEvent) -> Result<(), ModelError>;
+}
+
+#[async_trait]
+impl<T: LockoutRepository + ?Sized> LockoutRepository for Arc<T> {
+    async fn find(&self, user_id: Uuid) -> Result<Option<LoginFailures>, ModelError> {
+        (**self).find(user_id).await
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<i32, ModelError> {
+        (**self).record_failure(user_id).await
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<(), ModelError> {
+        (**self).lock(user_id, until).await
+    }
+
+    async fn reset(&self, user_id: Uuid) -> Result<(), ModelError> {
+        (**self).reset(user_id).await
+    }
+
+    async fn record_event(&self, event: LockoutEvent) -> Result<(), ModelError> {
+        (**self).record_event(event).await
+    }
+}
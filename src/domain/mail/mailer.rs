@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+// a plain text message to a single recipient, the sender is configured on the mailer
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait Mailer: Send + Sync {
+    // returns a description of why the message could not be handed over
+    async fn send(&self, mail: Mail) -> Result<(), String>;
+}
+
+#[async_trait]
+impl<T: Mailer + ?Sized> Mailer for Arc<T> {
+    async fn send(&self, mail: Mail) -> Result<(), String> {
+        (**self).send(mail).await
+    }
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{notification::model::Notification, shared::error::ModelError};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait NotificationRepository: Send + Sync {
+    async fn find_all(&self, user_id: Uuid, unread: bool) -> Result<Vec<Notification>, ModelError>;
+    async fn mark_read(&self, user_id: Uuid, id: Uuid) -> Result<Notification, ModelError>;
+}
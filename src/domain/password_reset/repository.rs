@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{password_reset::model::PasswordReset, shared::error::ModelError};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait PasswordResetRepository: Send + Sync {
+    // replaces any earlier reset of the same user, only the latest link works
+    async fn create(&self, reset: PasswordReset) -> Result<(), ModelError>;
+    // removes the reset so it can't be used twice, none when it never existed or was used already
+    async fn take(&self, token_hash: &str) -> Result<Option<PasswordReset>, ModelError>;
+}
+
+#[async_trait]
+impl<T: PasswordResetRepository + ?Sized> PasswordResetRepository for Arc<T> {
+    async fn create(&self, reset: PasswordReset) -> Result<(), ModelError> {
+        (**self).create(reset).await
+    }
+
+    async fn take(&self, token_hash: &str) -> Result<Option<PasswordReset>, ModelError> {
+        (**self).take(token_hash).await
+    }
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+// attempts counted under a key in the current fixed window, a new window starts at `expires_at`
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RateLimitCounter {
+    pub key: String,
+    pub hits: i32,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::domain::{rate_limit::model::RateLimitCounter, shared::error::ModelError};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait RateLimitRepository: Send + Sync {
+    // count one attempt, an expired counter starts over with a window of `window`
+    async fn hit(&self, key: &str, window: Duration) -> Result<RateLimitCounter, ModelError>;
+    async fn purge_expired(&self) -> Result<u64, ModelError>;
+}
+
+#[async_trait]
+impl<T: RateLimitRepository + ?Sized> RateLimitRepository for Arc<T> {
+    async fn hit(&self, key: &str, window: Duration) -> Result<RateLimitCounter, ModelError> {
+        (**self).hit(key, window).await
+    }
+
+    async fn purge_expired(&self) -> Result<u64, ModelError> {
+        (**self).purge_expired().await
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub minutes_before_due: Option<i32>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    // moment the reminder fires, a relative reminder follows the current due date of its todo
+    pub fn fire_at(&self, due_at: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        match (self.remind_at, self.minutes_before_due) {
+            (Some(remind_at), _) => Some(remind_at),
+            (None, Some(minutes)) => due_at.map(|due| due - Duration::minutes(minutes.into())),
+            (None, None) => None,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    notification::model::Notification, reminder::model::Reminder, shared::error::ModelError,
+};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait ReminderRepository: Send + Sync {
+    async fn create(&self, reminder: Reminder) -> Result<Reminder, ModelError>;
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Reminder>, ModelError>;
+    async fn find_by_todo(&self, user_id: Uuid, todo_id: Uuid)
+    -> Result<Vec<Reminder>, ModelError>;
+
+    // claim up to `limit` due reminders and turn them into notifications, every reminder is
+    // delivered once even when several instances call this at the same time
+    async fn fire_due(&self, limit: i64) -> Result<Vec<Notification>, ModelError>;
+}
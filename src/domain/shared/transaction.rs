@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    event::repository::EventRepository, shared::error::ModelError,
+    todo::repository::TodoRepository, user::repository::UserRepository,
+    workflow::repository::WorkflowRepository,
+};
+
+// repositories bound to a single transaction, their writes are only visible to others after
+// `commit`, dropping the unit of work without committing rolls it back
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn todos(&self) -> &dyn TodoRepository;
+    fn users(&self) -> &dyn UserRepository;
+    fn events(&self) -> &dyn EventRepository;
+    // only the postgres backend stores workflows
+    fn workflows(&self) -> Option<&dyn WorkflowRepository>;
+
+    async fn commit(self: Box<Self>) -> Result<(), ModelError>;
+    async fn rollback(self: Box<Self>) -> Result<(), ModelError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait TransactionManager: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError>;
+}
+
+#[async_trait]
+impl<T: TransactionManager + ?Sized> TransactionManager for Arc<T> {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError> {
+        (**self).begin().await
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Finished {
+    Committed,
+    RolledBack,
+}
+
+// hands out mocked repositories and records how the unit of work was finished
+#[cfg(test)]
+pub struct MockUnitOfWork {
+    pub todos: crate::domain::todo::repository::MockTodoRepository,
+    pub users: crate::domain::user::repository::MockUserRepository,
+    pub events: crate::domain::event::repository::MockEventRepository,
+    pub workflows: crate::domain::workflow::repository::MockWorkflowRepository,
+    pub finished: Arc<std::sync::Mutex<Option<Finished>>>,
+}
+
+#[cfg(test)]
+impl MockUnitOfWork {
+    pub fn new() -> Self {
+        Self {
+            todos: Default::default(),
+            users: Default::default(),
+            events: Default::default(),
+            workflows: Default::default(),
+            finished: Default::default(),
+        }
+    }
+
+    // a transaction manager whose single transaction is this unit of work
+    pub fn begins(self) -> MockTransactionManager {
+        let mut transactions = MockTransactionManager::new();
+
+        transactions.expect_begin().return_once(move || {
+            Box::pin(async move { Ok(Box::new(self) as Box<dyn UnitOfWork>) })
+        });
+
+        transactions
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl UnitOfWork for MockUnitOfWork {
+    fn todos(&self) -> &dyn TodoRepository {
+        &self.todos
+    }
+
+    fn users(&self) -> &dyn UserRepository {
+        &self.users
+    }
+
+    fn events(&self) -> &dyn EventRepository {
+        &self.events
+    }
+
+    fn workflows(&self) -> Option<&dyn WorkflowRepository> {
+        Some(&self.workflows)
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), ModelError> {
+        *self.finished.lock().unwrap() = Some(Finished::Committed);
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), ModelError> {
+        *self.finished.lock().unwrap() = Some(Finished::RolledBack);
+        Ok(())
+    }
+}
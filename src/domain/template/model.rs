@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct Template {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub priority: i16,
+    pub due_offset_minutes: Option<i64>,
+    pub checklist: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Template {
+    // replace every `{{name}}` placeholder found in `vars`, unknown placeholders are kept as is
+    pub fn render(text: &str, vars: &HashMap<String, String>) -> String {
+        let mut rendered = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start..].find("}}") else {
+                break;
+            };
+
+            rendered.push_str(&rest[..start]);
+
+            let key = rest[start + 2..start + end].trim();
+            match vars.get(key) {
+                Some(value) => rendered.push_str(value),
+                None => rendered.push_str(&rest[start..start + end + 2]),
+            }
+
+            rest = &rest[start + end + 2..];
+        }
+
+        rendered.push_str(rest);
+        rendered
+    }
+}
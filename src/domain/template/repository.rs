@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{shared::error::ModelError, template::model::Template};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait TemplateRepository: Send + Sync {
+    async fn create(&self, template: Template) -> Result<Template, ModelError>;
+    async fn update(&self, template: Template) -> Result<Template, ModelError>;
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError>;
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Template>, ModelError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Template>, ModelError>;
+}
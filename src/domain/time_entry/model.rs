@@ -0,0 +1,33 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TimeEntry {
+    pub fn duration(&self, now: DateTime<Utc>) -> Duration {
+        self.ended_at.unwrap_or(now) - self.started_at
+    }
+
+    // tracked time between `from` and `to`, a running entry counts until `now`
+    pub fn overlap(&self, from: DateTime<Utc>, to: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+        let start = self.started_at.max(from);
+        let end = self.ended_at.unwrap_or(now).min(to);
+
+        if end > start {
+            end - start
+        } else {
+            Duration::zero()
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{shared::error::ModelError, time_entry::model::TimeEntry};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait TimeEntryRepository: Send + Sync {
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError>;
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ModelError>;
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ModelError>;
+    async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ModelError>;
+    async fn find_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeEntry>, ModelError>;
+}
+
+#[async_trait]
+impl<T: TimeEntryRepository + ?Sized> TimeEntryRepository for Arc<T> {
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        (**self).create(entry).await
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        (**self).update(entry).await
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        (**self).delete(user_id, id).await
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        (**self).find_by_id(user_id, id).await
+    }
+
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        (**self).find_running(user_id).await
+    }
+
+    async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        (**self).find_by_todo(user_id, todo_id).await
+    }
+
+    async fn find_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        (**self).find_between(user_id, from, to).await
+    }
+}
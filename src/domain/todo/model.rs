@@ -1,14 +1,21 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Todo {
     pub id: Uuid,
     pub user_id: Uuid,
     pub title: String,
     pub description: String,
     pub is_completed: bool,
+    pub state_id: Option<Uuid>,
+    pub priority: i16,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub tracked_seconds: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::{shared::error::ModelError, todo::model::Todo};
@@ -8,8 +11,56 @@ use crate::domain::{shared::error::ModelError, todo::model::Todo};
 pub trait TodoRepository: Send + Sync {
     async fn create(&self, todo: Todo) -> Result<Todo, ModelError>;
     async fn update(&self, todo: Todo) -> Result<Todo, ModelError>;
-    async fn delete(&self, id: Uuid) -> Result<(), ModelError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError>;
     async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError>;
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError>;
     async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError>;
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError>;
     async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError>;
 }
+
+// lets use cases hold whichever backend was configured behind an `Arc<dyn TodoRepository>`
+#[async_trait]
+impl<T: TodoRepository + ?Sized> TodoRepository for Arc<T> {
+    async fn create(&self, todo: Todo) -> Result<Todo, ModelError> {
+        (**self).create(todo).await
+    }
+
+    async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
+        (**self).update(todo).await
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        (**self).delete(user_id, id).await
+    }
+
+    async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        (**self).toggle(user_id, id).await
+    }
+
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        (**self).snooze(user_id, id, until).await
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        (**self).find_all(user_id).await
+    }
+
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        (**self).find_all_including_snoozed(user_id).await
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
+        (**self).find_by_id(user_id, id).await
+    }
+}
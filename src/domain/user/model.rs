@@ -10,6 +10,10 @@ pub struct User {
     pub email: String,
     pub password: String,
     pub token: Option<String>,
+    // none until the owner followed the link sent to the address
+    pub email_verified_at: Option<DateTime<Utc>>,
+    // access tokens issued before it are revoked, none while the password was never changed
+    pub password_changed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -20,6 +24,6 @@ impl User {
     }
 
     pub fn verify_password(&self, password: String) -> bool {
-        bcrypt::verify(password, self.password.as_str()).is_ok()
+        bcrypt::verify(password, self.password.as_str()).unwrap_or(false)
     }
 }
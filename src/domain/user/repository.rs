@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
@@ -14,3 +16,35 @@ pub trait UserRepository: Send + Sync {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError>;
     async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError>;
 }
+
+// lets use cases hold whichever backend was configured behind an `Arc<dyn UserRepository>`
+#[async_trait]
+impl<T: UserRepository + ?Sized> UserRepository for Arc<T> {
+    async fn create(&self, user: User) -> Result<User, ModelError> {
+        (**self).create(user).await
+    }
+
+    async fn update(&self, user: &User) -> Result<User, ModelError> {
+        (**self).update(user).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        (**self).delete(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<User>, ModelError> {
+        (**self).find_all().await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
+        (**self).find_by_email(email).await
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
+        (**self).find_by_token(token).await
+    }
+}
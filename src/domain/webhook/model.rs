@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::event::model::{TodoEvent, TodoEventKind};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookEvent {
+    Created,
+    Updated,
+    Completed,
+    Deleted,
+}
+
+impl WebhookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Completed => "completed",
+            Self::Deleted => "deleted",
+        }
+    }
+
+    // a toggle that completes the todo is announced as `completed`, reopening it as `updated`
+    pub fn of(event: &TodoEvent) -> Self {
+        match event.kind {
+            TodoEventKind::Created => Self::Created,
+            TodoEventKind::Deleted => Self::Deleted,
+            TodoEventKind::Toggled if event.payload["is_completed"] == true => Self::Completed,
+            TodoEventKind::Toggled | TodoEventKind::Updated => Self::Updated,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub user_id: Uuid,
+    pub event_id: Option<i64>,
+    pub event: String,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub response_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// a claimed delivery together with the endpoint it has to be sent to
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DeliveryJob {
+    #[sqlx(flatten)]
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    webhook::model::{DeliveryJob, Webhook, WebhookDelivery},
+};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait WebhookRepository: Send + Sync {
+    async fn create(&self, webhook: Webhook) -> Result<Webhook, ModelError>;
+    async fn update(&self, webhook: Webhook) -> Result<Webhook, ModelError>;
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError>;
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Webhook>, ModelError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Webhook>, ModelError>;
+
+    async fn create_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ModelError>;
+    async fn update_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ModelError>;
+    async fn find_deliveries(
+        &self,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> Result<Vec<WebhookDelivery>, ModelError>;
+    async fn find_delivery(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, ModelError>;
+
+    // lease up to `limit` pending deliveries whose next attempt is due, a lease that is never
+    // reported back expires so the delivery is picked up again
+    async fn claim_deliveries(&self, limit: i64) -> Result<Vec<DeliveryJob>, ModelError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait WebhookSender: Send + Sync {
+    // returns the response status code, or a description of why no response was received
+    async fn send(&self, request: WebhookRequest) -> Result<u16, String>;
+}
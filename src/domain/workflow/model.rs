@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum StateCategory {
+    Todo,
+    Doing,
+    Done,
+}
+
+impl StateCategory {
+    pub fn is_completed(&self) -> bool {
+        matches!(self, Self::Done)
+    }
+}
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct WorkflowState {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub position: i32,
+    pub category: StateCategory,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Clone, sqlx::FromRow)]
+pub struct StateTransition {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub from_state_id: Option<Uuid>,
+    pub to_state_id: Uuid,
+    pub transitioned_at: DateTime<Utc>,
+}
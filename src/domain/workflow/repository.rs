@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    todo::model::Todo,
+    workflow::model::{StateTransition, WorkflowState},
+};
+
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait WorkflowRepository: Send + Sync {
+    async fn create_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError>;
+    async fn update_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError>;
+    // bring the completed flag of the todos in `state` in line with its category, returning the
+    // todos that changed
+    async fn align_todos(&self, state: &WorkflowState) -> Result<Vec<Todo>, ModelError>;
+    async fn delete_state(&self, id: Uuid) -> Result<(), ModelError>;
+    async fn find_states(&self, user_id: Uuid) -> Result<Vec<WorkflowState>, ModelError>;
+    async fn find_state(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WorkflowState>, ModelError>;
+
+    // move the todo into `to_state_id`, deriving `is_completed` from the state category
+    async fn transition(
+        &self,
+        transition: StateTransition,
+        is_completed: bool,
+    ) -> Result<StateTransition, ModelError>;
+    async fn find_transitions(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<StateTransition>, ModelError>;
+}
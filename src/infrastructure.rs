@@ -1,4 +1,9 @@
 pub mod bootstrap;
+pub mod cache;
 pub mod config;
 pub mod database;
+pub mod http;
+pub mod mail;
+pub mod realtime;
+pub mod scheduler;
 pub mod security;
@@ -1,11 +1,22 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
-use sqlx::postgres::PgPoolOptions;
+use lettre::{
+    AsyncSmtpTransport, Tokio1Executor, message::Mailbox,
+    transport::smtp::authentication::Credentials,
+};
+use sqlx::{Postgres, migrate::MigrateError, postgres::PgPoolOptions};
 use tokio::io;
 use tokio::net::TcpListener;
 use tracing::subscriber::SetGlobalDefaultError;
 
-use crate::infrastructure::config::AppConfig;
+use crate::{
+    domain::mail::mailer::Mailer,
+    infrastructure::{
+        config::{AppConfig, DatabaseBackend, MailTransport, SmtpTls},
+        database::{Connection, migration, replica::Replicas},
+        mail::{file_mailer::FileMailer, smtp_mailer::SmtpMailer},
+    },
+};
 
 pub fn logger(conf: &AppConfig) -> Result<(), SetGlobalDefaultError> {
     let level = match conf.log_level.to_ascii_lowercase().as_str() {
@@ -27,13 +38,17 @@ pub fn logger(conf: &AppConfig) -> Result<(), SetGlobalDefaultError> {
     tracing::subscriber::set_global_default(subscriber)
 }
 
-pub async fn sqlx(conf: &AppConfig) -> Result<sqlx::PgPool, sqlx::Error> {
+fn pool_options() -> PgPoolOptions {
     PgPoolOptions::new()
         .min_connections(1)
         .max_connections((num_cpus::get() * 2).try_into().unwrap_or(2))
         .acquire_timeout(Duration::from_secs(1))
         .idle_timeout(Duration::from_secs(300))
         .max_lifetime(Duration::from_secs(1800))
+}
+
+pub async fn sqlx(conf: &AppConfig) -> Result<sqlx::PgPool, sqlx::Error> {
+    pool_options()
         .connect(conf.db_uri().as_str())
         .await
         .inspect_err(|err| {
@@ -41,9 +56,119 @@ pub async fn sqlx(conf: &AppConfig) -> Result<sqlx::PgPool, sqlx::Error> {
         })
 }
 
+// replicas connect lazily, one that is down only sends its reads back to the primary
+pub fn replicas(conf: &AppConfig) -> Result<Replicas<Postgres>, sqlx::Error> {
+    let pools = conf
+        .replica_uris()
+        .iter()
+        .map(|uri| pool_options().min_connections(0).connect_lazy(uri))
+        .collect::<Result<Vec<_>, _>>()
+        .inspect_err(|err| {
+            tracing::error!("Failed to configure read replica: {}", err);
+        })?;
+
+    Ok(Replicas::new(pools))
+}
+
+// connect to the configured backend, sqlite is only available when built with the `sqlite` feature
+pub async fn database(conf: &AppConfig) -> Result<Connection, sqlx::Error> {
+    match conf.db_backend {
+        DatabaseBackend::Postgres => Ok(Connection::Postgres(sqlx(conf).await?, replicas(conf)?)),
+        #[cfg(feature = "sqlite")]
+        DatabaseBackend::Sqlite => sqlite(conf).await.map(Connection::Sqlite),
+        #[cfg(not(feature = "sqlite"))]
+        DatabaseBackend::Sqlite => {
+            tracing::error!("DB_BACKEND=sqlite needs a build with the `sqlite` feature");
+            Err(sqlx::Error::Configuration(
+                "the sqlite backend is not compiled in".into(),
+            ))
+        }
+        DatabaseBackend::Memory => Ok(Connection::memory()),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub async fn sqlite(conf: &AppConfig) -> Result<sqlx::SqlitePool, sqlx::Error> {
+    // sqlite serializes writers, a handful of connections is plenty
+    sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(4)
+        .acquire_timeout(Duration::from_secs(1))
+        .connect(conf.sqlite_uri().as_str())
+        .await
+        .inspect_err(|err| {
+            tracing::error!("Failed to open sqlite database: {}", err);
+        })
+}
+
+pub async fn migrate(conf: &AppConfig, connection: &Connection) -> Result<(), MigrateError> {
+    if conf.db_skip_migrations {
+        tracing::info!("database migrations are skipped");
+        return Ok(());
+    }
+
+    let applied = migration::run(connection).await?;
+
+    for migration in applied {
+        tracing::info!(
+            "applied migration {} {}",
+            migration.version,
+            migration.description
+        );
+    }
+
+    Ok(())
+}
+
 pub async fn listener(conf: &AppConfig) -> Result<TcpListener, io::Error> {
     TcpListener::bind(conf.server_addr()).await.map_err(|err| {
         tracing::error!("Failed to bind to {}: {}", conf.server_addr(), err);
         err
     })
 }
+
+// the smtp connection is only opened on the first mail, a wrong host shows up as failed sends
+pub fn mailer(conf: &AppConfig) -> Result<Arc<dyn Mailer>, String> {
+    let mailer = match conf.mail_transport {
+        MailTransport::File => file_mailer(conf),
+        MailTransport::Smtp => smtp_mailer(conf),
+    };
+
+    mailer.inspect_err(|err| tracing::error!("Failed to set up the mailer: {}", err))
+}
+
+fn mail_from(conf: &AppConfig) -> Result<Mailbox, String> {
+    conf.mail_from()
+        .parse()
+        .map_err(|err| format!("invalid MAIL_FROM : {}", err))
+}
+
+fn file_mailer(conf: &AppConfig) -> Result<Arc<dyn Mailer>, String> {
+    let outbox = conf.mail_outbox();
+    std::fs::create_dir_all(&outbox)
+        .map_err(|err| format!("unable to create {} : {}", outbox, err))?;
+
+    Ok(Arc::new(FileMailer::new(outbox.into(), mail_from(conf)?)))
+}
+
+fn smtp_mailer(conf: &AppConfig) -> Result<Arc<dyn Mailer>, String> {
+    let builder = match conf.smtp_tls {
+        SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&conf.smtp_host),
+        SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&conf.smtp_host),
+        SmtpTls::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+            &conf.smtp_host,
+        )),
+    }
+    .map_err(|err| format!("invalid SMTP_HOST : {}", err))?
+    .port(conf.smtp_port())
+    .timeout(Some(Duration::from_secs(10)));
+
+    let builder = match conf.smtp_username.is_empty() {
+        true => builder,
+        false => builder.credentials(Credentials::new(
+            conf.smtp_username.clone(),
+            conf.smtp_password.clone(),
+        )),
+    };
+
+    Ok(Arc::new(SmtpMailer::new(builder.build(), mail_from(conf)?)))
+}
@@ -0,0 +1,210 @@
+use std::{
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use uuid::Uuid;
+
+use crate::{
+    domain::{todo::model::Todo, user::model::User},
+    infrastructure::{
+        cache::{
+            broadcast::Broadcaster, todo_repository::CachedTodoRepository,
+            transaction_manager::CachedTransactionManager, user_repository::CachedUserRepository,
+        },
+        database::Repositories,
+    },
+};
+
+pub mod broadcast;
+pub mod time_entry_repository;
+pub mod todo_repository;
+pub mod transaction_manager;
+pub mod user_repository;
+
+// least recently used entries make room once the capacity is reached, an entry older than the
+// ttl counts as missing
+pub struct TtlCache<K: Hash + Eq, V> {
+    entries: Mutex<LruCache<K, (Instant, V)>>,
+    ttl: Duration,
+}
+
+impl<K: Hash + Eq, V: Clone> TtlCache<K, V> {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+
+        match entries.get(key) {
+            Some((stored_at, value)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.entries
+            .lock()
+            .unwrap()
+            .put(key, (Instant::now(), value));
+    }
+
+    pub fn remove(&self, key: &K) {
+        self.entries.lock().unwrap().pop(key);
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+// what a write made stale, sent to the other instances as `<kind>:<id>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invalidation {
+    // every cached todo of a user
+    Todos(Uuid),
+    // a user together with their todos
+    User(Uuid),
+}
+
+impl Invalidation {
+    pub fn encode(&self) -> String {
+        match self {
+            Self::Todos(user_id) => format!("todos:{}", user_id),
+            Self::User(id) => format!("user:{}", id),
+        }
+    }
+
+    pub fn decode(payload: &str) -> Option<Self> {
+        let (kind, id) = payload.split_once(':')?;
+        let id = Uuid::parse_str(id).ok()?;
+
+        match kind {
+            "todos" => Some(Self::Todos(id)),
+            "user" => Some(Self::User(id)),
+            _ => None,
+        }
+    }
+}
+
+// reads kept in this process for the cached repositories, todos are cached per owner as the
+// whole list including snoozed ones and every other read is answered from it
+pub struct RepositoryCache {
+    todos: TtlCache<Uuid, Vec<Todo>>,
+    users: TtlCache<Uuid, User>,
+    emails: TtlCache<String, Uuid>,
+    // bumped by every invalidation, a read that started before one must not fill the cache
+    epoch: AtomicU64,
+    broadcaster: Option<Broadcaster>,
+}
+
+impl RepositoryCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            todos: TtlCache::new(capacity, ttl),
+            users: TtlCache::new(capacity, ttl),
+            emails: TtlCache::new(capacity, ttl),
+            epoch: AtomicU64::new(0),
+            broadcaster: None,
+        }
+    }
+
+    // invalidations are sent to the other instances listening on the same database
+    pub fn broadcast(mut self, broadcaster: Broadcaster) -> Self {
+        self.broadcaster = Some(broadcaster);
+        self
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.load(Ordering::SeqCst)
+    }
+
+    pub fn todos(&self, user_id: Uuid) -> Option<Vec<Todo>> {
+        self.todos.get(&user_id)
+    }
+
+    pub fn store_todos(&self, epoch: u64, user_id: Uuid, todos: Vec<Todo>) {
+        if self.epoch() == epoch {
+            self.todos.insert(user_id, todos);
+        }
+    }
+
+    pub fn user(&self, id: Uuid) -> Option<User> {
+        self.users.get(&id)
+    }
+
+    // the address may have changed since it was indexed, so the user has to still own it
+    pub fn user_by_email(&self, email: &str) -> Option<User> {
+        let id = self.emails.get(&email.to_string())?;
+
+        self.users.get(&id).filter(|user| user.email == email)
+    }
+
+    pub fn store_user(&self, epoch: u64, user: User) {
+        if self.epoch() == epoch {
+            self.emails.insert(user.email.clone(), user.id);
+            self.users.insert(user.id, user);
+        }
+    }
+
+    // drops what the write made stale here and tells the other instances to do the same
+    pub async fn invalidate(&self, invalidation: Invalidation) {
+        self.evict(invalidation);
+
+        if let Some(broadcaster) = &self.broadcaster {
+            broadcaster.publish(invalidation).await;
+        }
+    }
+
+    pub fn evict(&self, invalidation: Invalidation) {
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+
+        match invalidation {
+            Invalidation::Todos(user_id) => self.todos.remove(&user_id),
+            Invalidation::User(id) => {
+                self.users.remove(&id);
+                self.todos.remove(&id);
+            }
+        }
+    }
+
+    // for when invalidations may have been missed, e.g. while reconnecting
+    pub fn clear(&self) {
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+        self.todos.clear();
+        self.users.clear();
+        self.emails.clear();
+    }
+}
+
+// puts the cache in front of the todo and user repositories, writes through a unit of work
+// invalidate once it commits
+pub fn repositories(repositories: Repositories, cache: Arc<RepositoryCache>) -> Repositories {
+    Repositories {
+        todo: Arc::new(CachedTodoRepository::new(repositories.todo, cache.clone())),
+        user: Arc::new(CachedUserRepository::new(repositories.user, cache.clone())),
+        event: repositories.event,
+        transactions: Arc::new(CachedTransactionManager::new(
+            repositories.transactions,
+            cache,
+        )),
+        lockout: repositories.lockout,
+        password_resets: repositories.password_resets,
+    }
+}
@@ -0,0 +1,121 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::{
+    PgPool,
+    postgres::{PgListener, PgPoolOptions},
+};
+use tokio::{sync::broadcast::error::RecvError, task::JoinHandle};
+use uuid::Uuid;
+
+use crate::infrastructure::{
+    cache::{Invalidation, RepositoryCache},
+    realtime::EventHub,
+};
+
+const CHANNEL: &str = "cache_invalidation";
+const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+// sends invalidations as `<origin> <kind>:<id>`, the origin lets an instance skip its own
+#[derive(Clone)]
+pub struct Broadcaster {
+    pool: PgPool,
+    origin: Uuid,
+}
+
+impl Broadcaster {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            origin: Uuid::new_v4(),
+        }
+    }
+
+    // a failed notify is only logged, the other instances catch up once their entries expire
+    pub async fn publish(&self, invalidation: Invalidation) {
+        let payload = format!("{} {}", self.origin, invalidation.encode());
+
+        if let Err(err) = sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(CHANNEL)
+            .bind(payload)
+            .execute(&self.pool)
+            .await
+        {
+            tracing::error!("failed to broadcast cache invalidation : {}", err);
+        }
+    }
+}
+
+// evicts what other instances invalidated, the todo event log also covers writes that bypass
+// the cached repositories, like graphql or workflow transitions
+pub fn listen(
+    cache: Arc<RepositoryCache>,
+    broadcaster: Broadcaster,
+    hub: EventHub,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut events = hub.subscribe();
+        let mut listener: Option<PgListener> = None;
+
+        loop {
+            if listener.is_none() {
+                listener = subscribe(&broadcaster.pool).await;
+                // anything sent while disconnected is lost
+                cache.clear();
+            }
+
+            let Some(active) = listener.as_mut() else {
+                tokio::time::sleep(RETRY_INTERVAL).await;
+                continue;
+            };
+
+            tokio::select! {
+                notification = active.try_recv() => match notification {
+                    Ok(Some(notification)) => {
+                        let Some((origin, payload)) = notification.payload().split_once(' ') else {
+                            continue;
+                        };
+
+                        if origin == broadcaster.origin.to_string() {
+                            continue;
+                        }
+
+                        if let Some(invalidation) = Invalidation::decode(payload) {
+                            cache.evict(invalidation);
+                        }
+                    }
+                    // reconnects on the next receive, anything sent in between is lost
+                    Ok(None) => cache.clear(),
+                    Err(err) => {
+                        tracing::error!("cache listener lost its connection : {}", err);
+                        listener = None;
+                    }
+                },
+                event = events.recv() => match event {
+                    Ok(event) => cache.evict(Invalidation::Todos(event.user_id)),
+                    Err(RecvError::Lagged(_)) => cache.clear(),
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    })
+}
+
+// the listener holds its connection for good, it gets its own so the pool is not left one short
+async fn subscribe(pool: &PgPool) -> Option<PgListener> {
+    let dedicated = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_lazy_with(pool.connect_options().as_ref().clone());
+
+    let mut listener = PgListener::connect_with(&dedicated)
+        .await
+        .inspect_err(|err| tracing::error!("failed to connect cache listener : {}", err))
+        .ok()?;
+
+    listener
+        .listen(CHANNEL)
+        .await
+        .inspect_err(|err| tracing::error!("failed to listen on {} : {}", CHANNEL, err))
+        .ok()?;
+
+    Some(listener)
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        time_entry::{model::TimeEntry, repository::TimeEntryRepository},
+    },
+    infrastructure::cache::{Invalidation, RepositoryCache},
+};
+
+// entries are not cached themselves, but every write changes the tracked seconds of the
+// cached todo listing
+pub struct CachedTimeEntryRepository<R> {
+    inner: R,
+    cache: Arc<RepositoryCache>,
+}
+
+impl<R: TimeEntryRepository> CachedTimeEntryRepository<R> {
+    pub fn new(inner: R, cache: Arc<RepositoryCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: TimeEntryRepository> TimeEntryRepository for CachedTimeEntryRepository<R> {
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        let user_id = entry.user_id;
+        let created = self.inner.create(entry).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(created)
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        let user_id = entry.user_id;
+        let updated = self.inner.update(entry).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.inner.delete(user_id, id).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        self.inner.find_by_id(user_id, id).await
+    }
+
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        self.inner.find_running(user_id).await
+    }
+
+    async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        self.inner.find_by_todo(user_id, todo_id).await
+    }
+
+    async fn find_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        self.inner.find_between(user_id, from, to).await
+    }
+}
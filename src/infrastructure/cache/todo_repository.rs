@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{shared::error::ModelError, todo::model::Todo, todo::repository::TodoRepository},
+    infrastructure::{
+        cache::{Invalidation, RepositoryCache},
+        database::replica,
+    },
+};
+
+// tracked seconds of a running timer are only as fresh as the cached listing
+pub struct CachedTodoRepository<R> {
+    inner: R,
+    cache: Arc<RepositoryCache>,
+}
+
+impl<R: TodoRepository> CachedTodoRepository<R> {
+    pub fn new(inner: R, cache: Arc<RepositoryCache>) -> Self {
+        Self { inner, cache }
+    }
+
+    async fn listing(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        if let Some(todos) = self.cache.todos(user_id) {
+            return Ok(todos);
+        }
+
+        // a lagging replica would keep the entry stale until it expires
+        let epoch = self.cache.epoch();
+        let todos = replica::scope(true, self.inner.find_all_including_snoozed(user_id)).await?;
+        self.cache.store_todos(epoch, user_id, todos.clone());
+
+        Ok(todos)
+    }
+}
+
+#[async_trait]
+impl<R: TodoRepository> TodoRepository for CachedTodoRepository<R> {
+    async fn create(&self, todo: Todo) -> Result<Todo, ModelError> {
+        let user_id = todo.user_id;
+        let created = self.inner.create(todo).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(created)
+    }
+
+    async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
+        let user_id = todo.user_id;
+        let updated = self.inner.update(todo).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.inner.delete(user_id, id).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(())
+    }
+
+    async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.inner.toggle(user_id, id).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(())
+    }
+
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        let snoozed = self.inner.snooze(user_id, id, until).await?;
+        self.cache.invalidate(Invalidation::Todos(user_id)).await;
+
+        Ok(snoozed)
+    }
+
+    // the snooze filter runs on every read, so a cached listing never hides a todo for too long
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        let now = Utc::now();
+
+        Ok(self
+            .listing(user_id)
+            .await?
+            .into_iter()
+            .filter(|todo| todo.snoozed_until.is_none_or(|until| until <= now))
+            .collect())
+    }
+
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        self.listing(user_id).await
+    }
+
+    // single lookups are answered from a cached listing but never load one
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
+        match self.cache.todos(user_id) {
+            Some(todos) => todos
+                .into_iter()
+                .find(|todo| todo.id == id)
+                .map(Some)
+                .ok_or(ModelError::NotFound),
+            None => self.inner.find_by_id(user_id, id).await,
+        }
+    }
+}
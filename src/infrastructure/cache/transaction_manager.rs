@@ -0,0 +1,230 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        event::repository::EventRepository,
+        shared::{
+            error::ModelError,
+            transaction::{TransactionManager, UnitOfWork},
+        },
+        todo::{model::Todo, repository::TodoRepository},
+        user::{model::User, repository::UserRepository},
+        workflow::{
+            model::{StateTransition, WorkflowState},
+            repository::WorkflowRepository,
+        },
+    },
+    infrastructure::cache::{Invalidation, RepositoryCache},
+};
+
+pub struct CachedTransactionManager<X> {
+    inner: X,
+    cache: Arc<RepositoryCache>,
+}
+
+impl<X: TransactionManager> CachedTransactionManager<X> {
+    pub fn new(inner: X, cache: Arc<RepositoryCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<X: TransactionManager> TransactionManager for CachedTransactionManager<X> {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError> {
+        Ok(Box::new(CachedUnitOfWork {
+            inner: self.inner.begin().await?,
+            cache: self.cache.clone(),
+            written: Mutex::new(Vec::new()),
+        }))
+    }
+}
+
+// reads inside the transaction skip the cache to see its own writes, the writes are only
+// invalidated once they are committed
+struct CachedUnitOfWork {
+    inner: Box<dyn UnitOfWork>,
+    cache: Arc<RepositoryCache>,
+    written: Mutex<Vec<Invalidation>>,
+}
+
+impl CachedUnitOfWork {
+    fn written(&self, invalidation: Invalidation) {
+        self.written.lock().unwrap().push(invalidation);
+    }
+
+    // only handed out by `workflows` when the inner unit of work has them
+    fn inner_workflows(&self) -> &dyn WorkflowRepository {
+        self.inner.workflows().unwrap()
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for CachedUnitOfWork {
+    fn todos(&self) -> &dyn TodoRepository {
+        self
+    }
+
+    fn users(&self) -> &dyn UserRepository {
+        self
+    }
+
+    fn events(&self) -> &dyn EventRepository {
+        self.inner.events()
+    }
+
+    fn workflows(&self) -> Option<&dyn WorkflowRepository> {
+        self.inner
+            .workflows()
+            .map(|_| self as &dyn WorkflowRepository)
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), ModelError> {
+        let written = self.written.into_inner().unwrap();
+        self.inner.commit().await?;
+
+        for invalidation in written {
+            self.cache.invalidate(invalidation).await;
+        }
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), ModelError> {
+        self.inner.rollback().await
+    }
+}
+
+#[async_trait]
+impl TodoRepository for CachedUnitOfWork {
+    async fn create(&self, todo: Todo) -> Result<Todo, ModelError> {
+        self.written(Invalidation::Todos(todo.user_id));
+        self.inner.todos().create(todo).await
+    }
+
+    async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
+        self.written(Invalidation::Todos(todo.user_id));
+        self.inner.todos().update(todo).await
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.written(Invalidation::Todos(user_id));
+        self.inner.todos().delete(user_id, id).await
+    }
+
+    async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.written(Invalidation::Todos(user_id));
+        self.inner.todos().toggle(user_id, id).await
+    }
+
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        self.written(Invalidation::Todos(user_id));
+        self.inner.todos().snooze(user_id, id, until).await
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        self.inner.todos().find_all(user_id).await
+    }
+
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        self.inner.todos().find_all_including_snoozed(user_id).await
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
+        self.inner.todos().find_by_id(user_id, id).await
+    }
+}
+
+#[async_trait]
+impl UserRepository for CachedUnitOfWork {
+    async fn create(&self, user: User) -> Result<User, ModelError> {
+        self.inner.users().create(user).await
+    }
+
+    async fn update(&self, user: &User) -> Result<User, ModelError> {
+        self.written(Invalidation::User(user.id));
+        self.inner.users().update(user).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        self.written(Invalidation::User(id));
+        self.inner.users().delete(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<User>, ModelError> {
+        self.inner.users().find_all().await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
+        self.inner.users().find_by_id(id).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
+        self.inner.users().find_by_email(email).await
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
+        self.inner.users().find_by_token(token).await
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for CachedUnitOfWork {
+    async fn create_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError> {
+        self.inner_workflows().create_state(state).await
+    }
+
+    async fn update_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError> {
+        self.inner_workflows().update_state(state).await
+    }
+
+    async fn align_todos(&self, state: &WorkflowState) -> Result<Vec<Todo>, ModelError> {
+        self.written(Invalidation::Todos(state.user_id));
+        self.inner_workflows().align_todos(state).await
+    }
+
+    async fn delete_state(&self, id: Uuid) -> Result<(), ModelError> {
+        self.inner_workflows().delete_state(id).await
+    }
+
+    async fn find_states(&self, user_id: Uuid) -> Result<Vec<WorkflowState>, ModelError> {
+        self.inner_workflows().find_states(user_id).await
+    }
+
+    async fn find_state(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WorkflowState>, ModelError> {
+        self.inner_workflows().find_state(user_id, id).await
+    }
+
+    async fn transition(
+        &self,
+        transition: StateTransition,
+        is_completed: bool,
+    ) -> Result<StateTransition, ModelError> {
+        self.written(Invalidation::Todos(transition.user_id));
+        self.inner_workflows()
+            .transition(transition, is_completed)
+            .await
+    }
+
+    async fn find_transitions(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<StateTransition>, ModelError> {
+        self.inner_workflows()
+            .find_transitions(user_id, todo_id)
+            .await
+    }
+}
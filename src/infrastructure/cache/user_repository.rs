@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::{shared::error::ModelError, user::model::User, user::repository::UserRepository},
+    infrastructure::{
+        cache::{Invalidation, RepositoryCache},
+        database::replica,
+    },
+};
+
+// users are cached by id and email, refresh tokens rotate on every use and are always looked up
+pub struct CachedUserRepository<R> {
+    inner: R,
+    cache: Arc<RepositoryCache>,
+}
+
+impl<R: UserRepository> CachedUserRepository<R> {
+    pub fn new(inner: R, cache: Arc<RepositoryCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: UserRepository> UserRepository for CachedUserRepository<R> {
+    async fn create(&self, user: User) -> Result<User, ModelError> {
+        self.inner.create(user).await
+    }
+
+    async fn update(&self, user: &User) -> Result<User, ModelError> {
+        let updated = self.inner.update(user).await?;
+        self.cache.invalidate(Invalidation::User(user.id)).await;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate(Invalidation::User(id)).await;
+
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<User>, ModelError> {
+        self.inner.find_all().await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
+        if let Some(user) = self.cache.user(id) {
+            return Ok(Some(user));
+        }
+
+        // a lagging replica would keep the entry stale until it expires
+        let epoch = self.cache.epoch();
+        let user = replica::scope(true, self.inner.find_by_id(id)).await?;
+        if let Some(user) = &user {
+            self.cache.store_user(epoch, user.clone());
+        }
+
+        Ok(user)
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
+        if let Some(user) = self.cache.user_by_email(email) {
+            return Ok(Some(user));
+        }
+
+        let epoch = self.cache.epoch();
+        let user = replica::scope(true, self.inner.find_by_email(email)).await?;
+        if let Some(user) = &user {
+            self.cache.store_user(epoch, user.clone());
+        }
+
+        Ok(user)
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
+        self.inner.find_by_token(token).await
+    }
+}
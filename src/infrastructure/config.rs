@@ -1,6 +1,46 @@
+use std::time::Duration;
+
 use config::{Config, ConfigError, Environment};
 use serde::Deserialize;
 
+use crate::application::{
+    auth::usecase::{EmailVerification, LockoutPolicy},
+    password_reset::usecase::PasswordResetPolicy,
+    rate_limit::usecase::RateLimitPolicy,
+};
+
+// where todos and users are stored, the remaining features need postgres
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    #[default]
+    Postgres,
+    Sqlite,
+    // keeps everything in the process, for local development and tests
+    Memory,
+}
+
+// how mails leave the app
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransport {
+    // written as files into the outbox directory, nothing is sent
+    #[default]
+    File,
+    Smtp,
+}
+
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    #[default]
+    Starttls,
+    // tls from the first byte, usually on port 465
+    Tls,
+    // plain text, only for a local smtp catcher
+    None,
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(default)]
 pub struct AppConfig {
@@ -9,14 +49,71 @@ pub struct AppConfig {
 
     pub log_level: String,
 
+    pub db_backend: DatabaseBackend,
+
     pub db_host: String,
     pub db_port: u32,
     pub db_user: String,
     pub db_password: String,
     pub db_name: String,
+    // comma separated `host[:port]` of read replicas, they share the credentials of the primary
+    pub db_replicas: String,
+    // migrations run on startup unless this is set, for schemas managed outside the app
+    pub db_skip_migrations: bool,
+
+    #[cfg(feature = "sqlite")]
+    pub sqlite_path: String,
 
     pub jwt_secret: String,
     pub jwt_duration: i64,
+
+    pub scheduler_interval: u64,
+    pub webhook_interval: u64,
+
+    pub idempotency_window: u64,
+
+    // attempts at login, register and refresh per window, by client address and by email
+    pub rate_limit_ip: u32,
+    pub rate_limit_email: u32,
+    pub rate_limit_window: u64,
+    // take the client address from `X-Forwarded-For`, only behind a proxy that sets it
+    pub trust_forwarded_for: bool,
+
+    // failed logins before an account is locked, the lock doubles with every further failure
+    pub lockout_threshold: u32,
+    pub lockout_duration: u64,
+    pub lockout_max_duration: u64,
+
+    // comma separated keys for operators calling the admin endpoints
+    pub admin_api_keys: String,
+
+    pub mail_transport: MailTransport,
+    pub mail_from: String,
+    pub mail_outbox: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: String,
+    pub smtp_password: String,
+    pub smtp_tls: SmtpTls,
+
+    // new accounts get a link to confirm their address, login waits for it only when required
+    pub email_verification_required: bool,
+    pub email_verification_ttl: u64,
+    pub email_verification_url: String,
+
+    // forgotten passwords are reset through a single-use token sent by mail
+    pub password_reset_ttl: u64,
+    pub password_reset_url: String,
+
+    // keeps todo listings and users in process, off unless set
+    pub cache_enabled: bool,
+    pub cache_capacity: usize,
+    pub cache_ttl: u64,
+
+    #[cfg(feature = "grpc")]
+    pub grpc_port: u32,
+    #[cfg(feature = "grpc")]
+    pub grpc_api_keys: String,
 }
 
 impl AppConfig {
@@ -27,9 +124,202 @@ impl AppConfig {
         )
     }
 
+    // the port of a replica defaults to the one of the primary
+    pub fn replica_uris(&self) -> Vec<String> {
+        self.db_replicas
+            .split(',')
+            .map(str::trim)
+            .filter(|replica| !replica.is_empty())
+            .map(|replica| {
+                let (host, port) = match replica.rsplit_once(':') {
+                    Some((host, port)) => (host.to_string(), port.to_string()),
+                    None => (replica.to_string(), self.db_port.to_string()),
+                };
+
+                format!(
+                    "postgres://{}:{}@{}:{}/{}?sslmode=disable",
+                    self.db_user, self.db_password, host, port, self.db_name
+                )
+            })
+            .collect()
+    }
+
+    // the database file is created on first start, defaults to `todo.db` in the working directory
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite_uri(&self) -> String {
+        match self.sqlite_path.as_str() {
+            "" => "sqlite://todo.db?mode=rwc".to_string(),
+            path => format!("sqlite://{}?mode=rwc", path),
+        }
+    }
+
     pub fn server_addr(&self) -> String {
         format!("{}:{}", self.server_host, self.server_port)
     }
+
+    // how often the background scheduler looks for due reminders, defaults to 30 seconds
+    pub fn scheduler_interval(&self) -> Duration {
+        match self.scheduler_interval {
+            0 => Duration::from_secs(30),
+            secs => Duration::from_secs(secs),
+        }
+    }
+
+    // how often pending webhook deliveries are sent, defaults to 5 seconds
+    pub fn webhook_interval(&self) -> Duration {
+        match self.webhook_interval {
+            0 => Duration::from_secs(5),
+            secs => Duration::from_secs(secs),
+        }
+    }
+
+    // how long a response is kept for replay under its `Idempotency-Key`, defaults to 24 hours
+    pub fn idempotency_window(&self) -> Duration {
+        match self.idempotency_window {
+            0 => Duration::from_secs(24 * 60 * 60),
+            secs => Duration::from_secs(secs),
+        }
+    }
+
+    pub fn rate_limit(&self) -> RateLimitPolicy {
+        RateLimitPolicy {
+            // defaults to 20 attempts per address
+            per_ip: match self.rate_limit_ip {
+                0 => 20,
+                limit => limit,
+            },
+            // defaults to 5 attempts per email
+            per_email: match self.rate_limit_email {
+                0 => 5,
+                limit => limit,
+            },
+            // defaults to a minute
+            window: match self.rate_limit_window {
+                0 => Duration::from_secs(60),
+                secs => Duration::from_secs(secs),
+            },
+        }
+    }
+
+    pub fn lockout(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            // defaults to 5 failed logins
+            threshold: match self.lockout_threshold {
+                0 => 5,
+                threshold => threshold,
+            },
+            // defaults to a minute
+            duration: match self.lockout_duration {
+                0 => Duration::from_secs(60),
+                secs => Duration::from_secs(secs),
+            },
+            // defaults to an hour
+            max_duration: match self.lockout_max_duration {
+                0 => Duration::from_secs(60 * 60),
+                secs => Duration::from_secs(secs),
+            },
+        }
+    }
+
+    // without any key the admin endpoints refuse every request
+    pub fn admin_api_keys(&self) -> Vec<String> {
+        self.admin_api_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    // defaults to a no-reply address on localhost
+    pub fn mail_from(&self) -> String {
+        match self.mail_from.trim() {
+            "" => "todo-rs <no-reply@localhost>".to_string(),
+            from => from.to_string(),
+        }
+    }
+
+    // defaults to `outbox` in the working directory
+    pub fn mail_outbox(&self) -> String {
+        match self.mail_outbox.trim() {
+            "" => "outbox".to_string(),
+            outbox => outbox.to_string(),
+        }
+    }
+
+    // defaults to the port of the tls mode
+    pub fn smtp_port(&self) -> u16 {
+        match (self.smtp_port, self.smtp_tls) {
+            (0, SmtpTls::Starttls) => 587,
+            (0, SmtpTls::Tls) => 465,
+            (0, SmtpTls::None) => 25,
+            (port, _) => port,
+        }
+    }
+
+    pub fn email_verification(&self) -> EmailVerification {
+        EmailVerification {
+            required: self.email_verification_required,
+            // defaults to a day
+            ttl: match self.email_verification_ttl {
+                0 => Duration::from_secs(24 * 60 * 60),
+                secs => Duration::from_secs(secs),
+            },
+            // defaults to the verify endpoint of this server, the token is appended as `?token=`
+            url: match self.email_verification_url.trim() {
+                "" => format!("http://{}/api/v1/auth/email/verify", self.server_addr()),
+                url => url.to_string(),
+            },
+        }
+    }
+
+    pub fn password_reset(&self) -> PasswordResetPolicy {
+        PasswordResetPolicy {
+            // defaults to an hour
+            ttl: match self.password_reset_ttl {
+                0 => Duration::from_secs(60 * 60),
+                secs => Duration::from_secs(secs),
+            },
+            // the page asking for the new password, without one the mail only carries the token
+            url: self.password_reset_url.trim().to_string(),
+        }
+    }
+
+    // entries kept per cache before the least recently used are dropped, defaults to 10000
+    pub fn cache_capacity(&self) -> usize {
+        match self.cache_capacity {
+            0 => 10_000,
+            capacity => capacity,
+        }
+    }
+
+    // how long a cached entry is served without asking the database, defaults to 30 seconds
+    pub fn cache_ttl(&self) -> Duration {
+        match self.cache_ttl {
+            0 => Duration::from_secs(30),
+            secs => Duration::from_secs(secs),
+        }
+    }
+
+    // the grpc server shares the host with the rest api on its own port, defaults to 50051
+    #[cfg(feature = "grpc")]
+    pub fn grpc_addr(&self) -> String {
+        match self.grpc_port {
+            0 => format!("{}:50051", self.server_host),
+            port => format!("{}:{}", self.server_host, port),
+        }
+    }
+
+    // comma separated keys for internal services calling the grpc api
+    #[cfg(feature = "grpc")]
+    pub fn grpc_api_keys(&self) -> Vec<String> {
+        self.grpc_api_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(String::from)
+            .collect()
+    }
 }
 
 // load from .env variable, but if .env not exist return using system environment
@@ -1 +1,133 @@
+use std::sync::Arc;
+
+use ::sqlx::{PgPool, Postgres};
+
+use crate::{
+    domain::{
+        event::repository::EventRepository, lockout::repository::LockoutRepository,
+        password_reset::repository::PasswordResetRepository,
+        shared::transaction::TransactionManager, todo::repository::TodoRepository,
+        user::repository::UserRepository,
+    },
+    infrastructure::database::{
+        memory::{
+            event_repository::InMemoryEventRepository,
+            lockout_repository::InMemoryLockoutRepository,
+            password_reset_repository::InMemoryPasswordResetRepository,
+            todo_repository::InMemoryTodoRepository,
+            transaction_manager::InMemoryTransactionManager,
+            user_repository::InMemoryUserRepository,
+        },
+        replica::Replicas,
+        sqlx::{
+            event_repository::PostgresEventRepository,
+            lockout_repository::PostgresLockoutRepository,
+            password_reset_repository::PostgresPasswordResetRepository,
+            todo_repository::PostgresTodoRepository,
+            transaction_manager::PostgresTransactionManager,
+            user_repository::PostgresUserRepository,
+        },
+    },
+};
+
+#[cfg(test)]
+mod contract;
+pub mod executor;
+pub mod memory;
+pub mod migration;
+pub mod replica;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod sqlx;
+
+// the pool of the configured backend, only postgres carries the features beyond todos and users
+#[derive(Clone)]
+pub enum Connection {
+    // the primary and the read replicas, which may be none
+    Postgres(PgPool, Replicas<Postgres>),
+    #[cfg(feature = "sqlite")]
+    Sqlite(::sqlx::SqlitePool),
+    // nothing is persisted, the repositories are shared by everyone holding the connection
+    Memory(Repositories),
+}
+
+impl Connection {
+    pub fn memory() -> Self {
+        let todo: Arc<dyn TodoRepository> = Arc::new(InMemoryTodoRepository::new());
+        let user: Arc<dyn UserRepository> = Arc::new(InMemoryUserRepository::new());
+        let event: Arc<dyn EventRepository> = Arc::new(InMemoryEventRepository::new());
+        let transactions =
+            InMemoryTransactionManager::new(todo.clone(), user.clone(), event.clone());
+
+        Self::Memory(Repositories {
+            todo,
+            user,
+            event,
+            transactions: Arc::new(transactions),
+            lockout: Arc::new(InMemoryLockoutRepository::new()),
+            password_resets: Arc::new(InMemoryPasswordResetRepository::new()),
+        })
+    }
+
+    pub fn postgres(&self) -> Option<&PgPool> {
+        match self {
+            Self::Postgres(pool, _) => Some(pool),
+            _ => None,
+        }
+    }
+
+    pub fn repositories(&self) -> Repositories {
+        match self {
+            // only todo and user reads are spread over the replicas
+            Self::Postgres(pool, replicas) => Repositories {
+                todo: Arc::new(PostgresTodoRepository::replicated(
+                    pool.clone(),
+                    replicas.clone(),
+                )),
+                user: Arc::new(PostgresUserRepository::replicated(
+                    pool.clone(),
+                    replicas.clone(),
+                )),
+                event: Arc::new(PostgresEventRepository::new(pool.clone())),
+                transactions: Arc::new(PostgresTransactionManager::new(pool.clone())),
+                lockout: Arc::new(PostgresLockoutRepository::new(pool.clone())),
+                password_resets: Arc::new(PostgresPasswordResetRepository::new(pool.clone())),
+            },
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(pool) => Repositories {
+                todo: Arc::new(sqlite::todo_repository::SqliteTodoRepository::new(
+                    pool.clone(),
+                )),
+                user: Arc::new(sqlite::user_repository::SqliteUserRepository::new(
+                    pool.clone(),
+                )),
+                event: Arc::new(sqlite::event_repository::SqliteEventRepository::new(
+                    pool.clone(),
+                )),
+                transactions: Arc::new(sqlite::transaction_manager::SqliteTransactionManager::new(
+                    pool.clone(),
+                )),
+                lockout: Arc::new(sqlite::lockout_repository::SqliteLockoutRepository::new(
+                    pool.clone(),
+                )),
+                password_resets: Arc::new(
+                    sqlite::password_reset_repository::SqlitePasswordResetRepository::new(
+                        pool.clone(),
+                    ),
+                ),
+            },
+            Self::Memory(repositories) => repositories.clone(),
+        }
+    }
+}
+
+// repositories every backend implements, routers build their use cases from these
+#[derive(Clone)]
+pub struct Repositories {
+    pub todo: Arc<dyn TodoRepository>,
+    pub user: Arc<dyn UserRepository>,
+    pub event: Arc<dyn EventRepository>,
+    pub transactions: Arc<dyn TransactionManager>,
+    pub lockout: Arc<dyn LockoutRepository>,
+    pub password_resets: Arc<dyn PasswordResetRepository>,
+}
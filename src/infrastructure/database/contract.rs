@@ -0,0 +1,649 @@
+// behaviour every repository backend has to share, each suite runs against a fresh, empty store
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    lockout::{
+        model::{LockoutEvent, LockoutEventKind},
+        repository::LockoutRepository,
+    },
+    password_reset::{model::PasswordReset, repository::PasswordResetRepository},
+    shared::error::ModelError,
+    todo::{model::Todo, repository::TodoRepository},
+    user::{model::User, repository::UserRepository},
+};
+
+fn user(email: &str) -> User {
+    User {
+        id: Uuid::new_v4(),
+        name: "test".to_string(),
+        email: email.to_string(),
+        password: "password".to_string(),
+        token: None,
+        email_verified_at: Some(Utc::now()),
+        password_changed_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn todo(user_id: Uuid, title: &str) -> Todo {
+    Todo {
+        id: Uuid::new_v4(),
+        user_id,
+        title: title.to_string(),
+        description: "description".to_string(),
+        is_completed: false,
+        state_id: None,
+        priority: 1,
+        tags: vec!["work".to_string()],
+        project: None,
+        due_at: None,
+        snoozed_until: None,
+        tracked_seconds: 0,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub async fn user_repository(users: &dyn UserRepository) {
+    let alice = users.create(user("alice@domain.com")).await.unwrap();
+
+    // lookups
+    let found = users.find_by_id(alice.id).await.unwrap().unwrap();
+    assert_eq!(found.email, "alice@domain.com");
+    assert!(found.password_changed_at.is_none());
+    let found = users.find_by_email("alice@domain.com").await.unwrap();
+    assert_eq!(found.map(|user| user.id), Some(alice.id));
+    assert!(matches!(
+        users.find_by_id(Uuid::new_v4()).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        users.find_by_email("nobody@domain.com").await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        users.find_by_token("missing").await,
+        Err(ModelError::NotFound)
+    ));
+
+    // conflicts
+    assert!(matches!(
+        users.create(user("alice@domain.com")).await,
+        Err(ModelError::Conflict)
+    ));
+    let bob = users.create(user("bob@domain.com")).await.unwrap();
+    let mut taken = bob.clone();
+    taken.email = alice.email.clone();
+    assert!(matches!(
+        users.update(&taken).await,
+        Err(ModelError::Conflict)
+    ));
+
+    // updates
+    let mut changed = alice.clone();
+    changed.name = "alice".to_string();
+    changed.email = "alice@example.com".to_string();
+    changed.token = Some("refresh-token".to_string());
+    changed.password_changed_at = Some(Utc::now());
+    let updated = users.update(&changed).await.unwrap();
+    assert_eq!(updated.name, "alice");
+    assert_eq!(updated.email, "alice@example.com");
+    assert_eq!(
+        updated.password_changed_at.map(|at| at.timestamp()),
+        changed.password_changed_at.map(|at| at.timestamp())
+    );
+    let found = users.find_by_token("refresh-token").await.unwrap();
+    assert_eq!(found.map(|user| user.id), Some(alice.id));
+    assert!(matches!(
+        users.find_by_email("alice@domain.com").await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        users.update(&user("ghost@domain.com")).await,
+        Err(ModelError::NotFound)
+    ));
+
+    let mut all = users.find_all().await.unwrap();
+    all.sort_by_key(|user| user.email.clone());
+    assert_eq!(
+        all.iter().map(|user| user.id).collect::<Vec<Uuid>>(),
+        vec![alice.id, bob.id]
+    );
+
+    // deletes
+    users.delete(bob.id).await.unwrap();
+    assert!(matches!(
+        users.find_by_id(bob.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        users.delete(bob.id).await,
+        Err(ModelError::NotFound)
+    ));
+    // the address of a deleted user can be registered again
+    users.create(user("bob@domain.com")).await.unwrap();
+}
+
+// `users` only provides owners, the sql backends reference them by foreign key
+pub async fn todo_repository(todos: &dyn TodoRepository, users: &dyn UserRepository) {
+    let owner = users.create(user("owner@domain.com")).await.unwrap().id;
+    let stranger = users.create(user("stranger@domain.com")).await.unwrap().id;
+
+    let first = todos.create(todo(owner, "first")).await.unwrap();
+    assert_eq!(first.title, "first");
+    assert_eq!(first.tags, vec!["work".to_string()]);
+    let second = todos.create(todo(owner, "second")).await.unwrap();
+    let foreign = todos.create(todo(stranger, "foreign")).await.unwrap();
+
+    // conflicts
+    let mut duplicate = todo(owner, "duplicate");
+    duplicate.id = first.id;
+    assert!(matches!(
+        todos.create(duplicate).await,
+        Err(ModelError::Conflict)
+    ));
+
+    // reads are scoped to the owner
+    let found = todos.find_by_id(owner, first.id).await.unwrap().unwrap();
+    assert_eq!(found.title, "first");
+    assert!(matches!(
+        todos.find_by_id(stranger, first.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        todos.find_by_id(owner, Uuid::new_v4()).await,
+        Err(ModelError::NotFound)
+    ));
+    let mut all = todos.find_all(owner).await.unwrap();
+    all.sort_by_key(|todo| todo.title.clone());
+    assert_eq!(
+        all.iter().map(|todo| todo.id).collect::<Vec<Uuid>>(),
+        vec![first.id, second.id]
+    );
+
+    // writes are scoped to the owner
+    let mut hijacked = found.clone();
+    hijacked.user_id = stranger;
+    hijacked.title = "hijacked".to_string();
+    assert!(matches!(
+        todos.update(hijacked).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        todos.toggle(stranger, first.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        todos.snooze(stranger, first.id, None).await,
+        Err(ModelError::NotFound)
+    ));
+    let untouched = todos.find_by_id(owner, first.id).await.unwrap().unwrap();
+    assert_eq!(untouched.title, "first");
+    assert!(!untouched.is_completed);
+
+    // updates
+    let mut changed = found;
+    changed.title = "renamed".to_string();
+    changed.tags = vec!["home".to_string(), "urgent".to_string()];
+    changed.priority = 3;
+    let updated = todos.update(changed).await.unwrap();
+    assert_eq!(updated.title, "renamed");
+    assert_eq!(updated.tags, vec!["home".to_string(), "urgent".to_string()]);
+    assert_eq!(updated.priority, 3);
+
+    todos.toggle(owner, first.id).await.unwrap();
+    let toggled = todos.find_by_id(owner, first.id).await.unwrap().unwrap();
+    assert!(toggled.is_completed);
+
+    // snoozed todos are hidden from the default listing only
+    let until = Utc::now() + Duration::days(1);
+    let snoozed = todos.snooze(owner, second.id, Some(until)).await.unwrap();
+    assert!(snoozed.snoozed_until.is_some());
+    let visible = todos.find_all(owner).await.unwrap();
+    assert_eq!(
+        visible.iter().map(|todo| todo.id).collect::<Vec<Uuid>>(),
+        vec![first.id]
+    );
+    let everything = todos.find_all_including_snoozed(owner).await.unwrap();
+    assert_eq!(everything.len(), 2);
+
+    // deletes
+    assert!(matches!(
+        todos.delete(owner, foreign.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(
+        todos
+            .find_by_id(stranger, foreign.id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+    todos.delete(stranger, foreign.id).await.unwrap();
+    assert!(matches!(
+        todos.find_by_id(stranger, foreign.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(matches!(
+        todos.delete(stranger, foreign.id).await,
+        Err(ModelError::NotFound)
+    ));
+    assert!(todos.find_all(stranger).await.unwrap().is_empty());
+}
+
+pub async fn lockout_repository(lockout: &dyn LockoutRepository, users: &dyn UserRepository) {
+    let alice = users.create(user("alice@domain.com")).await.unwrap();
+    assert!(lockout.find(alice.id).await.unwrap().is_none());
+
+    // failures count up from one
+    assert_eq!(lockout.record_failure(alice.id).await.unwrap(), 1);
+    assert_eq!(lockout.record_failure(alice.id).await.unwrap(), 2);
+    let failures = lockout.find(alice.id).await.unwrap().unwrap();
+    assert_eq!(failures.failures, 2);
+    assert!(!failures.is_locked(Utc::now()));
+
+    // a lock keeps the failures
+    let until = Utc::now() + Duration::minutes(5);
+    lockout.lock(alice.id, until).await.unwrap();
+    let failures = lockout.find(alice.id).await.unwrap().unwrap();
+    assert_eq!(failures.failures, 2);
+    assert!(failures.is_locked(Utc::now()));
+    assert!(!failures.is_locked(until + Duration::seconds(1)));
+    lockout
+        .record_event(LockoutEvent::new(
+            LockoutEventKind::Locked,
+            alice.id,
+            2,
+            Some(until),
+        ))
+        .await
+        .unwrap();
+
+    // a reset forgets both and starts counting again
+    lockout.reset(alice.id).await.unwrap();
+    assert!(lockout.find(alice.id).await.unwrap().is_none());
+    assert_eq!(lockout.record_failure(alice.id).await.unwrap(), 1);
+}
+
+fn reset(user_id: Uuid, token_hash: &str) -> PasswordReset {
+    PasswordReset {
+        token_hash: token_hash.to_string(),
+        user_id,
+        expires_at: Utc::now() + Duration::hours(1),
+        created_at: Utc::now(),
+    }
+}
+
+pub async fn password_reset_repository(
+    resets: &dyn PasswordResetRepository,
+    users: &dyn UserRepository,
+) {
+    let alice = users.create(user("alice@domain.com")).await.unwrap();
+    let bob = users.create(user("bob@domain.com")).await.unwrap();
+    assert!(resets.take("unknown").await.unwrap().is_none());
+
+    // a token can be taken only once
+    resets.create(reset(alice.id, "alice-1")).await.unwrap();
+    let taken = resets.take("alice-1").await.unwrap().unwrap();
+    assert_eq!(taken.user_id, alice.id);
+    assert!(!taken.is_expired(Utc::now()));
+    assert!(resets.take("alice-1").await.unwrap().is_none());
+
+    // a new request replaces the earlier token of the same user only
+    resets.create(reset(alice.id, "alice-2")).await.unwrap();
+    resets.create(reset(bob.id, "bob-1")).await.unwrap();
+    resets.create(reset(alice.id, "alice-3")).await.unwrap();
+    assert!(resets.take("alice-2").await.unwrap().is_none());
+    assert_eq!(
+        resets.take("alice-3").await.unwrap().unwrap().user_id,
+        alice.id
+    );
+    assert_eq!(resets.take("bob-1").await.unwrap().unwrap().user_id, bob.id);
+}
+
+mod memory {
+    use crate::infrastructure::database::memory::{
+        lockout_repository::InMemoryLockoutRepository,
+        password_reset_repository::InMemoryPasswordResetRepository,
+        todo_repository::InMemoryTodoRepository, user_repository::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn user_repository() {
+        super::user_repository(&InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn todo_repository() {
+        super::todo_repository(
+            &InMemoryTodoRepository::new(),
+            &InMemoryUserRepository::new(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn lockout_repository() {
+        super::lockout_repository(
+            &InMemoryLockoutRepository::new(),
+            &InMemoryUserRepository::new(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn password_reset_repository() {
+        super::password_reset_repository(
+            &InMemoryPasswordResetRepository::new(),
+            &InMemoryUserRepository::new(),
+        )
+        .await;
+    }
+}
+
+// the cache has to keep every read the same as the backend it wraps
+mod cached {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{
+            time_entry::{
+                model::TimeEntry,
+                repository::{MockTimeEntryRepository, TimeEntryRepository},
+            },
+            todo::repository::TodoRepository,
+        },
+        infrastructure::{
+            cache::{
+                RepositoryCache, time_entry_repository::CachedTimeEntryRepository,
+                todo_repository::CachedTodoRepository, user_repository::CachedUserRepository,
+            },
+            database::memory::{
+                todo_repository::InMemoryTodoRepository, user_repository::InMemoryUserRepository,
+            },
+        },
+    };
+
+    fn cache() -> Arc<RepositoryCache> {
+        Arc::new(RepositoryCache::new(100, Duration::from_secs(60)))
+    }
+
+    #[tokio::test]
+    async fn user_repository() {
+        super::user_repository(&CachedUserRepository::new(
+            InMemoryUserRepository::new(),
+            cache(),
+        ))
+        .await;
+    }
+
+    #[tokio::test]
+    async fn todo_repository() {
+        let cache = cache();
+
+        super::todo_repository(
+            &CachedTodoRepository::new(InMemoryTodoRepository::new(), cache.clone()),
+            &CachedUserRepository::new(InMemoryUserRepository::new(), cache),
+        )
+        .await;
+    }
+
+    // tracked seconds are part of the cached listing, so every time entry write drops it
+    #[tokio::test]
+    async fn time_entry_writes_evict_todos() {
+        let cache = cache();
+        let todos = CachedTodoRepository::new(InMemoryTodoRepository::new(), cache.clone());
+        let user_id = Uuid::new_v4();
+        todos.create(super::todo(user_id, "tracked")).await.unwrap();
+
+        let mut inner = MockTimeEntryRepository::new();
+        inner
+            .expect_create()
+            .returning(|entry| Box::pin(async move { Ok(entry) }));
+        inner
+            .expect_update()
+            .returning(|entry| Box::pin(async move { Ok(entry) }));
+        inner
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let entries = CachedTimeEntryRepository::new(inner, cache.clone());
+
+        let entry = TimeEntry {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id: Uuid::new_v4(),
+            started_at: Utc::now(),
+            ended_at: None,
+            note: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        todos.find_all(user_id).await.unwrap();
+        entries.create(entry.clone()).await.unwrap();
+        assert!(cache.todos(user_id).is_none());
+
+        todos.find_all(user_id).await.unwrap();
+        entries.update(entry.clone()).await.unwrap();
+        assert!(cache.todos(user_id).is_none());
+
+        todos.find_all(user_id).await.unwrap();
+        entries.delete(user_id, entry.id).await.unwrap();
+        assert!(cache.todos(user_id).is_none());
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use sqlx::SqlitePool;
+
+    use crate::infrastructure::database::sqlite::{
+        lockout_repository::SqliteLockoutRepository,
+        password_reset_repository::SqlitePasswordResetRepository,
+        todo_repository::SqliteTodoRepository, user_repository::SqliteUserRepository,
+    };
+
+    #[sqlx::test(migrations = "./database/sqlite")]
+    async fn user_repository(pool: SqlitePool) {
+        super::user_repository(&SqliteUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test(migrations = "./database/sqlite")]
+    async fn todo_repository(pool: SqlitePool) {
+        super::todo_repository(
+            &SqliteTodoRepository::new(pool.clone()),
+            &SqliteUserRepository::new(pool),
+        )
+        .await;
+    }
+
+    #[sqlx::test(migrations = "./database/sqlite")]
+    async fn lockout_repository(pool: SqlitePool) {
+        super::lockout_repository(
+            &SqliteLockoutRepository::new(pool.clone()),
+            &SqliteUserRepository::new(pool),
+        )
+        .await;
+    }
+
+    #[sqlx::test(migrations = "./database/sqlite")]
+    async fn password_reset_repository(pool: SqlitePool) {
+        super::password_reset_repository(
+            &SqlitePasswordResetRepository::new(pool.clone()),
+            &SqliteUserRepository::new(pool),
+        )
+        .await;
+    }
+}
+
+// every test gets its own database on the server in DATABASE_URL,
+// run with `cargo test -- --ignored`
+mod postgres {
+    use std::{sync::Arc, time::Duration};
+
+    use sqlx::{PgPool, postgres::PgPoolOptions};
+
+    use crate::{
+        application::{
+            auth::{
+                dto::LoginRequest,
+                error::AuthError,
+                usecase::{AuthUseCase, EmailVerification, LockoutPolicy},
+            },
+            user::{dto::ChangePasswordRequest, usecase::UserUseCase},
+        },
+        domain::{
+            mail::mailer::MockMailer,
+            user::{model::User, repository::UserRepository},
+        },
+        infrastructure::database::{
+            replica::{self, Replicas},
+            sqlx::{
+                lockout_repository::PostgresLockoutRepository,
+                password_reset_repository::PostgresPasswordResetRepository,
+                todo_repository::PostgresTodoRepository,
+                transaction_manager::PostgresTransactionManager,
+                user_repository::PostgresUserRepository,
+            },
+        },
+    };
+
+    // a replica that never catches up, it keeps serving the users as they were when it was made
+    async fn lagging_replica(pool: &PgPool) -> PgPool {
+        sqlx::query("CREATE SCHEMA lagging")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE lagging.users AS TABLE public.users")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let options = pool
+            .connect_options()
+            .as_ref()
+            .clone()
+            .options([("search_path", "lagging")]);
+
+        PgPoolOptions::new().connect_with(options).await.unwrap()
+    }
+
+    #[sqlx::test(migrations = "./database/migrations")]
+    #[ignore = "needs a postgres server in DATABASE_URL"]
+    async fn user_repository(pool: PgPool) {
+        super::user_repository(&PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test(migrations = "./database/migrations")]
+    #[ignore = "needs a postgres server in DATABASE_URL"]
+    async fn todo_repository(pool: PgPool) {
+        super::todo_repository(
+            &PostgresTodoRepository::new(pool.clone()),
+            &PostgresUserRepository::new(pool),
+        )
+        .await;
+    }
+
+    #[sqlx::test(migrations = "./database/migrations")]
+    #[ignore = "needs a postgres server in DATABASE_URL"]
+    async fn lockout_repository(pool: PgPool) {
+        super::lockout_repository(
+            &PostgresLockoutRepository::new(pool.clone()),
+            &PostgresUserRepository::new(pool),
+        )
+        .await;
+    }
+
+    #[sqlx::test(migrations = "./database/migrations")]
+    #[ignore = "needs a postgres server in DATABASE_URL"]
+    async fn password_reset_repository(pool: PgPool) {
+        super::password_reset_repository(
+            &PostgresPasswordResetRepository::new(pool.clone()),
+            &PostgresUserRepository::new(pool),
+        )
+        .await;
+    }
+
+    // the user is written back whole after these reads, one from the replica would bring the old
+    // password back
+    #[sqlx::test(migrations = "./database/migrations")]
+    #[ignore = "needs a postgres server in DATABASE_URL"]
+    async fn password_change_with_lagging_replica(pool: PgPool) {
+        let mut alice = super::user("alice@domain.com");
+        alice.password = User::hash_password("old-secret").unwrap();
+        let alice = PostgresUserRepository::new(pool.clone())
+            .create(alice)
+            .await
+            .unwrap();
+
+        let users = Arc::new(PostgresUserRepository::replicated(
+            pool.clone(),
+            Replicas::new(vec![lagging_replica(&pool).await]),
+        ));
+        let lockout_policy = LockoutPolicy {
+            threshold: 5,
+            duration: Duration::from_secs(60),
+            max_duration: Duration::from_secs(3600),
+        };
+        let verification = EmailVerification {
+            required: true,
+            ttl: Duration::from_secs(3600),
+            url: "http://localhost/verify".to_string(),
+        };
+
+        let user_usecase = UserUseCase::new(
+            users.clone(),
+            PostgresTransactionManager::new(pool.clone()),
+            PostgresLockoutRepository::new(pool.clone()),
+            MockMailer::new(),
+            lockout_policy,
+            verification.clone(),
+            "secret".to_string(),
+        );
+        let auth_usecase = AuthUseCase::new(
+            users.clone(),
+            PostgresLockoutRepository::new(pool.clone()),
+            MockMailer::new(),
+            lockout_policy,
+            verification,
+            "secret".to_string(),
+            60,
+        );
+
+        let login = |password: &str| LoginRequest {
+            email: alice.email.clone(),
+            password: password.to_string(),
+        };
+
+        // every request may read from the replica until it writes
+        replica::scope(
+            false,
+            user_usecase.change_password(
+                alice.id,
+                ChangePasswordRequest {
+                    current_password: "old-secret".to_string(),
+                    new_password: "new-secret".to_string(),
+                },
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            replica::scope(false, auth_usecase.login(login("old-secret"))).await,
+            Err(AuthError::InvalidCredentials)
+        ));
+        replica::scope(false, auth_usecase.login(login("new-secret")))
+            .await
+            .unwrap();
+
+        let stored = users.find_by_id(alice.id).await.unwrap().unwrap();
+        assert!(stored.verify_password("new-secret".to_string()));
+        assert!(stored.password_changed_at.is_some());
+    }
+}
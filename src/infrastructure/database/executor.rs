@@ -0,0 +1,176 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use sqlx::{Database, Pool, Transaction, pool::PoolConnection};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::{
+    domain::{
+        event::repository::EventRepository,
+        shared::{error::ModelError, transaction::UnitOfWork},
+        todo::repository::TodoRepository,
+        user::repository::UserRepository,
+        workflow::repository::WorkflowRepository,
+    },
+    infrastructure::database::replica::{self, Replicas},
+};
+
+// one open transaction shared by every repository of a unit of work, it is taken out on
+// commit or rollback
+pub type SharedTransaction<DB> = Arc<Mutex<Option<Transaction<'static, DB>>>>;
+
+// where a sql repository runs its queries, the pool outside of a unit of work
+pub enum Executor<DB: Database> {
+    Pool(Pool<DB>),
+    // the primary pool, reads that tolerate lag may go to one of the replicas
+    Replicated(Pool<DB>, Replicas<DB>),
+    Transaction(SharedTransaction<DB>),
+}
+
+pub enum Acquired<'e, DB: Database> {
+    Pool(PoolConnection<DB>),
+    Transaction(MutexGuard<'e, Option<Transaction<'static, DB>>>),
+}
+
+impl<DB: Database> Executor<DB> {
+    // queries of one transaction run one after another, the guard is held for a single query
+    pub async fn acquire(&self) -> Result<Acquired<'_, DB>, ModelError> {
+        let acquired = match self {
+            Self::Pool(pool) => pool.acquire().await.map(Acquired::Pool),
+            Self::Replicated(primary, _) => {
+                // whatever the caller reads next has to include this write
+                replica::pin();
+                primary.acquire().await.map(Acquired::Pool)
+            }
+            Self::Transaction(transaction) => {
+                let guard = transaction.lock().await;
+
+                match guard.is_some() {
+                    true => Ok(Acquired::Transaction(guard)),
+                    false => Err(sqlx::Error::Protocol(
+                        "transaction already finished".to_string(),
+                    )),
+                }
+            }
+        };
+
+        acquired.map_err(|err| {
+            tracing::error!("database.acquire : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    // for reads that may lag behind the primary, a replica that can't be reached sends them back
+    // to the primary
+    pub async fn acquire_read(&self) -> Result<Acquired<'_, DB>, ModelError> {
+        let Self::Replicated(primary, replicas) = self else {
+            return self.acquire().await;
+        };
+
+        if let Some(replica) = replicas.pick() {
+            match replica.acquire().await {
+                Ok(connection) => return Ok(Acquired::Pool(connection)),
+                Err(err) => tracing::warn!("database.acquire_read : {}", err.to_string()),
+            }
+        }
+
+        primary.acquire().await.map(Acquired::Pool).map_err(|err| {
+            tracing::error!("database.acquire : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
+
+impl<DB: Database> Deref for Acquired<'_, DB> {
+    type Target = DB::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pool(connection) => connection,
+            // checked in `acquire`, nothing takes the transaction while the guard is held
+            Self::Transaction(guard) => guard.as_ref().unwrap(),
+        }
+    }
+}
+
+impl<DB: Database> DerefMut for Acquired<'_, DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pool(connection) => connection,
+            Self::Transaction(guard) => guard.as_mut().unwrap(),
+        }
+    }
+}
+
+// `statement` replaces the backend's plain BEGIN
+pub async fn begin<DB: Database>(
+    pool: &Pool<DB>,
+    statement: Option<&'static str>,
+) -> Result<SharedTransaction<DB>, ModelError> {
+    let transaction = match statement {
+        Some(statement) => pool.begin_with(statement).await,
+        None => pool.begin().await,
+    };
+
+    let transaction = transaction.map_err(|err| {
+        tracing::error!("database.begin : {}", err.to_string());
+        ModelError::Database(err.to_string())
+    })?;
+
+    Ok(Arc::new(Mutex::new(Some(transaction))))
+}
+
+// the unit of work of the sql backends, the repositories share `transaction`
+pub struct SqlUnitOfWork<DB: Database> {
+    pub transaction: SharedTransaction<DB>,
+    pub todos: Box<dyn TodoRepository>,
+    pub users: Box<dyn UserRepository>,
+    pub events: Box<dyn EventRepository>,
+    pub workflows: Option<Box<dyn WorkflowRepository>>,
+}
+
+impl<DB: Database> SqlUnitOfWork<DB> {
+    async fn take(&self) -> Result<Transaction<'static, DB>, ModelError> {
+        self.transaction
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| ModelError::Database("transaction already finished".to_string()))
+    }
+}
+
+#[async_trait]
+impl<DB: Database> UnitOfWork for SqlUnitOfWork<DB> {
+    fn todos(&self) -> &dyn TodoRepository {
+        self.todos.as_ref()
+    }
+
+    fn users(&self) -> &dyn UserRepository {
+        self.users.as_ref()
+    }
+
+    fn events(&self) -> &dyn EventRepository {
+        self.events.as_ref()
+    }
+
+    fn workflows(&self) -> Option<&dyn WorkflowRepository> {
+        self.workflows.as_deref()
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), ModelError> {
+        self.take().await?.commit().await.map_err(|err| {
+            tracing::error!("database.commit : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), ModelError> {
+        self.take().await?.rollback().await.map_err(|err| {
+            tracing::error!("database.rollback : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
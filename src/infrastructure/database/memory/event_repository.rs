@@ -0,0 +1,106 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    event::{model::TodoEvent, repository::EventRepository},
+    shared::error::ModelError,
+};
+
+// the log is append-only, so the position of an event is its id minus one
+#[derive(Default)]
+pub struct InMemoryEventRepository {
+    events: RwLock<Vec<TodoEvent>>,
+}
+
+impl InMemoryEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn collect(
+        &self,
+        after: i64,
+        limit: i64,
+        filter: impl Fn(&TodoEvent) -> bool,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        let events = self.events.read().map_err(poisoned)?;
+
+        Ok(events
+            .iter()
+            .skip(after.max(0) as usize)
+            .filter(|event| filter(event))
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+fn poisoned<T>(_: T) -> ModelError {
+    ModelError::Database("event log lock poisoned".to_string())
+}
+
+#[async_trait]
+impl EventRepository for InMemoryEventRepository {
+    async fn append(&self, mut event: TodoEvent) -> Result<TodoEvent, ModelError> {
+        let mut events = self.events.write().map_err(poisoned)?;
+
+        event.id = events.len() as i64 + 1;
+        events.push(event.clone());
+
+        Ok(event)
+    }
+
+    async fn find_since(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        self.collect(after, limit, |event| event.user_id == user_id)
+    }
+
+    async fn find_after(&self, after: i64, limit: i64) -> Result<Vec<TodoEvent>, ModelError> {
+        self.collect(after, limit, |_| true)
+    }
+
+    async fn last_id(&self) -> Result<i64, ModelError> {
+        Ok(self.events.read().map_err(poisoned)?.len() as i64)
+    }
+
+    async fn find_changes(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        let page = self.collect(after, limit, |event| event.user_id == user_id)?;
+
+        // keep the last event of every todo, the page is already ordered by id
+        Ok(page
+            .iter()
+            .enumerate()
+            .filter(|(index, event)| {
+                !page[index + 1..]
+                    .iter()
+                    .any(|later| later.todo_id == event.todo_id)
+            })
+            .map(|(_, event)| event.clone())
+            .collect())
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Option<TodoEvent>, ModelError> {
+        let events = self.events.read().map_err(poisoned)?;
+
+        Ok(events
+            .iter()
+            .rev()
+            .find(|event| event.user_id == user_id && event.todo_id == todo_id)
+            .cloned())
+    }
+}
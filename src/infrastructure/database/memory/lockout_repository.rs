@@ -0,0 +1,72 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    lockout::{
+        model::{LockoutEvent, LoginFailures},
+        repository::LockoutRepository,
+    },
+    shared::error::ModelError,
+};
+
+#[derive(Default)]
+pub struct InMemoryLockoutRepository {
+    failures: DashMap<Uuid, LoginFailures>,
+    events: RwLock<Vec<LockoutEvent>>,
+}
+
+impl InMemoryLockoutRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LockoutRepository for InMemoryLockoutRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Option<LoginFailures>, ModelError> {
+        Ok(self
+            .failures
+            .get(&user_id)
+            .map(|entry| entry.value().clone()))
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<i32, ModelError> {
+        let mut entry = self
+            .failures
+            .entry(user_id)
+            .or_insert_with(|| LoginFailures {
+                user_id,
+                failures: 0,
+                locked_until: None,
+                updated_at: Utc::now(),
+            });
+
+        entry.failures += 1;
+        entry.updated_at = Utc::now();
+
+        Ok(entry.failures)
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<(), ModelError> {
+        if let Some(mut entry) = self.failures.get_mut(&user_id) {
+            entry.locked_until = Some(until);
+            entry.updated_at = Utc::now();
+        }
+
+        Ok(())
+    }
+
+    async fn reset(&self, user_id: Uuid) -> Result<(), ModelError> {
+        self.failures.remove(&user_id);
+        Ok(())
+    }
+
+    async fn record_event(&self, event: LockoutEvent) -> Result<(), ModelError> {
+        self.events.write().unwrap().push(event);
+        Ok(())
+    }
+}
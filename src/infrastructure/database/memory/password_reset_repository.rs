@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use dashmap::DashMap;
+
+use crate::domain::{
+    password_reset::{model::PasswordReset, repository::PasswordResetRepository},
+    shared::error::ModelError,
+};
+
+// keyed by the token hash, an earlier reset of the same user is dropped on create
+#[derive(Default)]
+pub struct InMemoryPasswordResetRepository {
+    resets: DashMap<String, PasswordReset>,
+}
+
+impl InMemoryPasswordResetRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for InMemoryPasswordResetRepository {
+    async fn create(&self, reset: PasswordReset) -> Result<(), ModelError> {
+        self.resets
+            .retain(|_, earlier| earlier.user_id != reset.user_id);
+        self.resets.insert(reset.token_hash.clone(), reset);
+
+        Ok(())
+    }
+
+    async fn take(&self, token_hash: &str) -> Result<Option<PasswordReset>, ModelError> {
+        Ok(self.resets.remove(token_hash).map(|(_, reset)| reset))
+    }
+}
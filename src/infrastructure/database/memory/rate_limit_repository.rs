@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use dashmap::DashMap;
+
+use crate::domain::{
+    rate_limit::{model::RateLimitCounter, repository::RateLimitRepository},
+    shared::error::ModelError,
+};
+
+// expired counters are dropped once the map holds this many keys, so one-off addresses don't pile up
+const PURGE_THRESHOLD: usize = 10_000;
+
+// counters of this process only, for the backends without postgres
+#[derive(Default)]
+pub struct InMemoryRateLimitRepository {
+    counters: DashMap<String, RateLimitCounter>,
+}
+
+impl InMemoryRateLimitRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for InMemoryRateLimitRepository {
+    async fn hit(&self, key: &str, window: Duration) -> Result<RateLimitCounter, ModelError> {
+        if self.counters.len() >= PURGE_THRESHOLD {
+            self.purge_expired().await?;
+        }
+
+        let now = Utc::now();
+        let mut counter =
+            self.counters
+                .entry(key.to_string())
+                .or_insert_with(|| RateLimitCounter {
+                    key: key.to_string(),
+                    hits: 0,
+                    expires_at: now + window,
+                });
+
+        if counter.expires_at <= now {
+            counter.hits = 0;
+            counter.expires_at = now + window;
+        }
+        counter.hits += 1;
+
+        Ok(counter.clone())
+    }
+
+    async fn purge_expired(&self) -> Result<u64, ModelError> {
+        let now = Utc::now();
+        let before = self.counters.len();
+        self.counters.retain(|_, counter| counter.expires_at > now);
+
+        Ok(before.saturating_sub(self.counters.len()) as u64)
+    }
+}
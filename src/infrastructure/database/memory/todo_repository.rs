@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    todo::{model::Todo, repository::TodoRepository},
+};
+
+// todos live in the process only, everything is gone on restart
+#[derive(Default)]
+pub struct InMemoryTodoRepository {
+    todos: DashMap<Uuid, Todo>,
+}
+
+impl InMemoryTodoRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn collect(&self, filter: impl Fn(&Todo) -> bool) -> Vec<Todo> {
+        let mut todos = self
+            .todos
+            .iter()
+            .filter(|entry| filter(entry.value()))
+            .map(|entry| entry.value().clone())
+            .collect::<Vec<Todo>>();
+        todos.sort_by_key(|todo| todo.created_at);
+        todos
+    }
+
+    // run `change` on the todo when it belongs to the user
+    fn modify(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        change: impl FnOnce(&mut Todo),
+    ) -> Result<Todo, ModelError> {
+        match self.todos.get_mut(&id) {
+            Some(mut todo) if todo.user_id == user_id => {
+                change(&mut todo);
+                Ok(todo.clone())
+            }
+            _ => Err(ModelError::NotFound),
+        }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for InMemoryTodoRepository {
+    async fn create(&self, todo: Todo) -> Result<Todo, ModelError> {
+        match self.todos.entry(todo.id) {
+            dashmap::Entry::Occupied(_) => Err(ModelError::Conflict),
+            dashmap::Entry::Vacant(entry) => Ok(entry.insert(todo).clone()),
+        }
+    }
+
+    async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
+        self.modify(todo.user_id, todo.id, |stored| {
+            stored.title = todo.title;
+            stored.description = todo.description;
+            stored.priority = todo.priority;
+            stored.tags = todo.tags;
+            stored.due_at = todo.due_at;
+            stored.updated_at = todo.updated_at;
+        })
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.todos
+            .remove_if(&id, |_, todo| todo.user_id == user_id)
+            .map(|_| ())
+            .ok_or(ModelError::NotFound)
+    }
+
+    async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        self.modify(user_id, id, |todo| {
+            todo.is_completed = !todo.is_completed;
+            todo.state_id = None;
+            todo.updated_at = Utc::now();
+        })
+        .map(|_| ())
+    }
+
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        self.modify(user_id, id, |todo| {
+            todo.snoozed_until = until;
+            todo.updated_at = Utc::now();
+        })
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        let now = Utc::now();
+
+        Ok(self.collect(|todo| {
+            todo.user_id == user_id && todo.snoozed_until.is_none_or(|until| until <= now)
+        }))
+    }
+
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        Ok(self.collect(|todo| todo.user_id == user_id))
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
+        match self.todos.get(&id) {
+            Some(todo) if todo.user_id == user_id => Ok(Some(todo.clone())),
+            _ => Err(ModelError::NotFound),
+        }
+    }
+}
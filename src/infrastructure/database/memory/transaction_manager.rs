@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    event::repository::EventRepository,
+    shared::{
+        error::ModelError,
+        transaction::{TransactionManager, UnitOfWork},
+    },
+    todo::repository::TodoRepository,
+    user::repository::UserRepository,
+    workflow::repository::WorkflowRepository,
+};
+
+// the maps apply every write right away, so a unit of work here is not atomic and a rollback
+// keeps what was written, good enough for local development
+#[derive(Clone)]
+pub struct InMemoryTransactionManager {
+    todo: Arc<dyn TodoRepository>,
+    user: Arc<dyn UserRepository>,
+    event: Arc<dyn EventRepository>,
+}
+
+impl InMemoryTransactionManager {
+    pub fn new(
+        todo: Arc<dyn TodoRepository>,
+        user: Arc<dyn UserRepository>,
+        event: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self { todo, user, event }
+    }
+}
+
+#[async_trait]
+impl TransactionManager for InMemoryTransactionManager {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError> {
+        Ok(Box::new(self.clone()))
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryTransactionManager {
+    fn todos(&self) -> &dyn TodoRepository {
+        self.todo.as_ref()
+    }
+
+    fn users(&self) -> &dyn UserRepository {
+        self.user.as_ref()
+    }
+
+    fn events(&self) -> &dyn EventRepository {
+        self.event.as_ref()
+    }
+
+    fn workflows(&self) -> Option<&dyn WorkflowRepository> {
+        None
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), ModelError> {
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), ModelError> {
+        tracing::warn!("in-memory unit of work rolled back, its writes are kept");
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use dashmap::{DashMap, Entry};
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    user::{model::User, repository::UserRepository},
+};
+
+// users live in the process only, `emails` keeps the address unique like the sql constraint
+#[derive(Default)]
+pub struct InMemoryUserRepository {
+    users: DashMap<Uuid, User>,
+    emails: DashMap<String, Uuid>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn find_one(&self, filter: impl Fn(&User) -> bool) -> Result<Option<User>, ModelError> {
+        self.users
+            .iter()
+            .find(|entry| filter(entry.value()))
+            .map(|entry| Some(entry.value().clone()))
+            .ok_or(ModelError::NotFound)
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn create(&self, user: User) -> Result<User, ModelError> {
+        if self.users.contains_key(&user.id) {
+            return Err(ModelError::Conflict);
+        }
+
+        match self.emails.entry(user.email.clone()) {
+            Entry::Occupied(_) => Err(ModelError::Conflict),
+            Entry::Vacant(entry) => {
+                entry.insert(user.id);
+                self.users.insert(user.id, user.clone());
+                Ok(user)
+            }
+        }
+    }
+
+    async fn update(&self, user: &User) -> Result<User, ModelError> {
+        let mut stored = self.users.get_mut(&user.id).ok_or(ModelError::NotFound)?;
+
+        if stored.email != user.email {
+            match self.emails.entry(user.email.clone()) {
+                Entry::Occupied(_) => return Err(ModelError::Conflict),
+                Entry::Vacant(entry) => {
+                    entry.insert(user.id);
+                }
+            }
+            self.emails.remove(&stored.email);
+        }
+
+        stored.name = user.name.clone();
+        stored.email = user.email.clone();
+        stored.password = user.password.clone();
+        stored.token = user.token.clone();
+        stored.email_verified_at = user.email_verified_at;
+        stored.password_changed_at = user.password_changed_at;
+        stored.updated_at = user.updated_at;
+
+        Ok(stored.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        let (_, user) = self.users.remove(&id).ok_or(ModelError::NotFound)?;
+        self.emails.remove(&user.email);
+
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<User>, ModelError> {
+        let mut users = self
+            .users
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect::<Vec<User>>();
+        users.sort_by_key(|user| user.created_at);
+
+        Ok(users)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
+        match self.users.get(&id) {
+            Some(user) => Ok(Some(user.clone())),
+            None => Err(ModelError::NotFound),
+        }
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
+        let id = self.emails.get(email).map(|id| *id);
+
+        match id {
+            Some(id) => self.find_by_id(id).await,
+            None => Err(ModelError::NotFound),
+        }
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
+        self.find_one(|user| user.token.as_deref() == Some(token))
+    }
+}
@@ -0,0 +1,143 @@
+use sqlx::migrate::{AppliedMigration, Migrate, MigrateError, Migrator};
+
+use crate::infrastructure::database::Connection;
+
+// every file in `database/migrations` is embedded, the version prefix sets the order
+static MIGRATOR: Migrator = sqlx::migrate!("./database/migrations");
+
+// the sqlite backend keeps its own, much smaller schema
+#[cfg(feature = "sqlite")]
+static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./database/sqlite");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStatus {
+    Applied,
+    Pending,
+    // the embedded file no longer matches the checksum stored when it was applied
+    Drifted,
+    // applied on the database but not known to this binary
+    Missing,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationPlan {
+    pub version: i64,
+    pub description: String,
+    pub status: MigrationStatus,
+}
+
+// compare the embedded migrations with the database without changing anything
+pub async fn plan(connection: &Connection) -> Result<Vec<MigrationPlan>, MigrateError> {
+    // the in-memory backend has no schema to migrate
+    let Some(migrator) = migrator(connection) else {
+        return Ok(Vec::new());
+    };
+    let applied = applied(connection).await?;
+
+    let mut plan = migrator
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+        .map(|migration| {
+            let status = match applied.iter().find(|row| row.version == migration.version) {
+                Some(row) if row.checksum == migration.checksum => MigrationStatus::Applied,
+                Some(_) => MigrationStatus::Drifted,
+                None => MigrationStatus::Pending,
+            };
+
+            MigrationPlan {
+                version: migration.version,
+                description: migration.description.to_string(),
+                status,
+            }
+        })
+        .collect::<Vec<MigrationPlan>>();
+
+    plan.extend(
+        applied
+            .iter()
+            .filter(|row| !migrator.version_exists(row.version))
+            .map(|row| MigrationPlan {
+                version: row.version,
+                description: String::new(),
+                status: MigrationStatus::Missing,
+            }),
+    );
+    plan.sort_by_key(|migration| migration.version);
+
+    Ok(plan)
+}
+
+// apply pending migrations, drift stops the run before anything is applied, returns the
+// migrations that ran
+pub async fn run(connection: &Connection) -> Result<Vec<MigrationPlan>, MigrateError> {
+    let plan = plan(connection).await?;
+
+    for migration in &plan {
+        match migration.status {
+            MigrationStatus::Drifted => {
+                return Err(MigrateError::VersionMismatch(migration.version));
+            }
+            MigrationStatus::Missing => {
+                return Err(MigrateError::VersionMissing(migration.version));
+            }
+            _ => {}
+        }
+    }
+
+    // the migrator holds an advisory lock, so replicas starting together apply each file once
+    let result = match connection {
+        Connection::Postgres(pool, _) => MIGRATOR.run(pool).await,
+        #[cfg(feature = "sqlite")]
+        Connection::Sqlite(pool) => SQLITE_MIGRATOR.run(pool).await,
+        Connection::Memory(_) => Ok(()),
+    };
+
+    result.inspect_err(|err| {
+        tracing::error!("database migration failed: {}", err);
+    })?;
+
+    Ok(plan
+        .into_iter()
+        .filter(|migration| migration.status == MigrationStatus::Pending)
+        .collect())
+}
+
+fn migrator(connection: &Connection) -> Option<&'static Migrator> {
+    match connection {
+        Connection::Postgres(..) => Some(&MIGRATOR),
+        #[cfg(feature = "sqlite")]
+        Connection::Sqlite(_) => Some(&SQLITE_MIGRATOR),
+        Connection::Memory(_) => None,
+    }
+}
+
+// the bookkeeping table only exists once the first migration ran
+async fn applied(connection: &Connection) -> Result<Vec<AppliedMigration>, MigrateError> {
+    match connection {
+        Connection::Postgres(pool, _) => {
+            let tracked: bool =
+                sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                    .fetch_one(pool)
+                    .await?;
+
+            match tracked {
+                true => pool.acquire().await?.list_applied_migrations().await,
+                false => Ok(Vec::new()),
+            }
+        }
+        #[cfg(feature = "sqlite")]
+        Connection::Sqlite(pool) => {
+            let tracked: bool = sqlx::query_scalar(
+                "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+            )
+            .fetch_one(pool)
+            .await?;
+
+            match tracked {
+                true => pool.acquire().await?.list_applied_migrations().await,
+                false => Ok(Vec::new()),
+            }
+        }
+        Connection::Memory(_) => Ok(Vec::new()),
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+use sqlx::{Database, Pool};
+
+tokio::task_local! {
+    static PRIMARY: AtomicBool;
+}
+
+// read-only copies of the primary, queries that may lag behind it take turns on them
+pub struct Replicas<DB: Database> {
+    pools: Arc<[Pool<DB>]>,
+    next: Arc<AtomicUsize>,
+}
+
+impl<DB: Database> Replicas<DB> {
+    pub fn new(pools: Vec<Pool<DB>>) -> Self {
+        Self {
+            pools: pools.into(),
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    // round robin, none while the caller has to read its own writes
+    pub fn pick(&self) -> Option<&Pool<DB>> {
+        if self.pools.is_empty() || pinned() {
+            return None;
+        }
+
+        let next = self.next.fetch_add(1, Ordering::Relaxed);
+        self.pools.get(next % self.pools.len())
+    }
+}
+
+impl<DB: Database> Clone for Replicas<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            pools: self.pools.clone(),
+            next: self.next.clone(),
+        }
+    }
+}
+
+// reads of `future` may go to a replica until it wrote something, or never with `primary`,
+// outside of any scope everything reads from the primary
+pub async fn scope<F: Future>(primary: bool, future: F) -> F::Output {
+    PRIMARY.scope(AtomicBool::new(primary), future).await
+}
+
+// the rest of the current scope reads from the primary
+pub fn pin() {
+    let _ = PRIMARY.try_with(|primary| primary.store(true, Ordering::Relaxed));
+}
+
+pub fn pinned() -> bool {
+    PRIMARY
+        .try_with(|primary| primary.load(Ordering::Relaxed))
+        .unwrap_or(true)
+}
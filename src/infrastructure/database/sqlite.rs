@@ -0,0 +1,6 @@
+pub mod event_repository;
+pub mod lockout_repository;
+pub mod password_reset_repository;
+pub mod todo_repository;
+pub mod transaction_manager;
+pub mod user_repository;
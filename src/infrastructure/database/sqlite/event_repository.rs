@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, SqlitePool, types::Json};
+use uuid::{Uuid, fmt::Hyphenated};
+
+use crate::{
+    domain::{
+        event::{
+            model::{TodoEvent, TodoEventKind},
+            repository::EventRepository,
+        },
+        shared::error::ModelError,
+    },
+    infrastructure::database::executor::{Executor, SharedTransaction},
+};
+
+pub struct SqliteEventRepository {
+    executor: Executor<Sqlite>,
+}
+
+impl SqliteEventRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Sqlite>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct EventRow {
+    id: i64,
+    user_id: Hyphenated,
+    todo_id: Hyphenated,
+    kind: TodoEventKind,
+    payload: Json<serde_json::Value>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<EventRow> for TodoEvent {
+    fn from(value: EventRow) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id.into_uuid(),
+            todo_id: value.todo_id.into_uuid(),
+            kind: value.kind,
+            payload: value.payload.0,
+            created_at: value.created_at,
+        }
+    }
+}
+
+fn into_events(rows: Vec<EventRow>) -> Vec<TodoEvent> {
+    rows.into_iter().map(TodoEvent::from).collect()
+}
+
+#[async_trait]
+impl EventRepository for SqliteEventRepository {
+    // webhooks are postgres only, so nothing is queued next to the event. sqlite has a single
+    // writer, ids are committed in the order they are drawn
+    async fn append(&self, event: TodoEvent) -> Result<TodoEvent, ModelError> {
+        sqlx::query_as::<_, EventRow>(
+            r#"
+            INSERT INTO
+            todo_events(user_id, todo_id, kind, payload, created_at)
+            VALUES ($1,$2,$3,$4,$5)
+            RETURNING id, user_id, todo_id, kind, payload, created_at
+            "#,
+        )
+        .bind(event.user_id.hyphenated())
+        .bind(event.todo_id.hyphenated())
+        .bind(event.kind)
+        .bind(Json(&event.payload))
+        .bind(event.created_at)
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map(TodoEvent::from)
+        .map_err(|err| {
+            tracing::error!("event_repository.append : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_since(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, EventRow>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE user_id = $1 AND id > $2
+            ORDER BY id
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id.hyphenated())
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map(into_events)
+        .map_err(|err| {
+            tracing::error!("event_repository.find_since : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_after(&self, after: i64, limit: i64) -> Result<Vec<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, EventRow>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE id > $1
+            ORDER BY id
+            LIMIT $2
+            "#,
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map(into_events)
+        .map_err(|err| {
+            tracing::error!("event_repository.find_after : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn last_id(&self) -> Result<i64, ModelError> {
+        sqlx::query_scalar::<_, i64>("SELECT COALESCE(MAX(id), 0) FROM todo_events")
+            .fetch_one(&mut *self.executor.acquire().await?)
+            .await
+            .map_err(|err| {
+                tracing::error!("event_repository.last_id : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })
+    }
+
+    async fn find_changes(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        // sqlite has no DISTINCT ON, the highest id per todo within the page is its latest event
+        sqlx::query_as::<_, EventRow>(
+            r#"
+            WITH page AS (
+                SELECT id, todo_id FROM todo_events
+                WHERE user_id = $1 AND id > $2
+                ORDER BY id
+                LIMIT $3
+            ),
+            latest AS (
+                SELECT MAX(id) AS id FROM page GROUP BY todo_id
+            )
+            SELECT e.id, e.user_id, e.todo_id, e.kind, e.payload, e.created_at
+            FROM todo_events e JOIN latest l ON l.id = e.id
+            ORDER BY e.id
+            "#,
+        )
+        .bind(user_id.hyphenated())
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map(into_events)
+        .map_err(|err| {
+            tracing::error!("event_repository.find_changes : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Option<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, EventRow>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE user_id = $1 AND todo_id = $2
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id.hyphenated())
+        .bind(todo_id.hyphenated())
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map(|row| row.map(TodoEvent::from))
+        .map_err(|err| {
+            tracing::error!("event_repository.find_latest : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use uuid::{Uuid, fmt::Hyphenated};
+
+use crate::domain::{
+    lockout::{
+        model::{LockoutEvent, LoginFailures},
+        repository::LockoutRepository,
+    },
+    shared::error::ModelError,
+};
+
+pub struct SqliteLockoutRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteLockoutRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct FailuresRow {
+    user_id: Hyphenated,
+    failures: i32,
+    locked_until: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<FailuresRow> for LoginFailures {
+    fn from(value: FailuresRow) -> Self {
+        Self {
+            user_id: value.user_id.into_uuid(),
+            failures: value.failures,
+            locked_until: value.locked_until,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl LockoutRepository for SqliteLockoutRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Option<LoginFailures>, ModelError> {
+        sqlx::query_as::<_, FailuresRow>(
+            r#"
+            SELECT user_id, failures, locked_until, updated_at
+            FROM login_failures WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.hyphenated())
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(LoginFailures::from))
+        .map_err(|err| {
+            tracing::error!("lockout_repository.find : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<i32, ModelError> {
+        sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO login_failures(user_id, failures, updated_at)
+            VALUES ($1, 1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET failures = login_failures.failures + 1, updated_at = excluded.updated_at
+            RETURNING failures
+            "#,
+        )
+        .bind(user_id.hyphenated())
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.record_failure : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<(), ModelError> {
+        sqlx::query(
+            "UPDATE login_failures SET locked_until = $1, updated_at = $2 WHERE user_id = $3",
+        )
+        .bind(until)
+        .bind(Utc::now())
+        .bind(user_id.hyphenated())
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.lock : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn reset(&self, user_id: Uuid) -> Result<(), ModelError> {
+        sqlx::query("DELETE FROM login_failures WHERE user_id = $1")
+            .bind(user_id.hyphenated())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("lockout_repository.reset : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?;
+
+        Ok(())
+    }
+
+    async fn record_event(&self, event: LockoutEvent) -> Result<(), ModelError> {
+        sqlx::query(
+            r#"
+            INSERT INTO
+            lockout_events(id, user_id, kind, failures, locked_until, created_at)
+            VALUES ($1,$2,$3,$4,$5,$6)
+            "#,
+        )
+        .bind(event.id.hyphenated())
+        .bind(event.user_id.hyphenated())
+        .bind(event.kind)
+        .bind(event.failures)
+        .bind(event.locked_until)
+        .bind(event.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.record_event : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+}
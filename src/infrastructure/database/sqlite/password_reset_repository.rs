@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use uuid::fmt::Hyphenated;
+
+use crate::domain::{
+    password_reset::{model::PasswordReset, repository::PasswordResetRepository},
+    shared::error::ModelError,
+};
+
+pub struct SqlitePasswordResetRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePasswordResetRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ResetRow {
+    token_hash: String,
+    user_id: Hyphenated,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ResetRow> for PasswordReset {
+    fn from(value: ResetRow) -> Self {
+        Self {
+            token_hash: value.token_hash,
+            user_id: value.user_id.into_uuid(),
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for SqlitePasswordResetRepository {
+    async fn create(&self, reset: PasswordReset) -> Result<(), ModelError> {
+        sqlx::query(
+            r#"
+            INSERT INTO password_resets(token_hash, user_id, expires_at, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE
+            SET token_hash = excluded.token_hash,
+                expires_at = excluded.expires_at,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(reset.token_hash)
+        .bind(reset.user_id.hyphenated())
+        .bind(reset.expires_at)
+        .bind(reset.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("password_reset_repository.create : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn take(&self, token_hash: &str) -> Result<Option<PasswordReset>, ModelError> {
+        sqlx::query_as::<_, ResetRow>(
+            r#"
+            DELETE FROM password_resets WHERE token_hash = $1
+            RETURNING token_hash, user_id, expires_at, created_at
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(PasswordReset::from))
+        .map_err(|err| {
+            tracing::error!("password_reset_repository.take : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,270 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, SqlitePool, types::Json};
+use uuid::{Uuid, fmt::Hyphenated};
+
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        todo::{model::Todo, repository::TodoRepository},
+    },
+    infrastructure::database::executor::{Executor, SharedTransaction},
+};
+
+const COLUMNS: &str = "id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at";
+
+pub struct SqliteTodoRepository {
+    executor: Executor<Sqlite>,
+}
+
+impl SqliteTodoRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Sqlite>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
+    }
+}
+
+// ids are stored as hyphenated text and tags as a json array, time tracking is postgres only
+#[derive(sqlx::FromRow)]
+struct TodoRow {
+    id: Hyphenated,
+    user_id: Hyphenated,
+    title: String,
+    description: String,
+    is_completed: bool,
+    state_id: Option<Hyphenated>,
+    priority: i16,
+    tags: Json<Vec<String>>,
+    project: Option<String>,
+    due_at: Option<DateTime<Utc>>,
+    snoozed_until: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TodoRow> for Todo {
+    fn from(value: TodoRow) -> Self {
+        Self {
+            id: value.id.into_uuid(),
+            user_id: value.user_id.into_uuid(),
+            title: value.title,
+            description: value.description,
+            is_completed: value.is_completed,
+            state_id: value.state_id.map(Hyphenated::into_uuid),
+            priority: value.priority,
+            tags: value.tags.0,
+            project: value.project,
+            due_at: value.due_at,
+            snoozed_until: value.snoozed_until,
+            tracked_seconds: 0,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for SqliteTodoRepository {
+    async fn create(&self, todo: Todo) -> Result<Todo, ModelError> {
+        let created = sqlx::query_as::<_, TodoRow>(&format!(
+            r#"
+            INSERT INTO
+            todos(id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, created_at, updated_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12)
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(todo.id.hyphenated())
+        .bind(todo.user_id.hyphenated())
+        .bind(todo.title.as_str())
+        .bind(todo.description.as_str())
+        .bind(todo.is_completed)
+        .bind(todo.state_id.map(|id| id.hyphenated()))
+        .bind(todo.priority)
+        .bind(Json(&todo.tags))
+        .bind(&todo.project)
+        .bind(todo.due_at)
+        .bind(todo.created_at)
+        .bind(todo.updated_at)
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.create : {}", err.to_string());
+            if err
+                .as_database_error()
+                .is_some_and(|db_err| db_err.is_unique_violation())
+            {
+                return ModelError::Conflict;
+            }
+
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(created.into())
+    }
+
+    async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
+        let updated = sqlx::query_as::<_, TodoRow>(&format!(
+            r#"
+            UPDATE todos
+            SET title=$1, description=$2, priority=$3, tags=$4, project=$5, due_at=$6, updated_at=$7
+            WHERE id=$8 AND user_id=$9
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(todo.title)
+        .bind(todo.description)
+        .bind(todo.priority)
+        .bind(Json(todo.tags))
+        .bind(todo.project)
+        .bind(todo.due_at)
+        .bind(todo.updated_at)
+        .bind(todo.id.hyphenated())
+        .bind(todo.user_id.hyphenated())
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.update : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        updated.map(Todo::from).ok_or(ModelError::NotFound)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM todos WHERE id = $1 AND user_id = $2")
+            .bind(id.hyphenated())
+            .bind(user_id.hyphenated())
+            .execute(&mut *self.executor.acquire().await?)
+            .await
+            .map_err(|err| {
+                tracing::error!("todo_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query(
+            r#"
+            UPDATE todos
+            SET is_completed = NOT is_completed, state_id = NULL, updated_at = $1
+            WHERE user_id = $2 AND id = $3
+            "#,
+        )
+        .bind(Utc::now())
+        .bind(user_id.hyphenated())
+        .bind(id.hyphenated())
+        .execute(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.toggle : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?
+        .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        let snoozed = sqlx::query_as::<_, TodoRow>(&format!(
+            r#"
+            UPDATE todos
+            SET snoozed_until=$1, updated_at=$2
+            WHERE id=$3 AND user_id=$4
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(until)
+        .bind(Utc::now())
+        .bind(id.hyphenated())
+        .bind(user_id.hyphenated())
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.snooze : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        snoozed.map(Todo::from).ok_or(ModelError::NotFound)
+    }
+
+    // timestamps are rfc 3339 text in utc, so they compare correctly as strings
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        sqlx::query_as::<_, TodoRow>(&format!(
+            r#"
+            SELECT {COLUMNS}
+            FROM todos
+            WHERE user_id=$1 AND (snoozed_until IS NULL OR snoozed_until <= $2)
+            ORDER BY created_at
+            "#
+        ))
+        .bind(user_id.hyphenated())
+        .bind(Utc::now())
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map(|rows| rows.into_iter().map(Todo::from).collect())
+        .map_err(|err| {
+            tracing::error!("todo_repository.find_all : {}", err.to_string());
+            ModelError::NotFound
+        })
+    }
+
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        sqlx::query_as::<_, TodoRow>(&format!(
+            "SELECT {COLUMNS} FROM todos WHERE user_id=$1 ORDER BY created_at"
+        ))
+        .bind(user_id.hyphenated())
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map(|rows| rows.into_iter().map(Todo::from).collect())
+        .map_err(|err| {
+            tracing::error!(
+                "todo_repository.find_all_including_snoozed : {}",
+                err.to_string()
+            );
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
+        let result = sqlx::query_as::<_, TodoRow>(&format!(
+            "SELECT {COLUMNS} FROM todos WHERE id = $1 AND user_id=$2"
+        ))
+        .bind(id.hyphenated())
+        .bind(user_id.hyphenated())
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.find_by_id : {}", err.to_string());
+            ModelError::NotFound
+        })?;
+
+        match result {
+            Some(todo) => Ok(Some(todo.into())),
+            None => Err(ModelError::NotFound),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::{
+    domain::shared::{
+        error::ModelError,
+        transaction::{TransactionManager, UnitOfWork},
+    },
+    infrastructure::database::{
+        executor::{self, SqlUnitOfWork},
+        sqlite::{
+            event_repository::SqliteEventRepository, todo_repository::SqliteTodoRepository,
+            user_repository::SqliteUserRepository,
+        },
+    },
+};
+
+pub struct SqliteTransactionManager {
+    pub pool: SqlitePool,
+}
+
+impl SqliteTransactionManager {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TransactionManager for SqliteTransactionManager {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError> {
+        // take the write lock up front, a deferred transaction that reads before writing fails
+        // with `database is locked` instead of waiting when another writer got there first
+        let transaction = executor::begin(&self.pool, Some("BEGIN IMMEDIATE")).await?;
+
+        Ok(Box::new(SqlUnitOfWork {
+            todos: Box::new(SqliteTodoRepository::transactional(transaction.clone())),
+            users: Box::new(SqliteUserRepository::transactional(transaction.clone())),
+            events: Box::new(SqliteEventRepository::transactional(transaction.clone())),
+            workflows: None,
+            transaction,
+        }))
+    }
+}
@@ -0,0 +1,204 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, SqlitePool};
+use uuid::{Uuid, fmt::Hyphenated};
+
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::database::executor::{Executor, SharedTransaction},
+};
+
+const COLUMNS: &str = "id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at";
+
+pub struct SqliteUserRepository {
+    executor: Executor<Sqlite>,
+}
+
+impl SqliteUserRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Sqlite>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct UserRow {
+    id: Hyphenated,
+    name: String,
+    email: String,
+    password: String,
+    token: Option<String>,
+    email_verified_at: Option<DateTime<Utc>>,
+    password_changed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<UserRow> for User {
+    fn from(value: UserRow) -> Self {
+        Self {
+            id: value.id.into_uuid(),
+            name: value.name,
+            email: value.email,
+            password: value.password,
+            token: value.token,
+            email_verified_at: value.email_verified_at,
+            password_changed_at: value.password_changed_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn create(&self, user: User) -> Result<User, ModelError> {
+        let saved = sqlx::query_as::<_, UserRow>(&format!(
+            r#"
+            INSERT INTO users (id, name, email, password, email_verified_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(user.id.hyphenated())
+        .bind(user.name)
+        .bind(user.email)
+        .bind(user.password)
+        .bind(user.email_verified_at)
+        .bind(user.created_at)
+        .bind(user.updated_at)
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("user_repository.create : {}", err.to_string());
+            if err
+                .as_database_error()
+                .is_some_and(|db_err| db_err.is_unique_violation())
+            {
+                return ModelError::Conflict;
+            }
+
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(saved.into())
+    }
+
+    async fn update(&self, user: &User) -> Result<User, ModelError> {
+        let updated = sqlx::query_as::<_, UserRow>(&format!(
+            r#"
+            UPDATE users
+            SET name=$1, email=$2, password=$3, token=$4, email_verified_at=$5,
+            password_changed_at=$6, updated_at=$7
+            WHERE id=$8
+            RETURNING {COLUMNS}
+            "#
+        ))
+        .bind(&user.name)
+        .bind(&user.email)
+        .bind(&user.password)
+        .bind(&user.token)
+        .bind(user.email_verified_at)
+        .bind(user.password_changed_at)
+        .bind(user.updated_at)
+        .bind(user.id.hyphenated())
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("user_repository.update : {}", err.to_string());
+            if err
+                .as_database_error()
+                .is_some_and(|db_err| db_err.is_unique_violation())
+            {
+                return ModelError::Conflict;
+            }
+
+            match err {
+                sqlx::Error::RowNotFound => ModelError::NotFound,
+                err => ModelError::Database(err.to_string()),
+            }
+        })?;
+
+        Ok(updated.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(id.hyphenated())
+            .execute(&mut *self.executor.acquire().await?)
+            .await
+            .map_err(|err| {
+                tracing::error!("user_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<User>, ModelError> {
+        sqlx::query_as::<_, UserRow>(&format!("SELECT {COLUMNS} FROM users"))
+            .fetch_all(&mut *self.executor.acquire().await?)
+            .await
+            .map(|rows| rows.into_iter().map(User::from).collect())
+            .map_err(|err| {
+                tracing::error!("user_repository.find_all : {}", err.to_string());
+                ModelError::NotFound
+            })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
+        self.find_one("id", id.hyphenated().to_string(), "find_by_id")
+            .await
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
+        self.find_one("email", email.to_string(), "find_by_email")
+            .await
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
+        self.find_one("token", token.to_string(), "find_by_token")
+            .await
+    }
+}
+
+impl SqliteUserRepository {
+    // `column` is always one of the fixed names above, never user input
+    async fn find_one(
+        &self,
+        column: &str,
+        value: String,
+        method: &str,
+    ) -> Result<Option<User>, ModelError> {
+        let result = sqlx::query_as::<_, UserRow>(&format!(
+            "SELECT {COLUMNS} FROM users WHERE {column} = $1"
+        ))
+        .bind(value)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("user_repository.{} : {}", method, err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        match result {
+            Some(user) => Ok(Some(user.into())),
+            None => Err(ModelError::NotFound),
+        }
+    }
+}
@@ -1,2 +1,14 @@
+pub mod event_repository;
+pub mod idempotency_repository;
+pub mod lockout_repository;
+pub mod notification_repository;
+pub mod password_reset_repository;
+pub mod rate_limit_repository;
+pub mod reminder_repository;
+pub mod template_repository;
+pub mod time_entry_repository;
 pub mod todo_repository;
+pub mod transaction_manager;
 pub mod user_repository;
+pub mod webhook_repository;
+pub mod workflow_repository;
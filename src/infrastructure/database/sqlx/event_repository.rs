@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        event::{model::TodoEvent, repository::EventRepository},
+        shared::error::ModelError,
+        webhook::model::WebhookEvent,
+    },
+    infrastructure::database::executor::{Executor, SharedTransaction},
+};
+
+pub struct PostgresEventRepository {
+    executor: Executor<Postgres>,
+}
+
+impl PostgresEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Postgres>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
+    }
+}
+
+#[async_trait]
+impl EventRepository for PostgresEventRepository {
+    async fn append(&self, event: TodoEvent) -> Result<TodoEvent, ModelError> {
+        // the event and a delivery for every subscribed webhook are written in one statement,
+        // so the webhook queue never misses a recorded event, listeners are woken on commit.
+        // ids are drawn under a lock held until commit, otherwise a transaction could commit a
+        // smaller id after readers already moved their cursor past a larger one
+        sqlx::query_as::<_, TodoEvent>(
+            r#"
+            WITH locked AS (
+                SELECT pg_advisory_xact_lock(hashtext('todo_events'))
+            ), event AS (
+                INSERT INTO
+                todo_events(user_id, todo_id, kind, payload, created_at)
+                SELECT $1,$2,$3,$4,$5 FROM locked
+                RETURNING id, user_id, todo_id, kind, payload, created_at
+            ), queued AS (
+                INSERT INTO
+                webhook_deliveries(webhook_id, user_id, event_id, event, payload)
+                SELECT
+                w.id, w.user_id, e.id, $6,
+                jsonb_build_object('event', $6, 'event_id', e.id, 'occurred_at', e.created_at, 'data', e.payload)
+                FROM webhooks w JOIN event e ON e.user_id = w.user_id
+                WHERE w.active AND $6 = ANY(w.events)
+            ), notified AS (
+                SELECT pg_notify('todo_events', e.id::text) FROM event e
+            )
+            SELECT id, user_id, todo_id, kind, payload, created_at FROM event CROSS JOIN notified
+            "#,
+        )
+        .bind(event.user_id)
+        .bind(event.todo_id)
+        .bind(event.kind)
+        .bind(&event.payload)
+        .bind(event.created_at)
+        .bind(WebhookEvent::of(&event).as_str())
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("event_repository.append : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_since(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, TodoEvent>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE user_id = $1 AND id > $2
+            ORDER BY id
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("event_repository.find_since : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_after(&self, after: i64, limit: i64) -> Result<Vec<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, TodoEvent>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE id > $1
+            ORDER BY id
+            LIMIT $2
+            "#,
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("event_repository.find_after : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn last_id(&self) -> Result<i64, ModelError> {
+        sqlx::query_scalar::<_, i64>("SELECT COALESCE(MAX(id), 0) FROM todo_events")
+            .fetch_one(&mut *self.executor.acquire().await?)
+            .await
+            .map_err(|err| {
+                tracing::error!("event_repository.last_id : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })
+    }
+
+    async fn find_changes(
+        &self,
+        user_id: Uuid,
+        after: i64,
+        limit: i64,
+    ) -> Result<Vec<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, TodoEvent>(
+            r#"
+            WITH page AS (
+                SELECT id FROM todo_events
+                WHERE user_id = $1 AND id > $2
+                ORDER BY id
+                LIMIT $3
+            ),
+            latest AS (
+                SELECT DISTINCT ON (e.todo_id)
+                e.id, e.user_id, e.todo_id, e.kind, e.payload, e.created_at
+                FROM todo_events e JOIN page p ON p.id = e.id
+                ORDER BY e.todo_id, e.id DESC
+            )
+            SELECT id, user_id, todo_id, kind, payload, created_at
+            FROM latest ORDER BY id
+            "#,
+        )
+        .bind(user_id)
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("event_repository.find_changes : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_latest(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Option<TodoEvent>, ModelError> {
+        sqlx::query_as::<_, TodoEvent>(
+            r#"
+            SELECT
+            id, user_id, todo_id, kind, payload, created_at
+            FROM todo_events WHERE user_id = $1 AND todo_id = $2
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(todo_id)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("event_repository.find_latest : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    idempotency::{model::IdempotencyRecord, repository::IdempotencyRepository},
+    shared::error::ModelError,
+};
+
+pub struct PostgresIdempotencyRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresIdempotencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for PostgresIdempotencyRepository {
+    // an expired record, or one left unfinished by a crashed request, can be taken over
+    async fn reserve(&self, record: IdempotencyRecord) -> Result<bool, ModelError> {
+        let reserved = sqlx::query_scalar::<_, String>(
+            r#"
+            INSERT INTO
+            idempotency_keys(scope, key, fingerprint, created_at, expires_at)
+            VALUES ($1,$2,$3,$4,$5)
+            ON CONFLICT (scope, key) DO UPDATE
+            SET fingerprint = EXCLUDED.fingerprint, status_code = NULL, content_type = NULL,
+            body = NULL, created_at = EXCLUDED.created_at, expires_at = EXCLUDED.expires_at
+            WHERE idempotency_keys.expires_at <= now()
+            OR (idempotency_keys.status_code IS NULL AND idempotency_keys.created_at < now() - INTERVAL '1 minute')
+            RETURNING key
+            "#,
+        )
+        .bind(record.scope)
+        .bind(record.key)
+        .bind(record.fingerprint)
+        .bind(record.created_at)
+        .bind(record.expires_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("idempotency_repository.reserve : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(reserved.is_some())
+    }
+
+    async fn find(&self, scope: &str, key: &str) -> Result<Option<IdempotencyRecord>, ModelError> {
+        sqlx::query_as::<_, IdempotencyRecord>(
+            r#"
+            SELECT
+            scope, key, fingerprint, status_code, content_type, body, created_at, expires_at
+            FROM idempotency_keys WHERE scope = $1 AND key = $2 AND expires_at > now()
+            "#,
+        )
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("idempotency_repository.find : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        status_code: i32,
+        content_type: Option<String>,
+        body: Vec<u8>,
+    ) -> Result<(), ModelError> {
+        sqlx::query(
+            r#"
+            UPDATE idempotency_keys
+            SET status_code = $1, content_type = $2, body = $3
+            WHERE scope = $4 AND key = $5
+            "#,
+        )
+        .bind(status_code)
+        .bind(content_type)
+        .bind(body)
+        .bind(scope)
+        .bind(key)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("idempotency_repository.complete : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn release(&self, scope: &str, key: &str) -> Result<(), ModelError> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE scope = $1 AND key = $2")
+            .bind(scope)
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("idempotency_repository.release : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<u64, ModelError> {
+        let rows = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= now()")
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("idempotency_repository.purge_expired : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        Ok(rows)
+    }
+}
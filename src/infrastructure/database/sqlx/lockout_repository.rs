@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    lockout::{
+        model::{LockoutEvent, LoginFailures},
+        repository::LockoutRepository,
+    },
+    shared::error::ModelError,
+};
+
+pub struct PostgresLockoutRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresLockoutRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LockoutRepository for PostgresLockoutRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Option<LoginFailures>, ModelError> {
+        sqlx::query_as::<_, LoginFailures>(
+            r#"
+            SELECT user_id, failures, locked_until, updated_at
+            FROM login_failures WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.find : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    // concurrent failures are all counted, the upsert takes the row lock
+    async fn record_failure(&self, user_id: Uuid) -> Result<i32, ModelError> {
+        sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO login_failures(user_id, failures, updated_at)
+            VALUES ($1, 1, now())
+            ON CONFLICT (user_id) DO UPDATE
+            SET failures = login_failures.failures + 1, updated_at = now()
+            RETURNING failures
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.record_failure : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<(), ModelError> {
+        sqlx::query(
+            "UPDATE login_failures SET locked_until = $1, updated_at = now() WHERE user_id = $2",
+        )
+        .bind(until)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.lock : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn reset(&self, user_id: Uuid) -> Result<(), ModelError> {
+        sqlx::query("DELETE FROM login_failures WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("lockout_repository.reset : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?;
+
+        Ok(())
+    }
+
+    async fn record_event(&self, event: LockoutEvent) -> Result<(), ModelError> {
+        sqlx::query(
+            r#"
+            INSERT INTO
+            lockout_events(id, user_id, kind, failures, locked_until, created_at)
+            VALUES ($1,$2,$3,$4,$5,$6)
+            "#,
+        )
+        .bind(event.id)
+        .bind(event.user_id)
+        .bind(event.kind)
+        .bind(event.failures)
+        .bind(event.locked_until)
+        .bind(event.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("lockout_repository.record_event : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    notification::{model::Notification, repository::NotificationRepository},
+    shared::error::ModelError,
+};
+
+pub struct PostgresNotificationRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresNotificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for PostgresNotificationRepository {
+    async fn find_all(&self, user_id: Uuid, unread: bool) -> Result<Vec<Notification>, ModelError> {
+        sqlx::query_as::<_, Notification>(
+            r#"
+            SELECT
+            id, user_id, todo_id, reminder_id, title, message, read_at, created_at
+            FROM notifications
+            WHERE user_id = $1 AND (NOT $2 OR read_at IS NULL)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(unread)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("notification_repository.find_all : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn mark_read(&self, user_id: Uuid, id: Uuid) -> Result<Notification, ModelError> {
+        sqlx::query_as::<_, Notification>(
+            r#"
+            UPDATE notifications
+            SET read_at = COALESCE(read_at, now())
+            WHERE id = $1 AND user_id = $2
+            RETURNING
+            id, user_id, todo_id, reminder_id, title, message, read_at, created_at
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("notification_repository.mark_read : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?
+        .ok_or(ModelError::NotFound)
+    }
+}
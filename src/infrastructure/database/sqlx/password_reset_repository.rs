@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    password_reset::{model::PasswordReset, repository::PasswordResetRepository},
+    shared::error::ModelError,
+};
+
+pub struct PostgresPasswordResetRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresPasswordResetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PostgresPasswordResetRepository {
+    async fn create(&self, reset: PasswordReset) -> Result<(), ModelError> {
+        sqlx::query(
+            r#"
+            INSERT INTO password_resets(token_hash, user_id, expires_at, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE
+            SET token_hash = excluded.token_hash,
+                expires_at = excluded.expires_at,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(reset.token_hash)
+        .bind(reset.user_id)
+        .bind(reset.expires_at)
+        .bind(reset.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("password_reset_repository.create : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    // of two concurrent resets with the same token only one gets the row
+    async fn take(&self, token_hash: &str) -> Result<Option<PasswordReset>, ModelError> {
+        sqlx::query_as::<_, PasswordReset>(
+            r#"
+            DELETE FROM password_resets WHERE token_hash = $1
+            RETURNING token_hash, user_id, expires_at, created_at
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("password_reset_repository.take : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
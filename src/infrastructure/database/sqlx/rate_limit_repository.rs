@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use sqlx::PgPool;
+
+use crate::domain::{
+    rate_limit::{model::RateLimitCounter, repository::RateLimitRepository},
+    shared::error::ModelError,
+};
+
+// counters live in postgres so every instance counts against the same limit
+pub struct PostgresRateLimitRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresRateLimitRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for PostgresRateLimitRepository {
+    async fn hit(&self, key: &str, window: Duration) -> Result<RateLimitCounter, ModelError> {
+        sqlx::query_as::<_, RateLimitCounter>(
+            r#"
+            INSERT INTO rate_limits(key, hits, expires_at)
+            VALUES ($1, 1, now() + make_interval(secs => $2))
+            ON CONFLICT (key) DO UPDATE
+            SET hits = CASE WHEN rate_limits.expires_at <= now() THEN 1 ELSE rate_limits.hits + 1 END,
+            expires_at = CASE WHEN rate_limits.expires_at <= now() THEN EXCLUDED.expires_at ELSE rate_limits.expires_at END
+            RETURNING key, hits, expires_at
+            "#,
+        )
+        .bind(key)
+        .bind(window.num_milliseconds() as f64 / 1000.0)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("rate_limit_repository.hit : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn purge_expired(&self) -> Result<u64, ModelError> {
+        let rows = sqlx::query("DELETE FROM rate_limits WHERE expires_at <= now()")
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("rate_limit_repository.purge_expired : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        Ok(rows)
+    }
+}
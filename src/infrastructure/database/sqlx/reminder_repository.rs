@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    notification::model::Notification,
+    reminder::{model::Reminder, repository::ReminderRepository},
+    shared::error::ModelError,
+};
+
+pub struct PostgresReminderRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresReminderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for PostgresReminderRepository {
+    async fn create(&self, reminder: Reminder) -> Result<Reminder, ModelError> {
+        sqlx::query_as::<_, Reminder>(
+            r#"
+            INSERT INTO
+            reminders(id, user_id, todo_id, remind_at, minutes_before_due, delivered_at, created_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7)
+            RETURNING
+            id, user_id, todo_id, remind_at, minutes_before_due, delivered_at, created_at
+            "#,
+        )
+        .bind(reminder.id)
+        .bind(reminder.user_id)
+        .bind(reminder.todo_id)
+        .bind(reminder.remind_at)
+        .bind(reminder.minutes_before_due)
+        .bind(reminder.delivered_at)
+        .bind(reminder.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("reminder_repository.create : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM reminders WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("reminder_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Reminder>, ModelError> {
+        sqlx::query_as::<_, Reminder>(
+            r#"
+            SELECT
+            id, user_id, todo_id, remind_at, minutes_before_due, delivered_at, created_at
+            FROM reminders WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("reminder_repository.find_by_id : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<Reminder>, ModelError> {
+        sqlx::query_as::<_, Reminder>(
+            r#"
+            SELECT
+            id, user_id, todo_id, remind_at, minutes_before_due, delivered_at, created_at
+            FROM reminders WHERE user_id = $1 AND todo_id = $2
+            ORDER BY created_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("reminder_repository.find_by_todo : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn fire_due(&self, limit: i64) -> Result<Vec<Notification>, ModelError> {
+        // claiming, marking and inserting happen in one statement, rows locked by another
+        // instance are skipped so a reminder can never turn into two notifications
+        sqlx::query_as::<_, Notification>(
+            r#"
+            WITH due AS (
+                SELECT r.id
+                FROM reminders r
+                JOIN todos t ON t.id = r.todo_id
+                WHERE r.delivered_at IS NULL
+                AND t.is_completed = FALSE
+                AND (t.snoozed_until IS NULL OR t.snoozed_until <= now())
+                AND COALESCE(r.remind_at, t.due_at - make_interval(mins => r.minutes_before_due)) <= now()
+                ORDER BY r.created_at
+                LIMIT $1
+                FOR UPDATE OF r SKIP LOCKED
+            ), fired AS (
+                UPDATE reminders r
+                SET delivered_at = now()
+                FROM due WHERE r.id = due.id
+                RETURNING r.id, r.user_id, r.todo_id
+            )
+            INSERT INTO
+            notifications(id, user_id, todo_id, reminder_id, title, message, created_at)
+            SELECT
+            uuid_generate_v4(), f.user_id, f.todo_id, f.id, t.title,
+            CASE
+                WHEN t.due_at IS NULL THEN 'Reminder'
+                ELSE 'Due at ' || to_char(t.due_at AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI "UTC"')
+            END,
+            now()
+            FROM fired f JOIN todos t ON t.id = f.todo_id
+            RETURNING
+            id, user_id, todo_id, reminder_id, title, message, read_at, created_at
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("reminder_repository.fire_due : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
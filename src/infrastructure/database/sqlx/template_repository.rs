@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    template::{model::Template, repository::TemplateRepository},
+};
+
+pub struct PostgresTemplateRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TemplateRepository for PostgresTemplateRepository {
+    async fn create(&self, template: Template) -> Result<Template, ModelError> {
+        let created = sqlx::query_as::<_, Template>(
+            r#"
+            INSERT INTO
+            templates(id, user_id, name, title, description, tags, priority, due_offset_minutes, checklist, created_at, updated_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11)
+            RETURNING
+            id, user_id, name, title, description, tags, priority, due_offset_minutes, checklist, created_at, updated_at
+            "#,
+        )
+        .bind(template.id)
+        .bind(template.user_id)
+        .bind(template.name)
+        .bind(template.title)
+        .bind(template.description)
+        .bind(template.tags)
+        .bind(template.priority)
+        .bind(template.due_offset_minutes)
+        .bind(template.checklist)
+        .bind(template.created_at)
+        .bind(template.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("template_repository.create : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(created)
+    }
+
+    async fn update(&self, template: Template) -> Result<Template, ModelError> {
+        let updated = sqlx::query_as::<_, Template>(
+            r#"
+            UPDATE templates
+            SET
+            name=$1, title=$2, description=$3, tags=$4, priority=$5, due_offset_minutes=$6, checklist=$7, updated_at=$8
+            WHERE id=$9 AND user_id=$10
+            RETURNING
+            id, user_id, name, title, description, tags, priority, due_offset_minutes, checklist, created_at, updated_at
+            "#,
+        )
+        .bind(template.name)
+        .bind(template.title)
+        .bind(template.description)
+        .bind(template.tags)
+        .bind(template.priority)
+        .bind(template.due_offset_minutes)
+        .bind(template.checklist)
+        .bind(template.updated_at)
+        .bind(template.id)
+        .bind(template.user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("template_repository.update : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        updated.ok_or(ModelError::NotFound)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM templates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("template_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Template>, ModelError> {
+        let results = sqlx::query_as::<_, Template>(
+            r#"
+            SELECT
+            id, user_id, name, title, description, tags, priority, due_offset_minutes, checklist, created_at, updated_at
+            FROM templates WHERE user_id=$1 ORDER BY name
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("template_repository.find_all : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        Ok(results)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Template>, ModelError> {
+        sqlx::query_as::<_, Template>(
+            r#"
+            SELECT
+            id, user_id, name, title, description, tags, priority, due_offset_minutes, checklist, created_at, updated_at
+            FROM templates WHERE id = $1 AND user_id=$2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("template_repository.find_by_id : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    time_entry::{model::TimeEntry, repository::TimeEntryRepository},
+};
+
+pub struct PostgresTimeEntryRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresTimeEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_write_error(scope: &str, err: sqlx::Error) -> ModelError {
+    tracing::error!("time_entry_repository.{} : {}", scope, err.to_string());
+    if let Some(db_err) = err.as_database_error()
+        && db_err.code().as_deref() == Some("23505")
+    {
+        return ModelError::Conflict;
+    }
+
+    ModelError::Database(err.to_string())
+}
+
+#[async_trait]
+impl TimeEntryRepository for PostgresTimeEntryRepository {
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            INSERT INTO
+            time_entries(id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+            RETURNING
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            "#,
+        )
+        .bind(entry.id)
+        .bind(entry.user_id)
+        .bind(entry.todo_id)
+        .bind(entry.started_at)
+        .bind(entry.ended_at)
+        .bind(entry.note)
+        .bind(entry.created_at)
+        .bind(entry.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| map_write_error("create", err))
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            UPDATE time_entries
+            SET
+            started_at=$1, ended_at=$2, note=$3, updated_at=$4
+            WHERE id=$5 AND user_id=$6
+            RETURNING
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            "#,
+        )
+        .bind(entry.started_at)
+        .bind(entry.ended_at)
+        .bind(entry.note)
+        .bind(entry.updated_at)
+        .bind(entry.id)
+        .bind(entry.user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_write_error("update", err))?
+        .ok_or(ModelError::NotFound)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM time_entries WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("time_entry_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            SELECT
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            FROM time_entries WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("time_entry_repository.find_by_id : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            SELECT
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            FROM time_entries WHERE user_id = $1 AND ended_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("time_entry_repository.find_running : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_by_todo(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            SELECT
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            FROM time_entries WHERE user_id = $1 AND todo_id = $2
+            ORDER BY started_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("time_entry_repository.find_by_todo : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_between(
+        &self,
+        user_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeEntry>, ModelError> {
+        sqlx::query_as::<_, TimeEntry>(
+            r#"
+            SELECT
+            id, user_id, todo_id, started_at, ended_at, note, created_at, updated_at
+            FROM time_entries
+            WHERE user_id = $1 AND started_at < $3 AND (ended_at IS NULL OR ended_at > $2)
+            ORDER BY started_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("time_entry_repository.find_between : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -1,19 +1,40 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres};
 use uuid::Uuid;
 
-use crate::domain::{
-    shared::error::ModelError,
-    todo::{model::Todo, repository::TodoRepository},
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        todo::{model::Todo, repository::TodoRepository},
+    },
+    infrastructure::database::{
+        executor::{Executor, SharedTransaction},
+        replica::Replicas,
+    },
 };
 
 pub struct PostgresTodoRepository {
-    pub pool: PgPool,
+    executor: Executor<Postgres>,
 }
 
 impl PostgresTodoRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn replicated(pool: PgPool, replicas: Replicas<Postgres>) -> Self {
+        Self {
+            executor: Executor::Replicated(pool, replicas),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Postgres>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
     }
 }
 
@@ -23,10 +44,14 @@ impl TodoRepository for PostgresTodoRepository {
         let created = sqlx::query_as::<_, Todo>(
             r#"
             INSERT INTO 
-            todos(id, user_id, title, description, is_completed, created_at, updated_at) 
-            VALUES ($1,$2,$3,$4,$5,$6,$7)
+            todos(id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, created_at, updated_at) 
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12)
             RETURNING
-            id, user_id, title, description, is_completed, created_at, updated_at
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
             "#,
         )
         .bind(todo.id)
@@ -34,12 +59,23 @@ impl TodoRepository for PostgresTodoRepository {
         .bind(todo.title.as_str())
         .bind(todo.description.as_str())
         .bind(todo.is_completed)
+        .bind(todo.state_id)
+        .bind(todo.priority)
+        .bind(&todo.tags)
+        .bind(&todo.project)
+        .bind(todo.due_at)
         .bind(todo.created_at)
         .bind(todo.updated_at)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
-            tracing::error!("todo_repository.find_all : {}", err.to_string());
+            tracing::error!("todo_repository.create : {}", err.to_string());
+            if let Some(db_err) = err.as_database_error()
+                && db_err.code().as_deref() == Some("23505")
+            {
+                return ModelError::Conflict;
+            }
+
             ModelError::Database(err.to_string())
         })?;
 
@@ -49,31 +85,42 @@ impl TodoRepository for PostgresTodoRepository {
     async fn update(&self, todo: Todo) -> Result<Todo, ModelError> {
         let updated = sqlx::query_as::<_, Todo>(
             r#"
-            UPDATEtodos
+            UPDATE todos
             SET 
-            title=$1, description=$2, updated_at=$3 WHERE id=$4",
+            title=$1, description=$2, priority=$3, tags=$4, project=$5, due_at=$6, updated_at=$7
+            WHERE id=$8 AND user_id=$9
             RETURNING
-            id, user_id, title, description, is_completed, created_at, updated_at
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
             "#,
         )
         .bind(todo.title)
         .bind(todo.description)
+        .bind(todo.priority)
+        .bind(todo.tags)
+        .bind(todo.project)
+        .bind(todo.due_at)
         .bind(todo.updated_at)
         .bind(todo.id)
-        .fetch_one(&self.pool)
+        .bind(todo.user_id)
+        .fetch_optional(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
-            tracing::error!("todo_repository.find_all : {}", err.to_string());
+            tracing::error!("todo_repository.update : {}", err.to_string());
             ModelError::Database(err.to_string())
         })?;
 
-        Ok(updated)
+        updated.ok_or(ModelError::NotFound)
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
-        let rows = sqlx::query("DELETE FROM todos WHERE id = $1")
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM todos WHERE id = $1 AND user_id = $2")
             .bind(id)
-            .execute(&self.pool)
+            .bind(user_id)
+            .execute(&mut *self.executor.acquire().await?)
             .await
             .map_err(|err| {
                 tracing::error!("todo_repository.delete : {}", err.to_string());
@@ -89,12 +136,17 @@ impl TodoRepository for PostgresTodoRepository {
     }
 
     async fn toggle(&self, user_id: Uuid, id: Uuid) -> Result<(), ModelError> {
+        // a toggled todo leaves its workflow state and falls back to the default one of its category
         let rows = sqlx::query(
-            "UPDATE todos SET is_completed = NOT is_completed WHERE user_id=$1 AND id = $2",
+            r#"
+            UPDATE todos
+            SET is_completed = NOT is_completed, state_id = NULL, updated_at = now()
+            WHERE user_id=$1 AND id = $2
+            "#,
         )
         .bind(user_id)
         .bind(id)
-        .execute(&self.pool)
+        .execute(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
             tracing::error!("todo_repository.toggle : {}", err.to_string());
@@ -109,12 +161,54 @@ impl TodoRepository for PostgresTodoRepository {
         Ok(())
     }
 
+    async fn snooze(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Todo, ModelError> {
+        let snoozed = sqlx::query_as::<_, Todo>(
+            r#"
+            UPDATE todos
+            SET snoozed_until=$1, updated_at=now()
+            WHERE id=$2 AND user_id=$3
+            RETURNING
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
+            "#,
+        )
+        .bind(until)
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!("todo_repository.snooze : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?;
+
+        snoozed.ok_or(ModelError::NotFound)
+    }
+
+    // snoozed todos stay hidden from the listing until their snooze time has passed
     async fn find_all(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
         let results = sqlx::query_as::<_, Todo>(
-            "SELECT id, user_id, title, description, is_completed, created_at, updated_at FROM todos WHERE user_id=$1",
+            r#"
+            SELECT
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
+            FROM todos
+            WHERE user_id=$1 AND (snoozed_until IS NULL OR snoozed_until <= now())
+            "#,
         )
         .bind(user_id)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.executor.acquire_read().await?)
         .await
         .map_err(|err| {
             tracing::error!("todo_repository.find_all : {}", err.to_string());
@@ -124,13 +218,46 @@ impl TodoRepository for PostgresTodoRepository {
         Ok(results)
     }
 
+    async fn find_all_including_snoozed(&self, user_id: Uuid) -> Result<Vec<Todo>, ModelError> {
+        sqlx::query_as::<_, Todo>(
+            r#"
+            SELECT
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
+            FROM todos
+            WHERE user_id=$1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *self.executor.acquire_read().await?)
+        .await
+        .map_err(|err| {
+            tracing::error!(
+                "todo_repository.find_all_including_snoozed : {}",
+                err.to_string()
+            );
+            ModelError::Database(err.to_string())
+        })
+    }
+
     async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ModelError> {
         let result = sqlx::query_as::<_, Todo>(
-            "SELECT id, title, description, is_completed, created_at, updated_at FROM todos WHERE id = $1 AND user_id=$2",
+            r#"
+            SELECT
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
+            FROM todos WHERE id = $1 AND user_id=$2
+            "#,
         )
         .bind(id)
         .bind(user_id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.executor.acquire_read().await?)
         .await
         .map_err(|err| {
             tracing::error!("todo_repository.find_by_id : {}", err.to_string());
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::{
+    domain::shared::{
+        error::ModelError,
+        transaction::{TransactionManager, UnitOfWork},
+    },
+    infrastructure::database::{
+        executor::{self, SqlUnitOfWork},
+        sqlx::{
+            event_repository::PostgresEventRepository, todo_repository::PostgresTodoRepository,
+            user_repository::PostgresUserRepository,
+            workflow_repository::PostgresWorkflowRepository,
+        },
+    },
+};
+
+pub struct PostgresTransactionManager {
+    pub pool: PgPool,
+}
+
+impl PostgresTransactionManager {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TransactionManager for PostgresTransactionManager {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ModelError> {
+        let transaction = executor::begin(&self.pool, None).await?;
+
+        Ok(Box::new(SqlUnitOfWork {
+            todos: Box::new(PostgresTodoRepository::transactional(transaction.clone())),
+            users: Box::new(PostgresUserRepository::transactional(transaction.clone())),
+            events: Box::new(PostgresEventRepository::transactional(transaction.clone())),
+            workflows: Some(Box::new(PostgresWorkflowRepository::transactional(
+                transaction.clone(),
+            ))),
+            transaction,
+        }))
+    }
+}
@@ -1,19 +1,41 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres};
 use uuid::Uuid;
 
-use crate::domain::{
-    shared::error::ModelError,
-    user::{model::User, repository::UserRepository},
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        user::{model::User, repository::UserRepository},
+    },
+    infrastructure::database::{
+        executor::{Executor, SharedTransaction},
+        replica::Replicas,
+    },
 };
 
 pub struct PostgresUserRepository {
-    pub pool: PgPool,
+    executor: Executor<Postgres>,
 }
 
 impl PostgresUserRepository {
+    // the app always goes through `replicated`, the contract suite runs on the primary alone
+    #[cfg(test)]
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn replicated(pool: PgPool, replicas: Replicas<Postgres>) -> Self {
+        Self {
+            executor: Executor::Replicated(pool, replicas),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Postgres>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
     }
 }
 
@@ -22,19 +44,20 @@ impl UserRepository for PostgresUserRepository {
     async fn create(&self, user: User) -> Result<User, ModelError> {
         let saved = sqlx::query_as::<_, User>(
             r#"
-            INSERT INTO users (id, name, email, password, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO users (id, name, email, password, email_verified_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             RETURNING 
-            id, name, email, password, token, created_at, updated_at
+            id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at
             "#,
         )
         .bind(user.id)
         .bind(user.name)
         .bind(user.email)
         .bind(user.password)
+        .bind(user.email_verified_at)
         .bind(user.created_at)
         .bind(user.updated_at)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.create : {}", err.to_string());
@@ -54,23 +77,35 @@ impl UserRepository for PostgresUserRepository {
         let updated = sqlx::query_as::<_, User>(
             r#"
             UPDATE users 
-            SET name=$1, email=$2, password=$3, token=$4, updated_at=$5 
-            WHERE id =$6
+            SET name=$1, email=$2, password=$3, token=$4, email_verified_at=$5,
+            password_changed_at=$6, updated_at=$7
+            WHERE id =$8
             RETURNING 
-            id, name, email, password, token, created_at, updated_at
+            id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at
             "#,
         )
         .bind(&user.name)
         .bind(&user.email)
         .bind(&user.password)
         .bind(&user.token)
+        .bind(user.email_verified_at)
+        .bind(user.password_changed_at)
         .bind(user.updated_at)
         .bind(user.id)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.update : {}", err.to_string());
-            ModelError::Database(err.to_string())
+            if let Some(db_err) = err.as_database_error()
+                && db_err.code().as_deref() == Some("23505")
+            {
+                return ModelError::Conflict;
+            }
+
+            match err {
+                sqlx::Error::RowNotFound => ModelError::NotFound,
+                err => ModelError::Database(err.to_string()),
+            }
         })?;
 
         Ok(updated)
@@ -79,7 +114,7 @@ impl UserRepository for PostgresUserRepository {
     async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
         let rows = sqlx::query("DELETE FROM users WHERE id = $1")
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *self.executor.acquire().await?)
             .await
             .map_err(|err| {
                 tracing::error!("user_repository.delete : {}", err.to_string());
@@ -96,9 +131,9 @@ impl UserRepository for PostgresUserRepository {
 
     async fn find_all(&self) -> Result<Vec<User>, ModelError> {
         let results = sqlx::query_as::<_, User>(
-            "SELECT id, name, email, password, token, created_at, updated_at FROM users",
+            "SELECT id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at FROM users",
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.executor.acquire_read().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.find_all : {}", err.to_string());
@@ -110,10 +145,10 @@ impl UserRepository for PostgresUserRepository {
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, ModelError> {
         let result = sqlx::query_as::<_, User>(
-            "SELECT id, name, email, password, token, created_at, updated_at FROM users WHERE id = $1",
+            "SELECT id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at FROM users WHERE id = $1",
         )
         .bind(id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.executor.acquire_read().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.find_by_id : {}", err.to_string());
@@ -128,10 +163,10 @@ impl UserRepository for PostgresUserRepository {
 
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, ModelError> {
         let result = sqlx::query_as::<_, User>(
-            "SELECT id, name, email, password, token, created_at, updated_at FROM users WHERE email = $1",
+            "SELECT id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at FROM users WHERE email = $1",
         )
         .bind(email)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.executor.acquire_read().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.find_by_email : {}", err.to_string());
@@ -146,10 +181,10 @@ impl UserRepository for PostgresUserRepository {
 
     async fn find_by_token(&self, token: &str) -> Result<Option<User>, ModelError> {
         let result = sqlx::query_as::<_, User>(
-            "SELECT id, name, email, password, token, created_at, updated_at FROM users WHERE token = $1",
+            "SELECT id, name, email, password, token, email_verified_at, password_changed_at, created_at, updated_at FROM users WHERE token = $1",
         )
         .bind(token)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.executor.acquire().await?)
         .await
         .map_err(|err| {
             tracing::error!("user_repository.find_by_token : {}", err.to_string());
@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::error::ModelError,
+    webhook::{
+        model::{DeliveryJob, Webhook, WebhookDelivery},
+        repository::WebhookRepository,
+    },
+};
+
+pub struct PostgresWebhookRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create(&self, webhook: Webhook) -> Result<Webhook, ModelError> {
+        sqlx::query_as::<_, Webhook>(
+            r#"
+            INSERT INTO
+            webhooks(id, user_id, url, secret, events, active, created_at, updated_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+            RETURNING
+            id, user_id, url, secret, events, active, created_at, updated_at
+            "#,
+        )
+        .bind(webhook.id)
+        .bind(webhook.user_id)
+        .bind(webhook.url)
+        .bind(webhook.secret)
+        .bind(webhook.events)
+        .bind(webhook.active)
+        .bind(webhook.created_at)
+        .bind(webhook.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.create : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn update(&self, webhook: Webhook) -> Result<Webhook, ModelError> {
+        sqlx::query_as::<_, Webhook>(
+            r#"
+            UPDATE webhooks
+            SET
+            url=$1, events=$2, active=$3, updated_at=$4
+            WHERE id=$5 AND user_id=$6
+            RETURNING
+            id, user_id, url, secret, events, active, created_at, updated_at
+            "#,
+        )
+        .bind(webhook.url)
+        .bind(webhook.events)
+        .bind(webhook.active)
+        .bind(webhook.updated_at)
+        .bind(webhook.id)
+        .bind(webhook.user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.update : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?
+        .ok_or(ModelError::NotFound)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                tracing::error!("webhook_repository.delete : {}", err.to_string());
+                ModelError::Database(err.to_string())
+            })?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<Webhook>, ModelError> {
+        sqlx::query_as::<_, Webhook>(
+            r#"
+            SELECT
+            id, user_id, url, secret, events, active, created_at, updated_at
+            FROM webhooks WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.find_all : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Webhook>, ModelError> {
+        sqlx::query_as::<_, Webhook>(
+            r#"
+            SELECT
+            id, user_id, url, secret, events, active, created_at, updated_at
+            FROM webhooks WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.find_by_id : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn create_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ModelError> {
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            INSERT INTO
+            webhook_deliveries(
+                id, webhook_id, user_id, event_id, event, payload, status, attempts,
+                next_attempt_at, response_code, last_error, delivered_at, created_at, updated_at
+            )
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14)
+            RETURNING
+            id, webhook_id, user_id, event_id, event, payload, status, attempts,
+            next_attempt_at, response_code, last_error, delivered_at, created_at, updated_at
+            "#,
+        )
+        .bind(delivery.id)
+        .bind(delivery.webhook_id)
+        .bind(delivery.user_id)
+        .bind(delivery.event_id)
+        .bind(delivery.event)
+        .bind(delivery.payload)
+        .bind(delivery.status)
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.response_code)
+        .bind(delivery.last_error)
+        .bind(delivery.delivered_at)
+        .bind(delivery.created_at)
+        .bind(delivery.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.create_delivery : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn update_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ModelError> {
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            UPDATE webhook_deliveries
+            SET
+            status=$1, attempts=$2, next_attempt_at=$3, response_code=$4, last_error=$5,
+            delivered_at=$6, updated_at=$7
+            WHERE id=$8
+            RETURNING
+            id, webhook_id, user_id, event_id, event, payload, status, attempts,
+            next_attempt_at, response_code, last_error, delivered_at, created_at, updated_at
+            "#,
+        )
+        .bind(delivery.status)
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.response_code)
+        .bind(delivery.last_error)
+        .bind(delivery.delivered_at)
+        .bind(delivery.updated_at)
+        .bind(delivery.id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.update_delivery : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })?
+        .ok_or(ModelError::NotFound)
+    }
+
+    async fn find_deliveries(
+        &self,
+        user_id: Uuid,
+        webhook_id: Uuid,
+    ) -> Result<Vec<WebhookDelivery>, ModelError> {
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT
+            id, webhook_id, user_id, event_id, event, payload, status, attempts,
+            next_attempt_at, response_code, last_error, delivered_at, created_at, updated_at
+            FROM webhook_deliveries WHERE user_id = $1 AND webhook_id = $2
+            ORDER BY created_at DESC
+            LIMIT 100
+            "#,
+        )
+        .bind(user_id)
+        .bind(webhook_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.find_deliveries : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn find_delivery(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, ModelError> {
+        sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT
+            id, webhook_id, user_id, event_id, event, payload, status, attempts,
+            next_attempt_at, response_code, last_error, delivered_at, created_at, updated_at
+            FROM webhook_deliveries WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.find_delivery : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+
+    async fn claim_deliveries(&self, limit: i64) -> Result<Vec<DeliveryJob>, ModelError> {
+        // pushing next_attempt_at forward is the lease, other instances skip the locked rows
+        // and will not see them again until the lease runs out
+        sqlx::query_as::<_, DeliveryJob>(
+            r#"
+            WITH due AS (
+                SELECT d.id
+                FROM webhook_deliveries d
+                WHERE d.status = 'pending' AND d.next_attempt_at <= now()
+                ORDER BY d.next_attempt_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            ), claimed AS (
+                UPDATE webhook_deliveries d
+                SET next_attempt_at = now() + interval '2 minutes'
+                FROM due WHERE d.id = due.id
+                RETURNING d.*
+            )
+            SELECT
+            c.id, c.webhook_id, c.user_id, c.event_id, c.event, c.payload, c.status, c.attempts,
+            c.next_attempt_at, c.response_code, c.last_error, c.delivered_at, c.created_at,
+            c.updated_at, w.url, w.secret
+            FROM claimed c JOIN webhooks w ON w.id = c.webhook_id
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| {
+            tracing::error!("webhook_repository.claim_deliveries : {}", err.to_string());
+            ModelError::Database(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        shared::error::ModelError,
+        todo::model::Todo,
+        workflow::{
+            model::{StateTransition, WorkflowState},
+            repository::WorkflowRepository,
+        },
+    },
+    infrastructure::database::executor::{Executor, SharedTransaction},
+};
+
+pub struct PostgresWorkflowRepository {
+    executor: Executor<Postgres>,
+}
+
+impl PostgresWorkflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: Executor::Pool(pool),
+        }
+    }
+
+    pub fn transactional(transaction: SharedTransaction<Postgres>) -> Self {
+        Self {
+            executor: Executor::Transaction(transaction),
+        }
+    }
+}
+
+fn map_error(scope: &str, err: sqlx::Error) -> ModelError {
+    tracing::error!("workflow_repository.{} : {}", scope, err.to_string());
+    if let Some(db_err) = err.as_database_error()
+        && db_err.code().as_deref() == Some("23505")
+    {
+        return ModelError::Conflict;
+    }
+
+    ModelError::Database(err.to_string())
+}
+
+#[async_trait]
+impl WorkflowRepository for PostgresWorkflowRepository {
+    async fn create_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError> {
+        sqlx::query_as::<_, WorkflowState>(
+            r#"
+            INSERT INTO
+            workflow_states(id, user_id, name, position, category, created_at, updated_at)
+            VALUES ($1,$2,$3,$4,$5,$6,$7)
+            RETURNING
+            id, user_id, name, position, category, created_at, updated_at
+            "#,
+        )
+        .bind(state.id)
+        .bind(state.user_id)
+        .bind(state.name)
+        .bind(state.position)
+        .bind(state.category)
+        .bind(state.created_at)
+        .bind(state.updated_at)
+        .fetch_one(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("create_state", err))
+    }
+
+    async fn update_state(&self, state: WorkflowState) -> Result<WorkflowState, ModelError> {
+        sqlx::query_as::<_, WorkflowState>(
+            r#"
+            UPDATE workflow_states
+            SET name=$1, position=$2, category=$3, updated_at=$4
+            WHERE id=$5 AND user_id=$6
+            RETURNING
+            id, user_id, name, position, category, created_at, updated_at
+            "#,
+        )
+        .bind(state.name)
+        .bind(state.position)
+        .bind(state.category)
+        .bind(state.updated_at)
+        .bind(state.id)
+        .bind(state.user_id)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("update_state", err))?
+        .ok_or(ModelError::NotFound)
+    }
+
+    async fn align_todos(&self, state: &WorkflowState) -> Result<Vec<Todo>, ModelError> {
+        sqlx::query_as::<_, Todo>(
+            r#"
+            UPDATE todos
+            SET is_completed = $1, updated_at = now()
+            WHERE user_id = $2 AND state_id = $3 AND is_completed <> $1
+            RETURNING
+            id, user_id, title, description, is_completed, state_id, priority, tags, project, due_at, snoozed_until, created_at, updated_at,
+            COALESCE((
+                SELECT SUM(EXTRACT(EPOCH FROM COALESCE(te.ended_at, now()) - te.started_at))::BIGINT
+                FROM time_entries te WHERE te.todo_id = todos.id
+            ), 0) AS tracked_seconds
+            "#,
+        )
+        .bind(state.category.is_completed())
+        .bind(state.user_id)
+        .bind(state.id)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("align_todos", err))
+    }
+
+    async fn delete_state(&self, id: Uuid) -> Result<(), ModelError> {
+        let rows = sqlx::query("DELETE FROM workflow_states WHERE id = $1")
+            .bind(id)
+            .execute(&mut *self.executor.acquire().await?)
+            .await
+            .map_err(|err| map_error("delete_state", err))?
+            .rows_affected();
+
+        if rows == 0 {
+            return Err(ModelError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_states(&self, user_id: Uuid) -> Result<Vec<WorkflowState>, ModelError> {
+        sqlx::query_as::<_, WorkflowState>(
+            r#"
+            SELECT
+            id, user_id, name, position, category, created_at, updated_at
+            FROM workflow_states WHERE user_id = $1
+            ORDER BY position, created_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("find_states", err))
+    }
+
+    async fn find_state(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<WorkflowState>, ModelError> {
+        sqlx::query_as::<_, WorkflowState>(
+            r#"
+            SELECT
+            id, user_id, name, position, category, created_at, updated_at
+            FROM workflow_states WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("find_state", err))
+    }
+
+    async fn transition(
+        &self,
+        transition: StateTransition,
+        is_completed: bool,
+    ) -> Result<StateTransition, ModelError> {
+        // the todo is moved and the transition recorded in one statement, nothing is recorded
+        // for a todo of someone else
+        sqlx::query_as::<_, StateTransition>(
+            r#"
+            WITH moved AS (
+                UPDATE todos
+                SET state_id = $5, is_completed = $7, updated_at = $6
+                WHERE id = $3 AND user_id = $2
+                RETURNING id
+            )
+            INSERT INTO
+            state_transitions(id, user_id, todo_id, from_state_id, to_state_id, transitioned_at)
+            SELECT $1,$2,moved.id,$4,$5,$6 FROM moved
+            RETURNING
+            id, user_id, todo_id, from_state_id, to_state_id, transitioned_at
+            "#,
+        )
+        .bind(transition.id)
+        .bind(transition.user_id)
+        .bind(transition.todo_id)
+        .bind(transition.from_state_id)
+        .bind(transition.to_state_id)
+        .bind(transition.transitioned_at)
+        .bind(is_completed)
+        .fetch_optional(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("transition", err))?
+        .ok_or(ModelError::NotFound)
+    }
+
+    async fn find_transitions(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+    ) -> Result<Vec<StateTransition>, ModelError> {
+        sqlx::query_as::<_, StateTransition>(
+            r#"
+            SELECT
+            id, user_id, todo_id, from_state_id, to_state_id, transitioned_at
+            FROM state_transitions WHERE user_id = $1 AND todo_id = $2
+            ORDER BY transitioned_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(todo_id)
+        .fetch_all(&mut *self.executor.acquire().await?)
+        .await
+        .map_err(|err| map_error("find_transitions", err))
+    }
+}
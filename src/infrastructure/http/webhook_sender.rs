@@ -0,0 +1,142 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use reqwest::{
+    Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect,
+};
+
+use crate::domain::webhook::sender::{WebhookRequest, WebhookSender};
+
+pub struct ReqwestWebhookSender {
+    pub client: reqwest::Client,
+}
+
+impl ReqwestWebhookSender {
+    pub fn new() -> Self {
+        // redirects and proxies would let a delivery reach an address the resolver never saw
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("todo-rs/", env!("CARGO_PKG_VERSION")))
+            .redirect(redirect::Policy::none())
+            .no_proxy()
+            .dns_resolver(Arc::new(PublicResolver))
+            .build()
+            .unwrap_or_default();
+
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for ReqwestWebhookSender {
+    async fn send(&self, request: WebhookRequest) -> Result<u16, String> {
+        // ip literals never go through the resolver, so the url is checked again before sending
+        let url = check_url(&request.url).inspect_err(|err| {
+            tracing::warn!("webhook_sender.send {} : {}", request.url, err);
+        })?;
+
+        let mut builder = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+
+        builder
+            .body(request.body)
+            .send()
+            .await
+            .map(|response| response.status().as_u16())
+            .map_err(|err| {
+                tracing::warn!("webhook_sender.send {} : {}", request.url, err.to_string());
+                err.to_string()
+            })
+    }
+}
+
+impl Default for ReqwestWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// resolves hosts at send time and only hands public addresses to the connector
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect::<Vec<SocketAddr>>();
+
+            if addrs.is_empty() {
+                return Err(
+                    format!("{} does not resolve to a public address", name.as_str()).into(),
+                );
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+// webhook urls must be http(s) and must not point at the host itself or a private network
+pub fn check_url(url: &str) -> Result<Url, String> {
+    let url = Url::parse(url).map_err(|err| err.to_string())?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("only http and https urls are allowed".to_string());
+    }
+
+    let host = url.host_str().ok_or("url has no host")?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        if !is_public(ip) {
+            return Err(format!("{ip} is not a public address"));
+        }
+    } else if host == "localhost" || host.ends_with(".localhost") {
+        return Err(format!("{host} is not a public address"));
+    }
+
+    Ok(url)
+}
+
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || a == 0
+                // carrier-grade nat, 100.64.0.0/10
+                || (a == 100 && b & 0xc0 == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(IpAddr::V4(ip)),
+            None => {
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
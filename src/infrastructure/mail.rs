@@ -0,0 +1,20 @@
+use lettre::{Message, message::Mailbox};
+
+use crate::domain::mail::mailer::Mail;
+
+pub mod file_mailer;
+pub mod smtp_mailer;
+
+fn message(from: &Mailbox, mail: Mail) -> Result<Message, String> {
+    let to: Mailbox = mail
+        .to
+        .parse()
+        .map_err(|err| format!("invalid recipient {} : {}", mail.to, err))?;
+
+    Message::builder()
+        .from(from.clone())
+        .to(to)
+        .subject(mail.subject)
+        .body(mail.body)
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor, message::Mailbox};
+
+use crate::{
+    domain::mail::mailer::{Mail, Mailer},
+    infrastructure::mail,
+};
+
+// writes every message as an `.eml` file into the outbox directory instead of sending it
+pub struct FileMailer {
+    pub transport: AsyncFileTransport<Tokio1Executor>,
+    pub from: Mailbox,
+}
+
+impl FileMailer {
+    pub fn new(outbox: PathBuf, from: Mailbox) -> Self {
+        Self {
+            transport: AsyncFileTransport::new(outbox),
+            from,
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, mail: Mail) -> Result<(), String> {
+        let message = mail::message(&self.from, mail)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|id| tracing::debug!("file_mailer.send wrote {}.eml", id))
+            .map_err(|err| {
+                tracing::warn!("file_mailer.send : {}", err.to_string());
+                err.to_string()
+            })
+    }
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor, message::Mailbox};
+
+use crate::{
+    domain::mail::mailer::{Mail, Mailer},
+    infrastructure::mail,
+};
+
+pub struct SmtpMailer {
+    pub transport: AsyncSmtpTransport<Tokio1Executor>,
+    pub from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(transport: AsyncSmtpTransport<Tokio1Executor>, from: Mailbox) -> Self {
+        Self { transport, from }
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<(), String> {
+        let message = mail::message(&self.from, mail)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                tracing::warn!("smtp_mailer.send : {}", err.to_string());
+                err.to_string()
+            })
+    }
+}
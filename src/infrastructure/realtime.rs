@@ -0,0 +1,109 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::{
+    PgPool,
+    postgres::{PgListener, PgPoolOptions},
+};
+use tokio::{sync::broadcast, task::JoinHandle};
+
+use crate::{
+    domain::event::{model::TodoEvent, repository::EventRepository},
+    infrastructure::database::sqlx::event_repository::PostgresEventRepository,
+};
+
+const CHANNEL: &str = "todo_events";
+const TAIL_BATCH: i64 = 500;
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// fan-out of todo events to the open connections of this instance
+#[derive(Clone)]
+pub struct EventHub {
+    sender: broadcast::Sender<Arc<TodoEvent>>,
+}
+
+impl EventHub {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<TodoEvent>> {
+        self.sender.subscribe()
+    }
+
+    pub fn publish(&self, event: TodoEvent) {
+        // nobody listening is not an error
+        let _ = self.sender.send(Arc::new(event));
+    }
+}
+
+// follow the event log written by every instance and publish it to the hub, NOTIFY wakes the
+// tail up right away and the poll interval covers notifications missed while reconnecting
+pub fn listen(pool: PgPool, hub: EventHub) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let events = PostgresEventRepository::new(pool.clone());
+
+        let mut last_id = loop {
+            match events.last_id().await {
+                Ok(id) => break id,
+                Err(_) => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        };
+
+        let mut listener: Option<PgListener> = None;
+
+        loop {
+            if listener.is_none() {
+                listener = subscribe(&pool).await;
+            }
+
+            match listener.as_mut() {
+                Some(active) => {
+                    if let Ok(Err(err)) = tokio::time::timeout(POLL_INTERVAL, active.recv()).await {
+                        tracing::error!("event listener lost its connection : {}", err);
+                        listener = None;
+                    }
+                }
+                None => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+
+            loop {
+                let batch = match events.find_after(last_id, TAIL_BATCH).await {
+                    Ok(batch) => batch,
+                    Err(_) => break,
+                };
+
+                let count = batch.len();
+                for event in batch {
+                    last_id = event.id;
+                    hub.publish(event);
+                }
+
+                if (count as i64) < TAIL_BATCH {
+                    break;
+                }
+            }
+        }
+    })
+}
+
+// the listener holds its connection for good, it gets its own so the pool is not left one short
+async fn subscribe(pool: &PgPool) -> Option<PgListener> {
+    let dedicated = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_lazy_with(pool.connect_options().as_ref().clone());
+
+    let mut listener = PgListener::connect_with(&dedicated)
+        .await
+        .inspect_err(|err| tracing::error!("failed to connect event listener : {}", err))
+        .ok()?;
+
+    listener
+        .listen(CHANNEL)
+        .await
+        .inspect_err(|err| tracing::error!("failed to listen on {} : {}", CHANNEL, err))
+        .ok()?;
+
+    Some(listener)
+}
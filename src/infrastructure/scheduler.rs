@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+
+use crate::{
+    application::{
+        idempotency::usecase::IdempotencyUseCase,
+        rate_limit::usecase::{RateLimitPolicy, RateLimitUseCase},
+        reminder::usecase::ReminderUseCase,
+        webhook::usecase::WebhookUseCase,
+    },
+    infrastructure::{
+        database::sqlx::{
+            idempotency_repository::PostgresIdempotencyRepository,
+            rate_limit_repository::PostgresRateLimitRepository,
+            reminder_repository::PostgresReminderRepository,
+            todo_repository::PostgresTodoRepository, webhook_repository::PostgresWebhookRepository,
+        },
+        http::webhook_sender::ReqwestWebhookSender,
+    },
+};
+
+const REMINDER_BATCH: i64 = 100;
+const WEBHOOK_BATCH: i64 = 50;
+
+// periodically turn due reminders into notifications, safe to run on every instance
+pub fn reminders(pool: PgPool, every: Duration) -> JoinHandle<()> {
+    let usecase = ReminderUseCase::new(
+        PostgresReminderRepository::new(pool.clone()),
+        PostgresTodoRepository::new(pool),
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match usecase.dispatch_due(REMINDER_BATCH).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("scheduler delivered {} reminders", count),
+                Err(err) => tracing::error!("scheduler failed to deliver reminders: {:?}", err),
+            }
+        }
+    })
+}
+
+// periodically send pending webhook deliveries, instances lease deliveries so each attempt is
+// made by one of them
+pub fn webhooks(pool: PgPool, every: Duration) -> JoinHandle<()> {
+    let usecase = WebhookUseCase::new(
+        PostgresWebhookRepository::new(pool),
+        ReqwestWebhookSender::new(),
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match usecase.dispatch_due(WEBHOOK_BATCH).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("scheduler attempted {} webhook deliveries", count),
+                Err(err) => tracing::error!("scheduler failed to send webhooks: {:?}", err),
+            }
+        }
+    })
+}
+
+// periodically drop idempotency keys whose replay window has passed
+pub fn idempotency_keys(pool: PgPool, every: Duration) -> JoinHandle<()> {
+    // the window only applies to new keys, purging reads the expiry stored with each key
+    let usecase = IdempotencyUseCase::new(PostgresIdempotencyRepository::new(pool), Duration::ZERO);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match usecase.purge_expired().await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("scheduler purged {} idempotency keys", count),
+                Err(err) => {
+                    tracing::error!("scheduler failed to purge idempotency keys: {:?}", err)
+                }
+            }
+        }
+    })
+}
+
+// periodically drop rate limit counters whose window has passed
+pub fn rate_limits(pool: PgPool, every: Duration) -> JoinHandle<()> {
+    // the policy only applies to new attempts, purging reads the expiry stored with each counter
+    let usecase = RateLimitUseCase::new(
+        PostgresRateLimitRepository::new(pool),
+        RateLimitPolicy {
+            per_ip: 0,
+            per_email: 0,
+            window: Duration::ZERO,
+        },
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match usecase.purge_expired().await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("scheduler purged {} rate limit counters", count),
+                Err(err) => {
+                    tracing::error!("scheduler failed to purge rate limit counters: {:?}", err)
+                }
+            }
+        }
+    })
+}
@@ -2,11 +2,18 @@ use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, errors::Error};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::{
+    domain::{shared::error::ModelError, user::repository::UserRepository},
+    infrastructure::database::replica,
+};
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct JwtClaims {
     pub sub: Uuid,
     pub exp: i64,
-    pub iat: i64,
+    // seconds with a fractional part, so tokens issued in the second the password changed are
+    // told apart by whether they came before or after it
+    pub iat: f64,
 }
 
 impl JwtClaims {
@@ -15,7 +22,7 @@ impl JwtClaims {
 
         Self {
             sub,
-            iat: now.timestamp(),
+            iat: seconds(now),
             exp: (now + dur).timestamp(),
         }
     }
@@ -36,4 +43,71 @@ impl JwtClaims {
         )
         .map(|op| op.claims)
     }
+
+    // deleting the user or changing the password revokes every access token issued before,
+    // read from the primary so a lagging replica can't bring a revoked token back
+    pub async fn is_revoked(&self, users: &dyn UserRepository) -> bool {
+        match replica::scope(true, users.find_by_id(self.sub)).await {
+            Ok(Some(user)) => user
+                .password_changed_at
+                .is_some_and(|changed_at| self.iat < seconds(changed_at)),
+            Ok(None) | Err(ModelError::NotFound) => true,
+            Err(err) => {
+                tracing::error!("failed check access token of {} : {}", self.sub, err);
+                true
+            }
+        }
+    }
+}
+
+fn seconds(at: chrono::DateTime<chrono::Utc>) -> f64 {
+    at.timestamp_micros() as f64 / 1_000_000.0
+}
+
+// proves control of `email`, the audience keeps it from passing as an access token and an access
+// token from passing as this
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct VerificationClaims {
+    pub sub: Uuid,
+    pub email: String,
+    pub aud: String,
+    pub exp: i64,
+    pub iat: i64,
+}
+
+impl VerificationClaims {
+    const AUDIENCE: &str = "email-verification";
+
+    pub fn new(sub: Uuid, email: String, dur: chrono::Duration) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            sub,
+            email,
+            aud: Self::AUDIENCE.to_string(),
+            iat: now.timestamp(),
+            exp: (now + dur).timestamp(),
+        }
+    }
+
+    pub fn encode(&self, secret: &str) -> Result<String, Error> {
+        jsonwebtoken::encode(
+            &Header::default(),
+            &self,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+    }
+
+    pub fn decode(token: &str, secret: &str) -> Result<Self, Error> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[Self::AUDIENCE]);
+        validation.set_required_spec_claims(&["exp", "aud"]);
+
+        jsonwebtoken::decode::<Self>(
+            token,
+            &DecodingKey::from_secret(secret.as_bytes()),
+            &validation,
+        )
+        .map(|op| op.claims)
+    }
 }
@@ -1,8 +1,29 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use clap::Parser;
+
 use crate::{
-    infrastructure::{bootstrap, config},
-    presentation::restapi::{self, RouterOption},
+    infrastructure::{
+        bootstrap,
+        cache::{self, RepositoryCache, broadcast::Broadcaster},
+        config::{self, AppConfig, DatabaseBackend},
+        database::Connection,
+        realtime::{self, EventHub},
+        scheduler,
+    },
+    presentation::{
+        cli::{
+            self,
+            command::{Cli, Command},
+        },
+        graphql,
+        restapi::{self, RouterOption},
+    },
 };
 
+#[cfg(feature = "grpc")]
+use crate::presentation::grpc;
+
 mod application;
 mod domain;
 mod infrastructure;
@@ -10,26 +31,99 @@ mod presentation;
 
 #[tokio::main]
 async fn main() {
+    let args = Cli::parse();
+
     // load app configuration
-    let conf = config::load().unwrap();
+    let mut conf = config::load().unwrap();
+
+    // dev mode runs without any database, whatever backend is configured
+    if args.dev {
+        conf.db_backend = DatabaseBackend::Memory;
+    }
 
     // setup logger for this application
     bootstrap::logger(&conf).unwrap();
 
-    // setup postgresql pool for this application
-    let pool = bootstrap::sqlx(&conf).await.unwrap();
+    // connect to the configured database backend
+    let connection = bootstrap::database(&conf).await.unwrap();
+
+    // operator commands run against the database and exit, serving is the default
+    match args.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(conf, connection).await,
+        command => {
+            if let Err(err) = cli::handler::run(command, &connection).await {
+                eprintln!("error: {}", err);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+async fn serve(conf: AppConfig, connection: Connection) {
+    // bring the schema up to date, a drifted migration stops the startup
+    bootstrap::migrate(&conf, &connection).await.unwrap();
+
+    let hub = EventHub::new(1024);
+    let pool = connection.postgres();
+
+    if let Some(pool) = pool {
+        // start background schedulers for due reminders, webhook deliveries, expired idempotency keys
+        // and rate limit counters
+        let _reminders = scheduler::reminders(pool.clone(), conf.scheduler_interval());
+        let _webhooks = scheduler::webhooks(pool.clone(), conf.webhook_interval());
+        let _idempotency = scheduler::idempotency_keys(pool.clone(), conf.scheduler_interval());
+        let _rate_limits = scheduler::rate_limits(pool.clone(), conf.scheduler_interval());
+
+        // follow the todo event log of every instance for realtime clients
+        let _events = realtime::listen(pool.clone(), hub.clone());
+    }
 
     // setup listener for this application
     let listener = bootstrap::listener(&conf).await.unwrap();
 
-    // setup main router
-    let app = restapi::setup(&RouterOption {
-        pool: &pool,
+    // setup main router, graphql is served next to the rest api on postgres
+    let mut repositories = connection.repositories();
+    let mut cache_store = None;
+    if conf.cache_enabled {
+        let mut store = RepositoryCache::new(conf.cache_capacity(), conf.cache_ttl());
+        let broadcaster = pool.map(|pool| Broadcaster::new(pool.clone()));
+        if let Some(broadcaster) = &broadcaster {
+            store = store.broadcast(broadcaster.clone());
+        }
+        let store = Arc::new(store);
+
+        // evict what other instances wrote, only postgres can be shared by several
+        if let Some(broadcaster) = broadcaster {
+            let _invalidations = cache::broadcast::listen(store.clone(), broadcaster, hub.clone());
+        }
+
+        repositories = cache::repositories(repositories, store.clone());
+        cache_store = Some(store);
+    }
+    let mailer = bootstrap::mailer(&conf).unwrap();
+
+    let opt = RouterOption {
+        pool,
+        repositories: &repositories,
         config: &conf,
-    });
+        hub: &hub,
+        mailer: &mailer,
+        cache: cache_store.as_ref(),
+    };
+    let mut app = restapi::setup(&opt);
+    if let Some(pool) = pool {
+        app = app.merge(graphql::router::setup(&opt, pool));
+    }
+
+    // internal services talk grpc on a separate port
+    #[cfg(feature = "grpc")]
+    let _grpc = grpc::server::serve(&opt);
 
     tracing::debug!("listen on {}", conf.server_addr());
 
+    // the client address is needed to rate limit the auth routes
+    let app = app.into_make_service_with_connect_info::<SocketAddr>();
+
     if let Err(err) = axum::serve(listener, app).await {
         tracing::error!("axum server error: {}", err);
         panic!("Server crashed");
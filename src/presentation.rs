@@ -1 +1,5 @@
+pub mod cli;
+pub mod graphql;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod restapi;
@@ -0,0 +1,85 @@
+use clap::{Parser, Subcommand};
+
+/// Todo API server and operator commands
+#[derive(Debug, Parser)]
+#[command(name = "todo-rs", version)]
+pub struct Cli {
+    /// Keep todos and users in memory instead of a database, nothing survives a restart
+    #[arg(long, global = true)]
+    pub dev: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the api server, the default when no command is given
+    Serve,
+
+    /// Apply pending database migrations
+    Migrate {
+        /// Only show the status of every migration, nothing is applied
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Manage user accounts
+    #[command(subcommand)]
+    User(UserCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UserCommand {
+    /// List every user
+    List {
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create a user that can log in right away
+    Create {
+        #[arg(long)]
+        name: String,
+
+        #[arg(long)]
+        email: String,
+
+        /// Prompted for when not given
+        #[arg(long)]
+        password: Option<String>,
+    },
+
+    /// Set a new password and sign the user out
+    ResetPassword {
+        #[arg(long)]
+        email: String,
+
+        /// Prompted for when not given
+        #[arg(long)]
+        password: Option<String>,
+    },
+
+    /// Revoke the refresh token so the user has to log in again
+    RevokeToken {
+        #[arg(long)]
+        email: String,
+    },
+
+    /// Lift a lock left by too many failed logins
+    Unlock {
+        #[arg(long)]
+        email: String,
+    },
+
+    /// Delete a user together with all of their data
+    Purge {
+        #[arg(long)]
+        email: String,
+
+        /// Confirm the purge, it can not be undone
+        #[arg(long)]
+        yes: bool,
+    },
+}
@@ -0,0 +1,153 @@
+use comfy_table::{Table, presets::UTF8_FULL_CONDENSED};
+use validator::Validate;
+
+use crate::{
+    application::admin::{
+        dto::{CreateUserRequest, ResetPasswordRequest},
+        error::AdminError,
+        usecase::AdminUseCase,
+    },
+    infrastructure::database::{
+        Connection,
+        migration::{self, MigrationStatus},
+    },
+    presentation::cli::command::{Command, UserCommand},
+};
+
+pub async fn run(command: Command, connection: &Connection) -> Result<(), String> {
+    match command {
+        // main serves the api itself
+        Command::Serve => Ok(()),
+        Command::Migrate { dry_run: true } => {
+            let plan = migration::plan(connection)
+                .await
+                .map_err(|err| format!("migration failed: {}", err))?;
+
+            for migration in plan {
+                let status = match migration.status {
+                    MigrationStatus::Applied => "applied",
+                    MigrationStatus::Pending => "pending",
+                    MigrationStatus::Drifted => "drifted, the file changed after it was applied",
+                    MigrationStatus::Missing => "missing, unknown to this binary",
+                };
+
+                println!(
+                    "{:04} {:<16} {}",
+                    migration.version, migration.description, status
+                );
+            }
+
+            Ok(())
+        }
+        Command::Migrate { dry_run: false } => {
+            let applied = migration::run(connection)
+                .await
+                .map_err(|err| format!("migration failed: {}", err))?;
+
+            if applied.is_empty() {
+                println!("database is up to date");
+            }
+
+            for migration in applied {
+                println!("applied {:04} {}", migration.version, migration.description);
+            }
+
+            Ok(())
+        }
+        Command::User(command) => user(command, connection).await,
+    }
+}
+
+async fn user(command: UserCommand, connection: &Connection) -> Result<(), String> {
+    let repositories = connection.repositories();
+    let usecase = AdminUseCase::new(
+        repositories.user,
+        repositories.transactions,
+        repositories.lockout,
+    );
+
+    match command {
+        UserCommand::List { json } => {
+            let users = usecase.list_users().await.map_err(message)?;
+
+            if json {
+                let users = serde_json::to_string_pretty(&users).map_err(|err| err.to_string())?;
+                println!("{}", users);
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_FULL_CONDENSED)
+                .set_header(vec!["ID", "NAME", "EMAIL"]);
+
+            for user in users {
+                table.add_row(vec![user.id.to_string(), user.name, user.email]);
+            }
+
+            println!("{table}");
+            Ok(())
+        }
+        UserCommand::Create {
+            name,
+            email,
+            password,
+        } => {
+            let dto = CreateUserRequest {
+                name,
+                email,
+                password: password_or_prompt(password)?,
+            };
+            dto.validate().map_err(|err| err.to_string())?;
+
+            let user = usecase.create_user(dto).await.map_err(message)?;
+            println!("created user {} ({})", user.email, user.id);
+            Ok(())
+        }
+        UserCommand::ResetPassword { email, password } => {
+            let dto = ResetPasswordRequest {
+                email,
+                password: password_or_prompt(password)?,
+            };
+            dto.validate().map_err(|err| err.to_string())?;
+
+            usecase.reset_password(dto).await.map_err(message)?;
+            println!("password changed, the user has to log in again");
+            Ok(())
+        }
+        UserCommand::RevokeToken { email } => {
+            usecase.revoke_token(&email).await.map_err(message)?;
+            println!("refresh token revoked for {}", email);
+            Ok(())
+        }
+        UserCommand::Unlock { email } => {
+            usecase.unlock_by_email(&email).await.map_err(message)?;
+            println!("{} can log in again", email);
+            Ok(())
+        }
+        UserCommand::Purge { email, yes } => {
+            if !yes {
+                return Err("purging a user can not be undone, pass --yes to confirm".to_string());
+            }
+
+            let result = usecase.purge_user(&email).await.map_err(message)?;
+            println!("purged {} and {} todos", email, result.todos);
+            Ok(())
+        }
+    }
+}
+
+fn password_or_prompt(password: Option<String>) -> Result<String, String> {
+    match password {
+        Some(password) => Ok(password),
+        None => rpassword::prompt_password("password: ").map_err(|err| err.to_string()),
+    }
+}
+
+fn message(err: AdminError) -> String {
+    match err {
+        AdminError::NotFound => "user not found".to_string(),
+        AdminError::Conflict => "a user with this email already exists".to_string(),
+        AdminError::GeneralError => "general error, see the log for details".to_string(),
+    }
+}
@@ -0,0 +1,8 @@
+pub mod controller;
+pub mod loader;
+pub mod mutation;
+pub mod query;
+pub mod router;
+pub mod schema;
+pub mod subscription;
+pub mod types;
@@ -0,0 +1,172 @@
+use std::{str::FromStr, sync::Arc};
+
+use async_graphql::{
+    Data,
+    dataloader::DataLoader,
+    futures_util::{SinkExt, StreamExt, future},
+    http::{
+        ALL_WEBSOCKET_PROTOCOLS, GraphiQLSource, WebSocket as GraphqlWebSocket, WebSocketProtocols,
+        WsMessage,
+    },
+};
+use axum::{
+    Json,
+    extract::{
+        State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{Extensions, HeaderMap, header::SEC_WEBSOCKET_PROTOCOL},
+    response::{Html, IntoResponse},
+};
+
+use crate::{
+    infrastructure::security::jwt::JwtClaims,
+    presentation::{
+        graphql::{loader::StateLoader, router::GraphqlState, schema::WorkflowService},
+        restapi::middleware::ClientAddress,
+    },
+};
+
+pub async fn graphql(
+    State(state): State<GraphqlState>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    Json(mut request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    let claims = match bearer(&headers) {
+        Some(token) => authorize(&state, token).await,
+        None => None,
+    };
+    request.data = caller_data(claims, &state.workflow);
+    request.data.insert(ClientAddress(
+        state.limit.client_address(&headers, &extensions),
+    ));
+
+    Json(state.schema.execute(request).await)
+}
+
+pub async fn graphiql() -> impl IntoResponse {
+    Html(
+        GraphiQLSource::build()
+            .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
+            .finish(),
+    )
+}
+
+// subscriptions over `graphql-transport-ws` or the older `graphql-ws`, the token comes from the
+// Authorization header or from the `connection_init` payload for browsers
+pub async fn graphql_ws(
+    State(state): State<GraphqlState>,
+    headers: HeaderMap,
+    extensions: Extensions,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    let protocol = headers
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .split(',')
+                .find_map(|protocol| WebSocketProtocols::from_str(protocol.trim()).ok())
+        })
+        .unwrap_or(WebSocketProtocols::GraphQLWS);
+
+    let claims = match bearer(&headers) {
+        Some(token) => authorize(&state, token).await,
+        None => None,
+    };
+    let address = ClientAddress(state.limit.client_address(&headers, &extensions));
+
+    ws.protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| session(state, claims, address, protocol, socket))
+}
+
+async fn session(
+    state: GraphqlState,
+    claims: Option<JwtClaims>,
+    address: ClientAddress,
+    protocol: WebSocketProtocols,
+    socket: WebSocket,
+) {
+    let (mut sink, stream) = socket.split();
+
+    let input = stream
+        .take_while(|message| future::ready(message.is_ok()))
+        .filter_map(|message| {
+            future::ready(match message {
+                Ok(Message::Text(text)) => Some(text.as_str().as_bytes().to_vec()),
+                Ok(Message::Binary(bytes)) => Some(bytes.to_vec()),
+                _ => None,
+            })
+        });
+
+    let init = state.clone();
+
+    // the init payload only adds claims, the address stays from the upgrade request
+    let mut data = caller_data(claims, &state.workflow);
+    data.insert(address);
+
+    let mut connection = GraphqlWebSocket::new(state.schema.clone(), input, protocol)
+        .connection_data(data)
+        .on_connection_init(move |payload| async move {
+            let token = ["Authorization", "authorization", "token"]
+                .iter()
+                .find_map(|field| payload.get(field).and_then(|value| value.as_str()))
+                .map(|value| value.strip_prefix("Bearer ").unwrap_or(value).to_string());
+
+            Ok(match token {
+                Some(token) => caller_data(authorize(&init, &token).await, &init.workflow),
+                None => Data::default(),
+            })
+        });
+
+    while let Some(message) = connection.next().await {
+        let message = match message {
+            WsMessage::Text(text) => Message::Text(text.into()),
+            WsMessage::Close(code, reason) => Message::Close(Some(CloseFrame {
+                code,
+                reason: reason.into(),
+            })),
+        };
+
+        if sink.send(message).await.is_err() {
+            break;
+        }
+    }
+}
+
+fn bearer(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("Authorization")
+        .and_then(|val| val.to_str().ok())
+        .and_then(|data| data.strip_prefix("Bearer "))
+}
+
+// same rules as `jwt_middleware`, a revoked token is treated like a missing one
+async fn authorize(state: &GraphqlState, token: &str) -> Option<JwtClaims> {
+    let claims = JwtClaims::decode(token.to_string(), &state.jwt_secret).ok()?;
+
+    match claims.is_revoked(state.users.as_ref()).await {
+        true => None,
+        false => Some(claims),
+    }
+}
+
+// per caller data, the loader caches for a single request or subscription connection
+fn caller_data(claims: Option<JwtClaims>, workflow: &Arc<WorkflowService>) -> Data {
+    let mut data = Data::default();
+
+    if let Some(claims) = claims {
+        data.insert(DataLoader::new(
+            StateLoader {
+                user_id: claims.sub,
+                workflow: workflow.clone(),
+            },
+            tokio::spawn,
+        ));
+        data.insert(claims);
+    }
+
+    data
+}
@@ -0,0 +1,31 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::Loader;
+use uuid::Uuid;
+
+use crate::presentation::graphql::{schema::WorkflowService, types::WorkflowStateObject};
+
+// loads workflow states of a single caller, every `Todo.state` of a request shares one query
+pub struct StateLoader {
+    pub user_id: Uuid,
+    pub workflow: Arc<WorkflowService>,
+}
+
+impl Loader<Uuid> for StateLoader {
+    type Value = WorkflowStateObject;
+    type Error = String;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let states = self
+            .workflow
+            .find_states(self.user_id)
+            .await
+            .map_err(|_| "failed to load workflow states".to_string())?;
+
+        Ok(states
+            .into_iter()
+            .filter(|state| keys.contains(&state.id))
+            .map(|state| (state.id, WorkflowStateObject::from(state)))
+            .collect())
+    }
+}
@@ -0,0 +1,194 @@
+use async_graphql::{Context, Error, Object, Result};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        auth::{
+            dto::{LoginRequest, RefreshTokenRequest, RegisterRequest},
+            error::AuthError,
+        },
+        todo::{
+            dto::{CreateTodoRequest, SnoozeTodoRequest, UpdateTodoRequest},
+            error::TodoError,
+        },
+    },
+    presentation::graphql::{
+        schema::{AuthService, TodoService, attempt, claims, error, service},
+        types::{
+            AuthPayload, CreateTodoInput, RegisterInput, TodoObject, UpdateTodoInput, UserObject,
+        },
+    },
+};
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn create_todo(&self, ctx: &Context<'_>, input: CreateTodoInput) -> Result<TodoObject> {
+        let claims = claims(ctx)?;
+
+        let dto = CreateTodoRequest {
+            title: input.title,
+            description: input.description,
+            priority: input.priority,
+            tags: input.tags,
+            project: input.project,
+            due_at: input.due_at,
+        };
+        dto.validate().map_err(invalid)?;
+
+        service::<TodoService>(ctx)
+            .create_todo(claims.sub, dto)
+            .await
+            .map(TodoObject::from)
+            .map_err(todo_error)
+    }
+
+    async fn update_todo(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        input: UpdateTodoInput,
+    ) -> Result<TodoObject> {
+        let claims = claims(ctx)?;
+
+        let dto = UpdateTodoRequest {
+            title: input.title,
+            description: input.description,
+            priority: input.priority,
+            tags: input.tags,
+            project: input.project,
+            due_at: input.due_at,
+        };
+        dto.validate().map_err(invalid)?;
+
+        service::<TodoService>(ctx)
+            .update_todo(claims.sub, id, dto)
+            .await
+            .map(TodoObject::from)
+            .map_err(todo_error)
+    }
+
+    async fn toggle_todo(&self, ctx: &Context<'_>, id: Uuid) -> Result<TodoObject> {
+        let claims = claims(ctx)?;
+        let todos = service::<TodoService>(ctx);
+
+        todos
+            .toggle_todo(claims.sub, id)
+            .await
+            .map_err(todo_error)?;
+
+        todos
+            .find_by_id(claims.sub, id)
+            .await
+            .map_err(todo_error)?
+            .map(TodoObject::from)
+            .ok_or_else(|| todo_error(TodoError::NotFound))
+    }
+
+    /// hide the todo from listings until the given time, null clears the snooze
+    async fn snooze_todo(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<TodoObject> {
+        let claims = claims(ctx)?;
+
+        service::<TodoService>(ctx)
+            .snooze_todo(claims.sub, id, SnoozeTodoRequest { until })
+            .await
+            .map(TodoObject::from)
+            .map_err(todo_error)
+    }
+
+    async fn delete_todo(&self, ctx: &Context<'_>, id: Uuid) -> Result<bool> {
+        let claims = claims(ctx)?;
+
+        service::<TodoService>(ctx)
+            .delete_todo(claims.sub, id)
+            .await
+            .map(|_| true)
+            .map_err(todo_error)
+    }
+
+    async fn register(&self, ctx: &Context<'_>, input: RegisterInput) -> Result<UserObject> {
+        let dto = RegisterRequest {
+            name: input.name,
+            email: input.email,
+            password: input.password,
+        };
+        dto.validate().map_err(invalid)?;
+        attempt(ctx, "register", Some(&dto.email)).await?;
+
+        match service::<AuthService>(ctx).register(dto).await {
+            Ok(user) => Ok(UserObject::from(user)),
+            Err(AuthError::Conflict) => Err(error("CONFLICT", "User already registed")),
+            Err(_) => Err(error("INTERNAL", "something went wrong")),
+        }
+    }
+
+    async fn login(
+        &self,
+        ctx: &Context<'_>,
+        email: String,
+        password: String,
+    ) -> Result<AuthPayload> {
+        let dto = LoginRequest { email, password };
+        dto.validate().map_err(invalid)?;
+        attempt(ctx, "login", Some(&dto.email)).await?;
+
+        match service::<AuthService>(ctx).login(dto).await {
+            Ok(data) => Ok(AuthPayload::from(data)),
+            Err(AuthError::InvalidCredentials | AuthError::NotFound) => {
+                Err(error("UNAUTHORIZED", "invalid email or password"))
+            }
+            Err(AuthError::EmailNotVerified) => Err(error(
+                "EMAIL_NOT_VERIFIED",
+                "verify your email address before logging in",
+            )),
+            Err(AuthError::Locked(until)) => Err(error(
+                "LOCKED",
+                format!("account locked until {}", until.to_rfc3339()),
+            )),
+            Err(_) => Err(error("INTERNAL", "something went wrong")),
+        }
+    }
+
+    async fn refresh_token(&self, ctx: &Context<'_>, token: String) -> Result<AuthPayload> {
+        let dto = RefreshTokenRequest { token };
+        dto.validate().map_err(invalid)?;
+        attempt(ctx, "refresh", None).await?;
+
+        match service::<AuthService>(ctx).refresh_access_token(dto).await {
+            Ok(data) => Ok(AuthPayload::from(data)),
+            Err(AuthError::TokenExpired | AuthError::NotFound) => {
+                Err(error("UNAUTHORIZED", "Token expired"))
+            }
+            Err(_) => Err(error("INTERNAL", "something went wrong")),
+        }
+    }
+
+    async fn logout(&self, ctx: &Context<'_>) -> Result<bool> {
+        let claims = claims(ctx)?;
+
+        service::<AuthService>(ctx)
+            .logout(claims.sub)
+            .await
+            .map(|_| true)
+            .map_err(|_| error("INTERNAL", "something went wrong"))
+    }
+}
+
+fn invalid(err: validator::ValidationErrors) -> Error {
+    error("UNPROCESSABLE_ENTITY", err.to_string())
+}
+
+fn todo_error(err: TodoError) -> Error {
+    match err {
+        TodoError::NotFound => error("NOT_FOUND", "todo not found"),
+        _ => error("INTERNAL", "something went wrong"),
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use async_graphql::{Context, Object, Result};
+use uuid::Uuid;
+
+use crate::{
+    application::{todo::error::TodoError, user::error::UserError},
+    presentation::graphql::{
+        schema::{TodoService, UserService, WorkflowService, claims, error, service},
+        types::{Tag, TodoFilter, TodoObject, UserObject, WorkflowStateObject},
+    },
+};
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// the authenticated user
+    async fn me(&self, ctx: &Context<'_>) -> Result<UserObject> {
+        let claims = claims(ctx)?;
+
+        match service::<UserService>(ctx).find_by_id(claims.sub).await {
+            Ok(Some(user)) => Ok(UserObject::from(user)),
+            Ok(None) | Err(UserError::NotFound) => Err(error("NOT_FOUND", "user not found")),
+            Err(_) => Err(error("INTERNAL", "something went wrong")),
+        }
+    }
+
+    async fn todos(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TodoFilter>,
+    ) -> Result<Vec<TodoObject>> {
+        let claims = claims(ctx)?;
+        let filter = filter.unwrap_or_default();
+        let search = filter.search.map(|search| search.to_lowercase());
+
+        let todos = service::<TodoService>(ctx)
+            .find_all(claims.sub)
+            .await
+            .map_err(|_| error("INTERNAL", "something went wrong"))?;
+
+        Ok(todos
+            .into_iter()
+            .filter(|todo| {
+                filter
+                    .completed
+                    .is_none_or(|done| todo.is_completed == done)
+            })
+            .filter(|todo| {
+                filter
+                    .tag
+                    .as_ref()
+                    .is_none_or(|tag| todo.tags.contains(tag))
+            })
+            .filter(|todo| {
+                search.as_ref().is_none_or(|search| {
+                    todo.title.to_lowercase().contains(search)
+                        || todo.description.to_lowercase().contains(search)
+                })
+            })
+            .map(TodoObject::from)
+            .collect())
+    }
+
+    async fn todo(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<TodoObject>> {
+        let claims = claims(ctx)?;
+
+        match service::<TodoService>(ctx).find_by_id(claims.sub, id).await {
+            Ok(todo) => Ok(todo.map(TodoObject::from)),
+            Err(TodoError::NotFound) => Ok(None),
+            Err(_) => Err(error("INTERNAL", "something went wrong")),
+        }
+    }
+
+    /// every tag used on the caller's todos, by name
+    async fn tags(&self, ctx: &Context<'_>) -> Result<Vec<Tag>> {
+        let claims = claims(ctx)?;
+
+        let todos = service::<TodoService>(ctx)
+            .find_all(claims.sub)
+            .await
+            .map_err(|_| error("INTERNAL", "something went wrong"))?;
+
+        let mut tags = BTreeMap::<String, i32>::new();
+        for tag in todos.into_iter().flat_map(|todo| todo.tags) {
+            *tags.entry(tag).or_default() += 1;
+        }
+
+        Ok(tags
+            .into_iter()
+            .map(|(name, count)| Tag { name, count })
+            .collect())
+    }
+
+    /// workflow states of the caller, ordered like the board
+    async fn states(&self, ctx: &Context<'_>) -> Result<Vec<WorkflowStateObject>> {
+        let claims = claims(ctx)?;
+
+        service::<WorkflowService>(ctx)
+            .find_states(claims.sub)
+            .await
+            .map(|states| states.into_iter().map(WorkflowStateObject::from).collect())
+            .map_err(|_| error("INTERNAL", "something went wrong"))
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use axum::{Router, middleware, routing::get};
+use sqlx::PgPool;
+
+use crate::{
+    application::workflow::usecase::WorkflowUseCase,
+    domain::user::repository::UserRepository,
+    infrastructure::database::sqlx::workflow_repository::PostgresWorkflowRepository,
+    presentation::{
+        graphql::{
+            controller::{graphiql, graphql, graphql_ws},
+            schema::{self, AppSchema, WorkflowService},
+        },
+        restapi::{
+            RouterOption,
+            middleware::{RateLimit, replica_middleware},
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct GraphqlState {
+    pub schema: AppSchema,
+    pub workflow: Arc<WorkflowService>,
+    pub limit: Arc<RateLimit>,
+    pub users: Arc<dyn UserRepository>,
+    pub jwt_secret: String,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let workflow = Arc::new(WorkflowUseCase::new(
+        PostgresWorkflowRepository::new(pool.clone()),
+        opt.repositories.todo.clone(),
+        opt.repositories.transactions.clone(),
+    ));
+
+    let limit = Arc::new(RateLimit::new(opt));
+
+    let state = GraphqlState {
+        schema: schema::build(opt, workflow.clone(), limit.clone()),
+        workflow,
+        limit,
+        users: opt.repositories.user.clone(),
+        jwt_secret: opt.config.jwt_secret.clone(),
+    };
+
+    Router::new()
+        .route("/graphql", get(graphiql).post(graphql))
+        .route("/graphql/ws", get(graphql_ws))
+        .layer(middleware::from_fn(replica_middleware))
+        .with_state(state)
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Error, ErrorExtensions, Result, Schema, dataloader::DataLoader};
+
+use crate::{
+    application::{
+        auth::usecase::AuthUseCase, rate_limit::error::RateLimitError, todo::usecase::TodoUseCase,
+        user::usecase::UserUseCase, workflow::usecase::WorkflowUseCase,
+    },
+    domain::{
+        lockout::repository::LockoutRepository, mail::mailer::Mailer,
+        shared::transaction::TransactionManager, todo::repository::TodoRepository,
+        user::repository::UserRepository,
+    },
+    infrastructure::{
+        database::sqlx::workflow_repository::PostgresWorkflowRepository, realtime::EventHub,
+        security::jwt::JwtClaims,
+    },
+    presentation::{
+        graphql::{mutation::MutationRoot, query::QueryRoot, subscription::SubscriptionRoot},
+        restapi::{
+            RouterOption,
+            middleware::{ClientAddress, RateLimit},
+        },
+    },
+};
+
+pub type AppSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+pub type TodoService = TodoUseCase<Arc<dyn TodoRepository>, Arc<dyn TransactionManager>>;
+pub type UserService = UserUseCase<
+    Arc<dyn UserRepository>,
+    Arc<dyn TransactionManager>,
+    Arc<dyn LockoutRepository>,
+    Arc<dyn Mailer>,
+>;
+pub type AuthService =
+    AuthUseCase<Arc<dyn UserRepository>, Arc<dyn LockoutRepository>, Arc<dyn Mailer>>;
+pub type WorkflowService = WorkflowUseCase<
+    PostgresWorkflowRepository,
+    Arc<dyn TodoRepository>,
+    Arc<dyn TransactionManager>,
+>;
+
+const MAX_DEPTH: usize = 10;
+const MAX_COMPLEXITY: usize = 500;
+
+// the use cases share the configured repositories with the rest api, so reads go through the
+// cache and the replicas the same way
+pub fn build(
+    opt: &RouterOption,
+    workflow: Arc<WorkflowService>,
+    limit: Arc<RateLimit>,
+) -> AppSchema {
+    let todo = TodoUseCase::new(
+        opt.repositories.todo.clone(),
+        opt.repositories.transactions.clone(),
+    );
+    let user = UserUseCase::new(
+        opt.repositories.user.clone(),
+        opt.repositories.transactions.clone(),
+        opt.repositories.lockout.clone(),
+        opt.mailer.clone(),
+        opt.config.lockout(),
+        opt.config.email_verification(),
+        opt.config.jwt_secret.clone(),
+    );
+    let auth = AuthUseCase::new(
+        opt.repositories.user.clone(),
+        opt.repositories.lockout.clone(),
+        opt.mailer.clone(),
+        opt.config.lockout(),
+        opt.config.email_verification(),
+        opt.config.jwt_secret.clone(),
+        opt.config.jwt_duration,
+    );
+
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot)
+        .data(Arc::new(todo))
+        .data(Arc::new(user))
+        .data(Arc::new(auth))
+        .data(workflow)
+        .data(limit)
+        .data(opt.hub.clone())
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+        .finish()
+}
+
+pub fn service<'ctx, T: Send + Sync + 'static>(ctx: &Context<'ctx>) -> &'ctx Arc<T> {
+    // every service is registered in `build`
+    ctx.data_unchecked::<Arc<T>>()
+}
+
+pub fn hub<'ctx>(ctx: &Context<'ctx>) -> &'ctx EventHub {
+    ctx.data_unchecked::<EventHub>()
+}
+
+// same rules as `jwt_middleware`, the claims are attached per request by the controller
+pub fn claims<'ctx>(ctx: &Context<'ctx>) -> Result<&'ctx JwtClaims> {
+    ctx.data_opt::<JwtClaims>()
+        .ok_or_else(|| error("UNAUTHORIZED", "Authorization not found"))
+}
+
+pub fn loader<'ctx, L: Send + Sync + 'static>(ctx: &Context<'ctx>) -> Result<&'ctx DataLoader<L>> {
+    claims(ctx)?;
+
+    ctx.data::<DataLoader<L>>()
+}
+
+// the auth mutations are counted like `rate_limit_middleware` counts the rest routes, once per
+// field so aliases do not multiply the attempts of a request
+pub async fn attempt(ctx: &Context<'_>, action: &str, email: Option<&str>) -> Result<()> {
+    let limit = service::<RateLimit>(ctx);
+    let ip = ctx
+        .data_opt::<ClientAddress>()
+        .map(|ClientAddress(ip)| ip.as_str())
+        .unwrap_or("unknown");
+
+    match limit.usecase.attempt(action, ip, email).await {
+        Ok(()) => Ok(()),
+        Err(RateLimitError::Exceeded { retry_after }) => Err(error(
+            "TOO_MANY_REQUESTS",
+            "Too many attempts, try again later",
+        )
+        .extend_with(|_, extensions| extensions.set("retryAfter", retry_after.as_secs()))),
+        Err(RateLimitError::GeneralError) => Err(error("INTERNAL", "something went wrong")),
+    }
+}
+
+pub fn error(code: &'static str, message: impl Into<String>) -> Error {
+    Error::new(message).extend_with(|_, extensions| extensions.set("code", code))
+}
@@ -0,0 +1,34 @@
+use async_graphql::futures_util::Stream;
+use async_graphql::{Context, Result, Subscription};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    application::event::dto::TodoEventResponse,
+    presentation::graphql::{
+        schema::{claims, hub},
+        types::TodoEventObject,
+    },
+};
+
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// changes to the caller's todos as they happen on any instance
+    async fn todo_events(&self, ctx: &Context<'_>) -> Result<impl Stream<Item = TodoEventObject>> {
+        let user_id = claims(ctx)?.sub;
+        let mut receiver = hub(ctx).subscribe();
+
+        Ok(async_stream::stream! {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) if event.user_id == user_id => {
+                        yield TodoEventObject::from(TodoEventResponse::from(event.as_ref().clone()));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use async_graphql::{ComplexObject, Context, Enum, InputObject, Result, SimpleObject};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        auth::dto::AuthResponse, event::dto::TodoEventResponse, todo::dto::TodoResponse,
+        user::dto::UserResponse, workflow::dto::WorkflowStateResponse,
+    },
+    domain::{event::model::TodoEventKind, user::model::User, workflow::model::StateCategory},
+    presentation::graphql::{loader::StateLoader, schema::loader},
+};
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex, name = "Todo")]
+pub struct TodoObject {
+    pub id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub is_completed: bool,
+    pub state_id: Option<Uuid>,
+    pub priority: i16,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub tracked_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl TodoObject {
+    /// workflow state of the todo, batched across every todo of the query
+    async fn state(&self, ctx: &Context<'_>) -> Result<Option<WorkflowStateObject>> {
+        match self.state_id {
+            Some(id) => Ok(loader::<StateLoader>(ctx)?.load_one(id).await?),
+            None => Ok(None),
+        }
+    }
+}
+
+impl From<TodoResponse> for TodoObject {
+    fn from(value: TodoResponse) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            description: value.description,
+            is_completed: value.is_completed,
+            state_id: value.state_id,
+            priority: value.priority,
+            tags: value.tags,
+            project: value.project,
+            due_at: value.due_at,
+            snoozed_until: value.snoozed_until,
+            tracked_seconds: value.tracked_seconds,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(name = "StateCategory")]
+pub enum StateCategoryObject {
+    Todo,
+    Doing,
+    Done,
+}
+
+impl From<StateCategory> for StateCategoryObject {
+    fn from(value: StateCategory) -> Self {
+        match value {
+            StateCategory::Todo => Self::Todo,
+            StateCategory::Doing => Self::Doing,
+            StateCategory::Done => Self::Done,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(name = "WorkflowState")]
+pub struct WorkflowStateObject {
+    pub id: Uuid,
+    pub name: String,
+    pub position: i32,
+    pub category: StateCategoryObject,
+}
+
+impl From<WorkflowStateResponse> for WorkflowStateObject {
+    fn from(value: WorkflowStateResponse) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            position: value.position,
+            category: StateCategoryObject::from(value.category),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct Tag {
+    pub name: String,
+    /// number of todos carrying the tag
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(name = "User")]
+pub struct UserObject {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+}
+
+impl From<UserResponse> for UserObject {
+    fn from(value: UserResponse) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            email: value.email,
+        }
+    }
+}
+
+impl From<User> for UserObject {
+    fn from(value: User) -> Self {
+        Self::from(UserResponse::from(value))
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct AuthPayload {
+    pub id: Uuid,
+    pub name: String,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+impl From<AuthResponse> for AuthPayload {
+    fn from(value: AuthResponse) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            access_token: value.access_token,
+            refresh_token: value.refresh_token,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(name = "TodoEventKind")]
+pub enum TodoEventKindObject {
+    Created,
+    Updated,
+    Toggled,
+    Deleted,
+}
+
+impl From<TodoEventKind> for TodoEventKindObject {
+    fn from(value: TodoEventKind) -> Self {
+        match value {
+            TodoEventKind::Created => Self::Created,
+            TodoEventKind::Updated => Self::Updated,
+            TodoEventKind::Toggled => Self::Toggled,
+            TodoEventKind::Deleted => Self::Deleted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(name = "TodoEvent")]
+pub struct TodoEventObject {
+    pub id: i64,
+    pub kind: TodoEventKindObject,
+    pub todo_id: Uuid,
+    /// the todo right after the change, the last known copy for deletes
+    pub todo: Option<TodoObject>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TodoEventResponse> for TodoEventObject {
+    fn from(value: TodoEventResponse) -> Self {
+        Self {
+            id: value.id,
+            kind: TodoEventKindObject::from(value.kind),
+            todo_id: value.todo_id,
+            todo: serde_json::from_value::<crate::domain::todo::model::Todo>(value.todo)
+                .ok()
+                .map(|todo| TodoObject::from(TodoResponse::from(todo))),
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Default, InputObject)]
+pub struct TodoFilter {
+    pub completed: Option<bool>,
+    pub tag: Option<String>,
+    /// case-insensitive match on title and description
+    pub search: Option<String>,
+}
+
+#[derive(Debug, InputObject)]
+pub struct CreateTodoInput {
+    pub title: String,
+    #[graphql(default)]
+    pub description: String,
+    #[graphql(default)]
+    pub priority: i16,
+    #[graphql(default)]
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, InputObject)]
+pub struct UpdateTodoInput {
+    pub title: String,
+    #[graphql(default)]
+    pub description: String,
+    #[graphql(default)]
+    pub priority: i16,
+    #[graphql(default)]
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, InputObject)]
+pub struct RegisterInput {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+}
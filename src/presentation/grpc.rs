@@ -0,0 +1,5 @@
+pub mod auth;
+pub mod proto;
+pub mod server;
+pub mod todo;
+pub mod user;
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use tonic::{Request, Status, service::Interceptor};
+use uuid::Uuid;
+
+use crate::{
+    domain::user::repository::UserRepository, infrastructure::security::jwt::JwtClaims,
+    presentation::grpc::proto,
+};
+
+const API_KEY: &str = "x-api-key";
+const ACTING_USER: &str = "x-user-id";
+
+// the user every grpc call is made for, with the claims of the token it was proven by
+#[derive(Debug, Clone)]
+pub struct Caller {
+    pub user_id: Uuid,
+    pub claims: Option<JwtClaims>,
+}
+
+// users send `authorization: Bearer <jwt>`, internal services send `x-api-key` together with
+// `x-user-id` for the user they act on behalf of
+#[derive(Clone)]
+pub struct Authenticator {
+    pub jwt_secret: String,
+    pub api_keys: Arc<Vec<String>>,
+    pub users: Arc<dyn UserRepository>,
+}
+
+impl Authenticator {
+    pub fn new(jwt_secret: String, api_keys: Vec<String>, users: Arc<dyn UserRepository>) -> Self {
+        Self {
+            jwt_secret,
+            api_keys: Arc::new(api_keys),
+            users,
+        }
+    }
+
+    // interceptors can't wait on the database, so revoked tokens are turned away here, before a
+    // service uses the caller
+    pub async fn caller<T>(&self, request: &Request<T>) -> Result<Uuid, Status> {
+        let caller = request
+            .extensions()
+            .get::<Caller>()
+            .ok_or_else(|| Status::unauthenticated("Authorization not found"))?;
+
+        if let Some(claims) = &caller.claims
+            && claims.is_revoked(self.users.as_ref()).await
+        {
+            return Err(Status::unauthenticated("Authorization revoked"));
+        }
+
+        Ok(caller.user_id)
+    }
+
+    fn authenticate<T>(&self, request: &Request<T>) -> Result<Caller, Status> {
+        let metadata = request.metadata();
+
+        if let Some(key) = metadata.get(API_KEY) {
+            let key = key.to_str().unwrap_or_default();
+
+            if !self.api_keys.iter().any(|known| known == key) {
+                return Err(Status::unauthenticated("invalid api key"));
+            }
+
+            let user_id = metadata
+                .get(ACTING_USER)
+                .and_then(|val| val.to_str().ok())
+                .ok_or_else(|| Status::unauthenticated("x-user-id is required with an api key"))
+                .and_then(proto::uuid)?;
+
+            return Ok(Caller {
+                user_id,
+                claims: None,
+            });
+        }
+
+        let token = metadata
+            .get("authorization")
+            .and_then(|val| val.to_str().ok())
+            .and_then(|data| data.strip_prefix("Bearer "))
+            .ok_or_else(|| Status::unauthenticated("Authorization not found"))?;
+
+        let claims = JwtClaims::decode(token.to_string(), &self.jwt_secret)
+            .map_err(|_| Status::unauthenticated("Authorization not found"))?;
+
+        Ok(Caller {
+            user_id: claims.sub,
+            claims: Some(claims),
+        })
+    }
+}
+
+impl Interceptor for Authenticator {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let caller = self.authenticate(&request)?;
+
+        request.extensions_mut().insert(caller);
+        Ok(request)
+    }
+}
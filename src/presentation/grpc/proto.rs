@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+use tonic::Status;
+use uuid::Uuid;
+
+pub mod todo {
+    tonic::include_proto!("todo.v1");
+}
+
+pub mod user {
+    tonic::include_proto!("user.v1");
+}
+
+pub fn timestamp(value: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: value.timestamp(),
+        nanos: value.timestamp_subsec_nanos() as i32,
+    }
+}
+
+pub fn datetime(value: Timestamp) -> Result<DateTime<Utc>, Status> {
+    u32::try_from(value.nanos)
+        .ok()
+        .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+        .ok_or_else(|| Status::invalid_argument("invalid timestamp"))
+}
+
+pub fn uuid(value: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(value).map_err(|_| Status::invalid_argument("invalid id"))
+}
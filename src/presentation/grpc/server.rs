@@ -0,0 +1,72 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use tokio::task::JoinHandle;
+use tonic::transport::Server;
+
+use crate::{
+    application::{todo::usecase::TodoUseCase, user::usecase::UserUseCase},
+    presentation::{
+        grpc::{
+            auth::Authenticator,
+            proto::{
+                todo::todo_service_server::TodoServiceServer,
+                user::user_service_server::UserServiceServer,
+            },
+            todo::TodoGrpcService,
+            user::UserGrpcService,
+        },
+        restapi::RouterOption,
+    },
+};
+
+// serve the grpc api on its own port next to the axum server
+pub fn serve(opt: &RouterOption) -> JoinHandle<()> {
+    let addr = opt.config.grpc_addr();
+
+    let auth = Authenticator::new(
+        opt.config.jwt_secret.clone(),
+        opt.config.grpc_api_keys(),
+        opt.repositories.user.clone(),
+    );
+
+    let todo = TodoGrpcService {
+        todo_usecase: Arc::new(TodoUseCase::new(
+            opt.repositories.todo.clone(),
+            opt.repositories.transactions.clone(),
+        )),
+        auth: auth.clone(),
+    };
+
+    let user = UserGrpcService {
+        user_usecase: Arc::new(UserUseCase::new(
+            opt.repositories.user.clone(),
+            opt.repositories.transactions.clone(),
+            opt.repositories.lockout.clone(),
+            opt.mailer.clone(),
+            opt.config.lockout(),
+            opt.config.email_verification(),
+            opt.config.jwt_secret.clone(),
+        )),
+        auth: auth.clone(),
+    };
+
+    let router = Server::builder()
+        .add_service(TodoServiceServer::with_interceptor(todo, auth.clone()))
+        .add_service(UserServiceServer::with_interceptor(user, auth));
+
+    tokio::spawn(async move {
+        let addr = match addr.parse::<SocketAddr>() {
+            Ok(addr) => addr,
+            Err(err) => {
+                tracing::error!("invalid grpc address {}: {}", addr, err);
+                return;
+            }
+        };
+
+        tracing::debug!("grpc listen on {}", addr);
+
+        if let Err(err) = router.serve(addr).await {
+            tracing::error!("grpc server error: {}", err);
+        }
+    })
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+use validator::Validate;
+
+use crate::{
+    application::todo::{
+        dto::{CreateTodoRequest, SnoozeTodoRequest, TodoResponse, UpdateTodoRequest},
+        error::TodoError,
+        usecase::TodoUseCase,
+    },
+    domain::{shared::transaction::TransactionManager, todo::repository::TodoRepository},
+    presentation::grpc::{
+        auth::Authenticator,
+        proto::{self, todo},
+    },
+};
+
+pub type TodoService = TodoUseCase<Arc<dyn TodoRepository>, Arc<dyn TransactionManager>>;
+
+pub struct TodoGrpcService {
+    pub todo_usecase: Arc<TodoService>,
+    pub auth: Authenticator,
+}
+
+#[tonic::async_trait]
+impl todo::todo_service_server::TodoService for TodoGrpcService {
+    async fn list_todos(
+        &self,
+        request: Request<todo::ListTodosRequest>,
+    ) -> Result<Response<todo::ListTodosResponse>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+
+        let todos = self.todo_usecase.find_all(user_id).await.map_err(status)?;
+
+        Ok(Response::new(todo::ListTodosResponse {
+            todos: todos.into_iter().map(todo::Todo::from).collect(),
+        }))
+    }
+
+    async fn get_todo(
+        &self,
+        request: Request<todo::GetTodoRequest>,
+    ) -> Result<Response<todo::Todo>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let id = proto::uuid(&request.get_ref().id)?;
+
+        match self.todo_usecase.find_by_id(user_id, id).await {
+            Ok(Some(todo)) => Ok(Response::new(todo.into())),
+            Ok(None) => Err(status(TodoError::NotFound)),
+            Err(err) => Err(status(err)),
+        }
+    }
+
+    async fn create_todo(
+        &self,
+        request: Request<todo::CreateTodoRequest>,
+    ) -> Result<Response<todo::Todo>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let input = request.into_inner();
+
+        let dto = CreateTodoRequest {
+            title: input.title,
+            description: input.description,
+            priority: priority(input.priority)?,
+            tags: input.tags,
+            project: input.project,
+            due_at: input.due_at.map(proto::datetime).transpose()?,
+        };
+
+        if let Err(err) = dto.validate() {
+            return Err(Status::invalid_argument(err.to_string()));
+        }
+
+        self.todo_usecase
+            .create_todo(user_id, dto)
+            .await
+            .map(|todo| Response::new(todo.into()))
+            .map_err(status)
+    }
+
+    async fn update_todo(
+        &self,
+        request: Request<todo::UpdateTodoRequest>,
+    ) -> Result<Response<todo::Todo>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let input = request.into_inner();
+        let id = proto::uuid(&input.id)?;
+
+        let dto = UpdateTodoRequest {
+            title: input.title,
+            description: input.description,
+            priority: priority(input.priority)?,
+            tags: input.tags,
+            project: input.project,
+            due_at: input.due_at.map(proto::datetime).transpose()?,
+        };
+
+        if let Err(err) = dto.validate() {
+            return Err(Status::invalid_argument(err.to_string()));
+        }
+
+        self.todo_usecase
+            .update_todo(user_id, id, dto)
+            .await
+            .map(|todo| Response::new(todo.into()))
+            .map_err(status)
+    }
+
+    async fn toggle_todo(
+        &self,
+        request: Request<todo::ToggleTodoRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let id = proto::uuid(&request.get_ref().id)?;
+
+        self.todo_usecase
+            .toggle_todo(user_id, id)
+            .await
+            .map(Response::new)
+            .map_err(status)
+    }
+
+    async fn snooze_todo(
+        &self,
+        request: Request<todo::SnoozeTodoRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let input = request.into_inner();
+        let id = proto::uuid(&input.id)?;
+
+        let dto = SnoozeTodoRequest {
+            until: input.until.map(proto::datetime).transpose()?,
+        };
+
+        self.todo_usecase
+            .snooze_todo(user_id, id, dto)
+            .await
+            .map(|_| Response::new(()))
+            .map_err(status)
+    }
+
+    async fn delete_todo(
+        &self,
+        request: Request<todo::DeleteTodoRequest>,
+    ) -> Result<Response<()>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+        let id = proto::uuid(&request.get_ref().id)?;
+
+        self.todo_usecase
+            .delete_todo(user_id, id)
+            .await
+            .map(Response::new)
+            .map_err(status)
+    }
+}
+
+impl From<TodoResponse> for todo::Todo {
+    fn from(value: TodoResponse) -> Self {
+        Self {
+            id: value.id.to_string(),
+            title: value.title,
+            description: value.description,
+            is_completed: value.is_completed,
+            state_id: value.state_id.map(|id| id.to_string()),
+            priority: value.priority.into(),
+            tags: value.tags,
+            project: value.project,
+            due_at: value.due_at.map(proto::timestamp),
+            snoozed_until: value.snoozed_until.map(proto::timestamp),
+            tracked_seconds: value.tracked_seconds,
+            created_at: Some(proto::timestamp(value.created_at)),
+            updated_at: Some(proto::timestamp(value.updated_at)),
+        }
+    }
+}
+
+fn priority(value: i32) -> Result<i16, Status> {
+    i16::try_from(value).map_err(|_| Status::invalid_argument("priority must be between 0 and 3"))
+}
+
+fn status(err: TodoError) -> Status {
+    match err {
+        TodoError::NotFound => Status::not_found("Todo not found"),
+        TodoError::BussinerError => Status::failed_precondition("Todo can not be changed"),
+        TodoError::GeneralError => Status::internal("general error"),
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::{
+    application::user::{dto::UserResponse, error::UserError, usecase::UserUseCase},
+    domain::{
+        lockout::repository::LockoutRepository, mail::mailer::Mailer,
+        shared::transaction::TransactionManager, user::repository::UserRepository,
+    },
+    presentation::grpc::{
+        auth::Authenticator,
+        proto::{self, user},
+    },
+};
+
+pub type UserService = UserUseCase<
+    Arc<dyn UserRepository>,
+    Arc<dyn TransactionManager>,
+    Arc<dyn LockoutRepository>,
+    Arc<dyn Mailer>,
+>;
+
+pub struct UserGrpcService {
+    pub user_usecase: Arc<UserService>,
+    pub auth: Authenticator,
+}
+
+#[tonic::async_trait]
+impl user::user_service_server::UserService for UserGrpcService {
+    async fn me(&self, request: Request<()>) -> Result<Response<user::User>, Status> {
+        let user_id = self.auth.caller(&request).await?;
+
+        self.find(user_id).await
+    }
+
+    async fn list_users(
+        &self,
+        request: Request<user::ListUsersRequest>,
+    ) -> Result<Response<user::ListUsersResponse>, Status> {
+        self.auth.caller(&request).await?;
+
+        let users = self.user_usecase.find_all().await.map_err(status)?;
+
+        Ok(Response::new(user::ListUsersResponse {
+            users: users.into_iter().map(user::User::from).collect(),
+        }))
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<user::GetUserRequest>,
+    ) -> Result<Response<user::User>, Status> {
+        self.auth.caller(&request).await?;
+
+        self.find(proto::uuid(&request.get_ref().id)?).await
+    }
+
+    async fn delete_user(
+        &self,
+        request: Request<user::DeleteUserRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.auth.caller(&request).await?;
+
+        let id = proto::uuid(&request.get_ref().id)?;
+
+        self.user_usecase
+            .delete_user(id)
+            .await
+            .map(Response::new)
+            .map_err(status)
+    }
+}
+
+impl UserGrpcService {
+    async fn find(&self, id: uuid::Uuid) -> Result<Response<user::User>, Status> {
+        match self.user_usecase.find_by_id(id).await {
+            Ok(Some(user)) => Ok(Response::new(user.into())),
+            Ok(None) => Err(status(UserError::NotFound)),
+            Err(err) => Err(status(err)),
+        }
+    }
+}
+
+impl From<UserResponse> for user::User {
+    fn from(value: UserResponse) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name,
+            email: value.email,
+        }
+    }
+}
+
+fn status(err: UserError) -> Status {
+    match err {
+        UserError::NotFound => Status::not_found("User not found"),
+        UserError::Conflict => Status::already_exists("Email already registered"),
+        UserError::InvalidPassword => Status::permission_denied("Current password mismatch"),
+        UserError::Locked(until) => {
+            Status::permission_denied(format!("Account locked until {}", until.to_rfc3339()))
+        }
+        UserError::GeneralError => Status::internal("general error"),
+    }
+}
@@ -1,29 +1,73 @@
-use axum::Router;
+use axum::{Extension, Router, middleware::from_fn};
 use sqlx::PgPool;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{infrastructure::config::AppConfig, presentation::restapi::swagger::ApiDoc};
+use std::sync::Arc;
 
+use crate::{
+    domain::mail::mailer::Mailer,
+    infrastructure::{
+        cache::RepositoryCache, config::AppConfig, database::Repositories, realtime::EventHub,
+    },
+    presentation::restapi::swagger::ApiDoc,
+};
+
+mod admin;
 mod auth;
-mod middleware;
+mod event;
+pub mod middleware;
+mod notification;
+mod realtime;
+mod reminder;
 mod response;
 mod swagger;
+mod sync;
+mod template;
+mod time_entry;
 mod todo;
 mod user;
+mod webhook;
+mod workflow;
 
 pub struct RouterOption<'ro> {
-    pub pool: &'ro PgPool,
+    // only set on the postgres backend, routers beyond auth, users and todos need it
+    pub pool: Option<&'ro PgPool>,
+    pub repositories: &'ro Repositories,
     pub config: &'ro AppConfig,
+    pub hub: &'ro EventHub,
+    pub mailer: &'ro Arc<dyn Mailer>,
+    // set when caching is enabled, for writes outside the cached repositories that make it stale
+    pub cache: Option<&'ro Arc<RepositoryCache>>,
 }
 
 pub fn setup(opt: &RouterOption) -> Router {
-    let router = Router::new()
+    let mut router = Router::new()
+        .nest("/admin", admin::router::setup(opt))
         .nest("/auth", auth::router::setup(opt))
         .nest("/todo", todo::router::setup(opt))
-        .nest("/user", user::router::setup(opt));
+        .nest("/user", user::router::setup(opt))
+        .merge(sync::router::setup(opt));
+
+    if let Some(pool) = opt.pool {
+        router = router
+            .nest("/notifications", notification::router::setup(opt, pool))
+            .nest("/realtime", realtime::router::setup(opt, pool))
+            .nest("/templates", template::router::setup(opt, pool))
+            .nest("/webhooks", webhook::router::setup(opt, pool))
+            .merge(event::router::setup(opt, pool))
+            .merge(reminder::router::setup(opt, pool))
+            .merge(time_entry::router::setup(opt, pool))
+            .merge(workflow::router::setup(opt, pool));
+    }
 
     Router::new()
-        .nest("/api/v1", router)
+        .nest(
+            "/api/v1",
+            router
+                .layer(from_fn(middleware::replica_middleware))
+                // `jwt_middleware` checks every token against its user
+                .layer(Extension(opt.repositories.user.clone())),
+        )
         .merge(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi()))
 }
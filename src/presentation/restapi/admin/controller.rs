@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    application::admin::error::AdminError,
+    presentation::restapi::{
+        admin::router::AdminState,
+        response::{ApiResponse, Empty},
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/admin/users/{id}/unlock",
+    params(
+        ("id"=Uuid, Path, description = "Unique user identifier")
+    ),
+    responses(
+        (status = 200, description = "User can log in again", body = ApiResponse<Empty>),
+        (status = 401, description = "Missing or unknown api key", body = ApiResponse<Empty>),
+        (status = 404, description = "User not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "admin",
+    security(("api_key" = []))
+)]
+#[axum::debug_handler]
+pub async fn unlock_user(
+    State(state): State<AdminState>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.admin_usecase.unlock(id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(AdminError::NotFound) => ApiResponse::not_found("User not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use axum::{Extension, Router, middleware, routing::post};
+
+use crate::{
+    application::admin::usecase::AdminUseCase,
+    domain::{
+        lockout::repository::LockoutRepository, shared::transaction::TransactionManager,
+        user::repository::UserRepository,
+    },
+    presentation::restapi::{
+        RouterOption,
+        admin::controller::unlock_user,
+        middleware::{AdminApiKeys, admin_middleware},
+    },
+};
+
+type AdminService =
+    AdminUseCase<Arc<dyn UserRepository>, Arc<dyn TransactionManager>, Arc<dyn LockoutRepository>>;
+
+#[derive(Clone)]
+pub struct AdminState {
+    pub admin_usecase: Arc<AdminService>,
+}
+
+pub fn setup(opt: &RouterOption) -> Router {
+    let usecase = AdminUseCase::new(
+        opt.repositories.user.clone(),
+        opt.repositories.transactions.clone(),
+        opt.repositories.lockout.clone(),
+    );
+
+    let state = AdminState {
+        admin_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/users/{id}/unlock", post(unlock_user))
+        .layer(middleware::from_fn(admin_middleware))
+        .layer(Extension(AdminApiKeys::new(opt.config.admin_api_keys())))
+        .with_state(state)
+}
@@ -1,12 +1,23 @@
-use axum::{Extension, Json, extract::State, response::IntoResponse};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    response::IntoResponse,
+};
 use validator::Validate;
 
 use crate::{
     application::{
         auth::{
-            dto::{AuthResponse, LoginRequest, RefreshTokenRequest, RegisterRequest},
+            dto::{
+                AuthResponse, LoginRequest, RefreshTokenRequest, RegisterRequest,
+                ResendVerificationRequest, VerifyEmailQuery,
+            },
             error::AuthError,
         },
+        password_reset::{
+            dto::{ForgotPasswordRequest, PasswordResetRequest},
+            error::PasswordResetError,
+        },
         user::dto::UserResponse,
     },
     infrastructure::security::jwt::JwtClaims,
@@ -24,6 +35,7 @@ use crate::{
         (status = 200, description = "Registration successful", body = ApiResponse<Empty>),
         (status = 409, description = "User already registered", body = ApiResponse<Empty>),
         (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
         (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
     ),
     tag = "auth"
@@ -48,11 +60,15 @@ pub async fn register(
 #[utoipa::path(
     post,
     path = "/auth/login",
+    description = "Not idempotent, an `Idempotency-Key` header is ignored and every retry is issued new tokens",
     request_body = LoginRequest,
     responses(
         (status = 200, description = "Login successful", body = ApiResponse<AuthResponse>),
         (status = 401, description = "Invalid credentials", body = ApiResponse<Empty>),
+        (status = 403, description = "Email address not verified yet", body = ApiResponse<Empty>),
         (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 423, description = "Locked after too many failed logins", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
         (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
     ),
     tag = "auth",
@@ -69,6 +85,133 @@ pub async fn login_with_email(
     match state.auth_usecase.login(dto).await {
         Ok(data) => ApiResponse::<AuthResponse>::success(Some(data)),
         Err(AuthError::InvalidCredentials) => ApiResponse::unauthorized("password missmatch"),
+        Err(AuthError::Locked(until)) => {
+            ApiResponse::locked(format!("Account locked until {}", until.to_rfc3339()))
+        }
+        Err(AuthError::EmailNotVerified) => {
+            ApiResponse::forbidden("Verify your email address before logging in")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/email/verify",
+    params(VerifyEmailQuery),
+    responses(
+        (status = 200, description = "Email address verified", body = ApiResponse<Empty>),
+        (status = 401, description = "Link invalid or expired", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "auth",
+)]
+#[axum::debug_handler]
+pub async fn verify_email(
+    State(state): State<AuthState>,
+    Query(query): Query<VerifyEmailQuery>,
+) -> impl IntoResponse {
+    if let Err(err) = query.validate() {
+        return ApiResponse::<Empty>::unprocessable_entity(err.to_string());
+    }
+
+    match state.auth_usecase.verify_email(&query.token).await {
+        Ok(_) => ApiResponse::success(None),
+        Err(AuthError::TokenExpired | AuthError::NotFound) => {
+            ApiResponse::unauthorized("Link invalid or expired")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/email/resend",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Sent again if the address belongs to an unverified account", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "auth",
+)]
+#[axum::debug_handler]
+pub async fn resend_verification(
+    State(state): State<AuthState>,
+    Json(dto): Json<ResendVerificationRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::<Empty>::unprocessable_entity(err.to_string());
+    }
+
+    match state.auth_usecase.resend_verification(dto).await {
+        Ok(_) => ApiResponse::success(None),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/forgot",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset token mailed if the address belongs to an account", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
+    ),
+    tag = "auth",
+)]
+#[axum::debug_handler]
+pub async fn forgot_password(
+    State(state): State<AuthState>,
+    Json(dto): Json<ForgotPasswordRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::<Empty>::unprocessable_entity(err.to_string());
+    }
+
+    // answered before the lookup and the mail, neither the outcome nor the time taken may tell
+    // whether the address is registered
+    let usecase = state.password_reset_usecase.clone();
+    tokio::spawn(async move {
+        if let Err(err) = usecase.forgot_password(dto).await {
+            tracing::error!("failed start password reset : {:?}", err);
+        }
+    });
+
+    ApiResponse::success(None)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/reset",
+    request_body = PasswordResetRequest,
+    responses(
+        (status = 200, description = "Password changed, every session is signed out", body = ApiResponse<Empty>),
+        (status = 401, description = "Token invalid or expired", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "auth",
+)]
+#[axum::debug_handler]
+pub async fn reset_password(
+    State(state): State<AuthState>,
+    Json(dto): Json<PasswordResetRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::<Empty>::unprocessable_entity(err.to_string());
+    }
+
+    match state.password_reset_usecase.reset_password(dto).await {
+        Ok(_) => ApiResponse::success(None),
+        Err(PasswordResetError::InvalidToken) => {
+            ApiResponse::unauthorized("Token invalid or expired")
+        }
         Err(_) => ApiResponse::general_error(),
     }
 }
@@ -76,11 +219,13 @@ pub async fn login_with_email(
 #[utoipa::path(
     post,
     path = "/auth/refresh",
+    description = "Not idempotent, an `Idempotency-Key` header is ignored and every retry is issued new tokens",
     request_body = RefreshTokenRequest,
     responses(
         (status = 200, description = "Successfully refreshed access token", body = ApiResponse<Option<AuthResponse>>),
         (status = 401, description = "Unauthorized - token expired or invalid", body = ApiResponse<Empty>),
         (status = 422, description = "Unprocessable entity - validation error", body = ApiResponse<Empty>),
+        (status = 429, description = "Too many attempts, see Retry-After", body = ApiResponse<Empty>),
         (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
     ),
     tag = "auth",
@@ -6,36 +6,105 @@ use axum::{
 };
 
 use crate::{
-    application::auth::usecase::AuthUseCase,
-    infrastructure::database::sqlx::user_repository::PostgresUserRepository,
+    application::{
+        auth::usecase::AuthUseCase, idempotency::usecase::IdempotencyUseCase,
+        password_reset::usecase::PasswordResetUseCase,
+    },
+    domain::{
+        lockout::repository::LockoutRepository, mail::mailer::Mailer,
+        password_reset::repository::PasswordResetRepository, user::repository::UserRepository,
+    },
+    infrastructure::database::sqlx::idempotency_repository::PostgresIdempotencyRepository,
     presentation::restapi::{
         RouterOption,
-        auth::controller::{login_with_email, logout, refresh_access_token, register, whoami},
-        middleware::jwt_middleware,
+        auth::controller::{
+            forgot_password, login_with_email, logout, refresh_access_token, register,
+            resend_verification, reset_password, verify_email, whoami,
+        },
+        middleware::{RateLimit, idempotency_middleware, jwt_middleware, rate_limit_middleware},
     },
 };
 
+type AuthService =
+    AuthUseCase<Arc<dyn UserRepository>, Arc<dyn LockoutRepository>, Arc<dyn Mailer>>;
+
+type PasswordResetService = PasswordResetUseCase<
+    Arc<dyn UserRepository>,
+    Arc<dyn PasswordResetRepository>,
+    Arc<dyn Mailer>,
+>;
+
 #[derive(Clone)]
 pub struct AuthState {
-    pub auth_usecase: Arc<AuthUseCase<PostgresUserRepository>>,
+    pub auth_usecase: Arc<AuthService>,
+    pub password_reset_usecase: Arc<PasswordResetService>,
 }
 
 pub fn setup(opt: &RouterOption) -> Router {
-    let user = PostgresUserRepository::new(opt.pool.clone());
-    let usecase = AuthUseCase::new(user, opt.config.jwt_secret.clone(), opt.config.jwt_duration);
+    let user = opt.repositories.user.clone();
+    let usecase = AuthUseCase::new(
+        user,
+        opt.repositories.lockout.clone(),
+        opt.mailer.clone(),
+        opt.config.lockout(),
+        opt.config.email_verification(),
+        opt.config.jwt_secret.clone(),
+        opt.config.jwt_duration,
+    );
+
+    let password_reset = PasswordResetUseCase::new(
+        opt.repositories.user.clone(),
+        opt.repositories.password_resets.clone(),
+        opt.mailer.clone(),
+        opt.config.password_reset(),
+        opt.config.jwt_secret.clone(),
+    );
 
     let state = AuthState {
         auth_usecase: Arc::new(usecase),
+        password_reset_usecase: Arc::new(password_reset),
     };
 
-    let public = Router::new()
+    // responses carrying tokens are never stored for an idempotent replay, see the api docs
+    let tokens = Router::new()
         .route("/login", post(login_with_email))
-        .route("/register", post(register))
         .route("/refresh", post(refresh_access_token));
 
-    let private = Router::new()
+    let mut public = Router::new()
+        .route("/register", post(register))
+        .route("/email/verify", get(verify_email))
+        .route("/email/resend", post(resend_verification))
+        .route("/password/forgot", post(forgot_password))
+        .route("/password/reset", post(reset_password));
+
+    let mut private = Router::new()
         .route("/whoami", get(whoami))
-        .route("/logout", delete(logout))
+        .route("/logout", delete(logout));
+
+    // idempotency keys are stored in postgres, other backends serve writes without replay
+    if let Some(pool) = opt.pool {
+        let idempotency = Arc::new(IdempotencyUseCase::new(
+            PostgresIdempotencyRepository::new(pool.clone()),
+            opt.config.idempotency_window(),
+        ));
+
+        public = public
+            .layer(middleware::from_fn(idempotency_middleware))
+            .layer(Extension(idempotency.clone()));
+        private = private
+            .layer(middleware::from_fn(idempotency_middleware))
+            .layer(Extension(idempotency));
+    }
+
+    // counted before anything else runs
+    let limit = Arc::new(RateLimit::new(opt));
+
+    let public = public
+        .merge(tokens)
+        .layer(middleware::from_fn(rate_limit_middleware))
+        .layer(Extension(limit));
+
+    let private = private
         .layer(middleware::from_fn(jwt_middleware))
         .layer(Extension(opt.config.jwt_secret.clone()));
 
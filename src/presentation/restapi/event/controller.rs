@@ -0,0 +1,111 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum::{
+    Extension,
+    extract::State,
+    http::HeaderMap,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    application::event::{dto::TodoEventResponse, usecase::REPLAY_BATCH},
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{event::router::EventState, response::ApiResponse, response::Empty},
+};
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+#[utoipa::path(
+    get,
+    path = "/todo/events",
+    params(
+        ("Last-Event-ID" = Option<i64>, Header, description = "Resume after this event id"),
+    ),
+    responses(
+        (status = 200, description = "Stream of todo changes, each `data` is a TodoEventResponse", body = TodoEventResponse, content_type = "text/event-stream"),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+    ),
+    tag = "todos",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn todo_events(
+    State(state): State<EventState>,
+    Extension(claims): Extension<JwtClaims>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let mut cursor = headers
+        .get("Last-Event-ID")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<i64>().ok());
+
+    // subscribe before replaying so nothing slips in between, duplicates are dropped by id
+    let mut receiver = state.hub.subscribe();
+
+    // the stream outlives the request, stop once the access token used to open it expires
+    let remaining = (claims.exp - chrono::Utc::now().timestamp()).max(0) as u64;
+    let expired = tokio::time::sleep(Duration::from_secs(remaining));
+
+    let stream = async_stream::stream! {
+        tokio::pin!(expired);
+        let mut replay = cursor.is_some();
+
+        loop {
+            if replay {
+                let batch = match state
+                    .event_usecase
+                    .replay(claims.sub, cursor.unwrap_or_default())
+                    .await
+                {
+                    Ok(batch) => batch,
+                    // the client reconnects with its Last-Event-ID
+                    Err(_) => break,
+                };
+
+                replay = batch.len() == REPLAY_BATCH;
+                for event in batch {
+                    cursor = Some(event.id);
+                    yield Ok::<Event, Infallible>(sse_event(&event));
+                }
+
+                continue;
+            }
+
+            tokio::select! {
+                _ = &mut expired => break,
+                received = receiver.recv() => match received {
+                    Ok(event) => {
+                        if event.user_id != claims.sub || cursor.is_some_and(|id| event.id <= id) {
+                            continue;
+                        }
+
+                        let event = TodoEventResponse::from(event.as_ref().clone());
+                        cursor = Some(event.id);
+                        yield Ok(sse_event(&event));
+                    }
+                    // fell behind the hub, the client reconnects with its Last-Event-ID and
+                    // catches up from the event log
+                    Err(RecvError::Lagged(_)) | Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    };
+
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(HEARTBEAT_INTERVAL)
+            .text("heartbeat"),
+    )
+}
+
+fn sse_event(event: &TodoEventResponse) -> Event {
+    Event::default()
+        .id(event.id.to_string())
+        .event(event.kind.as_str())
+        .json_data(event)
+        .unwrap_or_default()
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use axum::{Extension, Router, middleware, routing::get};
+use sqlx::PgPool;
+
+use crate::{
+    application::event::usecase::EventUseCase,
+    infrastructure::{
+        database::sqlx::event_repository::PostgresEventRepository, realtime::EventHub,
+    },
+    presentation::restapi::{
+        RouterOption, event::controller::todo_events, middleware::jwt_middleware,
+    },
+};
+
+#[derive(Clone)]
+pub struct EventState {
+    pub event_usecase: Arc<EventUseCase<PostgresEventRepository>>,
+    pub hub: EventHub,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let repo = PostgresEventRepository::new(pool.clone());
+    let usecase = EventUseCase::new(repo);
+
+    let state = EventState {
+        event_usecase: Arc::new(usecase),
+        hub: opt.hub.clone(),
+    };
+
+    // the route sits under `/todo` next to the todo router, so this router is merged instead of nested
+    Router::new()
+        .route("/todo/events", get(todo_events))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
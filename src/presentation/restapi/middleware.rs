@@ -1,15 +1,99 @@
+use std::{net::SocketAddr, sync::Arc};
+
 use axum::{
-    extract::{Extension, Request},
+    body::{Body, to_bytes},
+    extract::{ConnectInfo, Extension, Request},
+    http::{
+        Extensions, HeaderMap, HeaderValue, Method,
+        header::{CONTENT_TYPE, RETRY_AFTER},
+    },
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
+use sha2::{Digest, Sha256};
 
 use crate::{
-    infrastructure::security::jwt::JwtClaims, presentation::restapi::response::ApiResponse,
+    application::{
+        idempotency::{dto::StoredResponse, error::IdempotencyError, usecase::IdempotencyUseCase},
+        rate_limit::{error::RateLimitError, usecase::RateLimitUseCase},
+    },
+    domain::{rate_limit::repository::RateLimitRepository, user::repository::UserRepository},
+    infrastructure::{
+        database::{
+            memory::rate_limit_repository::InMemoryRateLimitRepository,
+            replica,
+            sqlx::{
+                idempotency_repository::PostgresIdempotencyRepository,
+                rate_limit_repository::PostgresRateLimitRepository,
+            },
+        },
+        security::jwt::JwtClaims,
+    },
+    presentation::restapi::{RouterOption, response::ApiResponse},
 };
 
+const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+const READ_YOUR_WRITES: &str = "Read-Your-Writes";
+const API_KEY: &str = "x-api-key";
+const FORWARDED_FOR: &str = "X-Forwarded-For";
+const MAX_REQUEST_BODY: usize = 2 * 1024 * 1024;
+
 pub async fn jwt_middleware(
     Extension(secret): Extension<String>,
+    Extension(users): Extension<Arc<dyn UserRepository>>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiResponse<()>> {
+    let token = request
+        .headers()
+        .get("Authorization")
+        .and_then(|val| val.to_str().ok())
+        .and_then(|data| data.strip_prefix("Bearer "))
+        .ok_or(ApiResponse::unauthorized("Authorization not found"))?;
+
+    let claims = JwtClaims::decode(token.to_string(), &secret)
+        .map_err(|_| ApiResponse::unauthorized("Authorization not found"))?;
+
+    if claims.is_revoked(users.as_ref()).await {
+        return Err(ApiResponse::unauthorized("Authorization revoked"));
+    }
+
+    request.extensions_mut().insert(claims);
+    Ok(next.run(request).await)
+}
+
+// keys operators send as `x-api-key` to the admin endpoints
+#[derive(Clone)]
+pub struct AdminApiKeys(Arc<Vec<String>>);
+
+impl AdminApiKeys {
+    pub fn new(keys: Vec<String>) -> Self {
+        Self(Arc::new(keys))
+    }
+}
+
+pub async fn admin_middleware(
+    Extension(keys): Extension<AdminApiKeys>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiResponse<()>> {
+    let key = request
+        .headers()
+        .get(API_KEY)
+        .and_then(|val| val.to_str().ok())
+        .ok_or(ApiResponse::unauthorized("Api key not found"))?;
+
+    if !keys.0.iter().any(|known| known == key) {
+        return Err(ApiResponse::unauthorized("Invalid api key"));
+    }
+
+    Ok(next.run(request).await)
+}
+
+// browsers can't set headers on a websocket handshake, so the token may also come as `?access_token=`
+pub async fn ws_jwt_middleware(
+    Extension(secret): Extension<String>,
+    Extension(users): Extension<Arc<dyn UserRepository>>,
     mut request: Request,
     next: Next,
 ) -> Result<Response, ApiResponse<()>> {
@@ -18,11 +102,247 @@ pub async fn jwt_middleware(
         .get("Authorization")
         .and_then(|val| val.to_str().ok())
         .and_then(|data| data.strip_prefix("Bearer "))
+        .or_else(|| {
+            request
+                .uri()
+                .query()
+                .into_iter()
+                .flat_map(|query| query.split('&'))
+                .find_map(|pair| pair.strip_prefix("access_token="))
+        })
         .ok_or(ApiResponse::unauthorized("Authorization not found"))?;
 
     let claims = JwtClaims::decode(token.to_string(), &secret)
         .map_err(|_| ApiResponse::unauthorized("Authorization not found"))?;
 
+    if claims.is_revoked(users.as_ref()).await {
+        return Err(ApiResponse::unauthorized("Authorization revoked"));
+    }
+
     request.extensions_mut().insert(claims);
     Ok(next.run(request).await)
 }
+
+// reads of a request may be served by a read replica until it writes, `Read-Your-Writes: true`
+// keeps all of them on the primary, e.g. for the requests right after a mutation
+pub async fn replica_middleware(request: Request, next: Next) -> Response {
+    let primary = request
+        .headers()
+        .get(READ_YOUR_WRITES)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"));
+
+    replica::scope(primary, next.run(request)).await
+}
+
+pub struct RateLimit {
+    pub usecase: RateLimitUseCase<Arc<dyn RateLimitRepository>>,
+    pub trust_forwarded_for: bool,
+}
+
+impl RateLimit {
+    // every instance shares the counters on postgres, the graphql auth mutations count against
+    // the same ones as the rest routes
+    pub fn new(opt: &RouterOption) -> Self {
+        let counters: Arc<dyn RateLimitRepository> = match opt.pool {
+            Some(pool) => Arc::new(PostgresRateLimitRepository::new(pool.clone())),
+            None => Arc::new(InMemoryRateLimitRepository::new()),
+        };
+
+        Self {
+            usecase: RateLimitUseCase::new(counters, opt.config.rate_limit()),
+            trust_forwarded_for: opt.config.trust_forwarded_for,
+        }
+    }
+
+    pub fn client_address(&self, headers: &HeaderMap, extensions: &Extensions) -> String {
+        client_address(headers, extensions, self.trust_forwarded_for)
+    }
+}
+
+// count the attempt by client address and by the `email` of a json body, over the limit the
+// request is answered with `429` and `Retry-After` without reaching the handler
+pub async fn rate_limit_middleware(
+    Extension(limit): Extension<Arc<RateLimit>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiResponse<()>> {
+    let action = request.uri().path().trim_matches('/').to_string();
+    let ip = client_ip(&request, limit.trust_forwarded_for);
+
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, MAX_REQUEST_BODY)
+        .await
+        .map_err(|_| ApiResponse::unprocessable_entity("request body is too large"))?;
+
+    let email = serde_json::from_slice::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|json| json.get("email")?.as_str().map(String::from));
+
+    match limit.usecase.attempt(&action, &ip, email.as_deref()).await {
+        Ok(()) => {
+            let mut request = Request::from_parts(parts, body.into());
+            request.extensions_mut().insert(ClientAddress(ip));
+
+            Ok(next.run(request).await)
+        }
+        Err(RateLimitError::Exceeded { retry_after }) => {
+            let mut response =
+                ApiResponse::<()>::too_many_requests("Too many attempts, try again later")
+                    .into_response();
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after.as_secs()));
+
+            Ok(response)
+        }
+        Err(RateLimitError::GeneralError) => Err(ApiResponse::general_error()),
+    }
+}
+
+// the address `rate_limit_middleware` counted the request against, for layers running after it
+#[derive(Clone)]
+pub struct ClientAddress(pub String);
+
+fn client_ip(request: &Request, trust_forwarded_for: bool) -> String {
+    client_address(request.headers(), request.extensions(), trust_forwarded_for)
+}
+
+// the proxy appends the address it saw, so only the last `X-Forwarded-For` entry is trusted
+fn client_address(
+    headers: &HeaderMap,
+    extensions: &Extensions,
+    trust_forwarded_for: bool,
+) -> String {
+    let forwarded = headers
+        .get(FORWARDED_FOR)
+        .filter(|_| trust_forwarded_for)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .map(str::trim)
+        .filter(|ip| !ip.is_empty());
+
+    match forwarded {
+        Some(ip) => ip.to_string(),
+        None => extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+    }
+}
+
+// replay the stored response when a mutating request is retried with the same `Idempotency-Key`,
+// placed after `jwt_middleware` so keys are scoped to the caller. responses are stored verbatim,
+// so routes handing out tokens must not sit behind this layer
+pub async fn idempotency_middleware(
+    Extension(idempotency): Extension<Arc<IdempotencyUseCase<PostgresIdempotencyRepository>>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiResponse<()>> {
+    let mutating = matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    );
+
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY).filter(|_| mutating) else {
+        return Ok(next.run(request).await);
+    };
+
+    let key = key
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|key| !key.is_empty() && key.len() <= 255)
+        .ok_or(ApiResponse::unprocessable_entity(
+            "Idempotency-Key must be between 1 and 255 characters",
+        ))?
+        .to_string();
+
+    // without a caller, keys are only shared by requests from the same address
+    let mut scope = match request.extensions().get::<JwtClaims>() {
+        Some(claims) => claims.sub.to_string(),
+        None => {
+            let address = match request.extensions().get::<ClientAddress>() {
+                Some(ClientAddress(ip)) => ip.clone(),
+                None => client_ip(&request, false),
+            };
+            format!("anonymous:{address}")
+        }
+    };
+    scope.truncate(64);
+
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, MAX_REQUEST_BODY)
+        .await
+        .map_err(|_| ApiResponse::unprocessable_entity("request body is too large"))?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(parts.method.as_str());
+    hasher.update(b" ");
+    hasher.update(parts.uri.path());
+    hasher.update(b"\n");
+    hasher.update(&body);
+    let fingerprint = hex::encode(hasher.finalize());
+
+    match idempotency.begin(&scope, &key, &fingerprint).await {
+        Ok(Some(stored)) => return Ok(replay(stored)),
+        Ok(None) => {}
+        Err(IdempotencyError::Mismatch) => {
+            return Err(ApiResponse::unprocessable_entity(
+                "Idempotency-Key was already used for a different request",
+            ));
+        }
+        Err(IdempotencyError::InProgress) => {
+            return Err(ApiResponse::conflict(
+                "A request with this Idempotency-Key is still in progress",
+            ));
+        }
+        Err(IdempotencyError::GeneralError) => return Err(ApiResponse::general_error()),
+    }
+
+    let response = next.run(Request::from_parts(parts, body.into())).await;
+
+    // failures on our side aren't kept, so the client can retry them with the same key
+    if response.status().is_server_error() {
+        let _ = idempotency.release(&scope, &key).await;
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let Ok(body) = to_bytes(body, usize::MAX).await else {
+        let _ = idempotency.release(&scope, &key).await;
+        return Err(ApiResponse::general_error());
+    };
+
+    let stored = StoredResponse {
+        status: parts.status.as_u16(),
+        content_type: parts
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from),
+        body: body.to_vec(),
+    };
+
+    if let Err(err) = idempotency.complete(&scope, &key, stored).await {
+        tracing::error!("failed to store idempotent response : {:?}", err);
+    }
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = stored.status.try_into().unwrap_or_default();
+
+    let headers = response.headers_mut();
+    if let Some(value) = stored
+        .content_type
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+    {
+        headers.insert(CONTENT_TYPE, value);
+    }
+    headers.insert("Idempotent-Replayed", HeaderValue::from_static("true"));
+
+    response
+}
@@ -0,0 +1,70 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    application::notification::{
+        dto::{NotificationQuery, NotificationResponse},
+        error::NotificationError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        notification::router::NotificationState,
+        response::{ApiResponse, Empty},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/notifications",
+    params(NotificationQuery),
+    responses(
+        (status = 200, description = "Delivered notifications, newest first", body = ApiResponse<Vec<NotificationResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "notifications",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_all_notification(
+    State(state): State<NotificationState>,
+    Extension(claims): Extension<JwtClaims>,
+    Query(query): Query<NotificationQuery>,
+) -> impl IntoResponse {
+    match state.notification_usecase.find_all(claims.sub, query).await {
+        Ok(data) => ApiResponse::<Vec<NotificationResponse>>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/notifications/{id}/read",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the notification"),
+    ),
+    responses(
+        (status = 200, description = "Notification marked as read", body = ApiResponse<NotificationResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Notification not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "notifications",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn mark_notification_read(
+    State(state): State<NotificationState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.notification_usecase.mark_read(claims.sub, id).await {
+        Ok(data) => ApiResponse::<NotificationResponse>::success(Some(data)),
+        Err(NotificationError::NotFound) => ApiResponse::not_found("notification not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
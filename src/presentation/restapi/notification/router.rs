@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{get, patch},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::notification::usecase::NotificationUseCase,
+    infrastructure::database::sqlx::notification_repository::PostgresNotificationRepository,
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        notification::controller::{find_all_notification, mark_notification_read},
+    },
+};
+
+#[derive(Clone)]
+pub struct NotificationState {
+    pub notification_usecase: Arc<NotificationUseCase<PostgresNotificationRepository>>,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let repo = PostgresNotificationRepository::new(pool.clone());
+    let usecase = NotificationUseCase::new(repo);
+
+    let state = NotificationState {
+        notification_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/", get(find_all_notification))
+        .route("/{id}/read", patch(mark_notification_read))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
@@ -0,0 +1,3 @@
+pub mod controller;
+pub mod message;
+pub mod router;
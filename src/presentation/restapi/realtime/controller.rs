@@ -0,0 +1,216 @@
+use std::time::Duration;
+
+use axum::{
+    Extension,
+    extract::{
+        State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    response::IntoResponse,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    application::{
+        event::dto::TodoEventResponse, todo::error::TodoError, workflow::dto::TransitionRequest,
+        workflow::error::WorkflowError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        realtime::{
+            message::{ClientMessage, ServerMessage, Subscription},
+            router::RealtimeState,
+        },
+        response::{ApiResponse, Empty},
+    },
+};
+
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+#[utoipa::path(
+    get,
+    path = "/realtime",
+    params(
+        ("access_token" = Option<String>, Query, description = "Access token for clients that can't send the Authorization header"),
+    ),
+    responses(
+        (status = 101, description = "Switching to the websocket protocol, frames are JSON messages tagged by `type`"),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+    ),
+    tag = "realtime",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn realtime(
+    State(state): State<RealtimeState>,
+    Extension(claims): Extension<JwtClaims>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| session(state, claims, socket))
+}
+
+async fn session(state: RealtimeState, claims: JwtClaims, mut socket: WebSocket) {
+    let mut receiver = state.hub.subscribe();
+    let mut subscription = Subscription::default();
+
+    // the socket outlives the request, close it once the access token used to open it expires
+    let remaining = (claims.exp - chrono::Utc::now().timestamp()).max(0) as u64;
+    let expired = tokio::time::sleep(Duration::from_secs(remaining));
+    tokio::pin!(expired);
+
+    let mut ping = tokio::time::interval(PING_INTERVAL);
+    ping.reset();
+
+    loop {
+        let reply = tokio::select! {
+            _ = &mut expired => {
+                let _ = socket
+                    .send(Message::Close(Some(CloseFrame {
+                        code: close_code::POLICY,
+                        reason: "token expired".into(),
+                    })))
+                    .await;
+                break;
+            }
+            _ = ping.tick() => {
+                if socket.send(Message::Ping(Default::default())).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+            received = receiver.recv() => match received {
+                Ok(event) => {
+                    if event.user_id != claims.sub || !subscription.accepts(&event) {
+                        continue;
+                    }
+
+                    ServerMessage::Event {
+                        event: TodoEventResponse::from(event.as_ref().clone()),
+                    }
+                }
+                Err(RecvError::Lagged(missed)) => ServerMessage::Lagged { missed },
+                Err(RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    command(&state, &claims, &mut subscription, text.as_str()).await
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // pings are answered by axum, pongs and binary frames carry nothing for us
+                Some(Ok(_)) => continue,
+            },
+        };
+
+        let Ok(reply) = serde_json::to_string(&reply) else {
+            continue;
+        };
+
+        if socket.send(Message::Text(reply.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn command(
+    state: &RealtimeState,
+    claims: &JwtClaims,
+    subscription: &mut Subscription,
+    text: &str,
+) -> ServerMessage {
+    let message = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) => message,
+        Err(err) => {
+            return ServerMessage::Error {
+                request_id: None,
+                message: err.to_string(),
+            };
+        }
+    };
+
+    match message {
+        ClientMessage::Subscribe { lists } => {
+            let known = match state.workflow_usecase.find_states(claims.sub).await {
+                Ok(states) => states,
+                Err(_) => return workflow_error(None, WorkflowError::GeneralError),
+            };
+
+            if lists
+                .iter()
+                .any(|list| !known.iter().any(|state| &state.id == list))
+            {
+                return workflow_error(None, WorkflowError::NotFound);
+            }
+
+            let todos = match state.todo_usecase.find_all(claims.sub).await {
+                Ok(todos) => todos,
+                Err(_) => return workflow_error(None, WorkflowError::GeneralError),
+            };
+
+            subscription.track(todos.into_iter().map(|todo| (todo.id, todo.state_id)));
+            subscription.subscribe(lists);
+            subscription.message()
+        }
+        ClientMessage::Unsubscribe { lists } => {
+            subscription.unsubscribe(lists);
+            subscription.message()
+        }
+        ClientMessage::Toggle {
+            request_id,
+            todo_id,
+        } => match state.todo_usecase.toggle_todo(claims.sub, todo_id).await {
+            Ok(_) => ServerMessage::Ack {
+                request_id,
+                lists: None,
+            },
+            Err(TodoError::NotFound) => ServerMessage::Error {
+                request_id,
+                message: "todo not found".to_string(),
+            },
+            Err(_) => ServerMessage::Error {
+                request_id,
+                message: "failed to toggle todo".to_string(),
+            },
+        },
+        ClientMessage::Move {
+            request_id,
+            todo_id,
+            list_id,
+        } => match state
+            .workflow_usecase
+            .transition(claims.sub, todo_id, TransitionRequest { state_id: list_id })
+            .await
+        {
+            Ok(_) => ServerMessage::Ack {
+                request_id,
+                lists: None,
+            },
+            Err(err) => workflow_error(request_id, err),
+        },
+        ClientMessage::Reorder { request_id, lists } => {
+            match state
+                .workflow_usecase
+                .reorder_states(claims.sub, lists)
+                .await
+            {
+                Ok(lists) => ServerMessage::Ack {
+                    request_id,
+                    lists: Some(lists),
+                },
+                Err(err) => workflow_error(request_id, err),
+            }
+        }
+    }
+}
+
+fn workflow_error(request_id: Option<String>, err: WorkflowError) -> ServerMessage {
+    let message = match err {
+        WorkflowError::NotFound => "list or todo not found",
+        WorkflowError::Conflict => "todo is already in that list",
+        WorkflowError::GeneralError => "something went wrong",
+    };
+
+    ServerMessage::Error {
+        request_id,
+        message: message.to_string(),
+    }
+}
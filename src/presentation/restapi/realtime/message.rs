@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    application::{event::dto::TodoEventResponse, workflow::dto::WorkflowStateResponse},
+    domain::event::model::{TodoEvent, TodoEventKind},
+};
+
+/// Frames sent by the client, lists are the caller's workflow states
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// an empty `lists` subscribes to every todo
+    Subscribe {
+        #[serde(default)]
+        lists: Vec<Uuid>,
+    },
+    /// an empty `lists` drops every subscription
+    Unsubscribe {
+        #[serde(default)]
+        lists: Vec<Uuid>,
+    },
+    Toggle {
+        #[serde(default)]
+        request_id: Option<String>,
+        todo_id: Uuid,
+    },
+    /// move a todo into another list
+    Move {
+        #[serde(default)]
+        request_id: Option<String>,
+        todo_id: Uuid,
+        list_id: Uuid,
+    },
+    /// reorder the lists of the board
+    Reorder {
+        #[serde(default)]
+        request_id: Option<String>,
+        lists: Vec<Uuid>,
+    },
+}
+
+/// Frames sent by the server
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Event {
+        event: TodoEventResponse,
+    },
+    Subscribed {
+        all: bool,
+        lists: Vec<Uuid>,
+    },
+    Ack {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lists: Option<Vec<WorkflowStateResponse>>,
+    },
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        message: String,
+    },
+    /// events were dropped for this socket, the client should refetch
+    Lagged {
+        missed: u64,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct Subscription {
+    all: bool,
+    lists: HashSet<Uuid>,
+    // last known list of each todo, so moving out of a list still reaches its subscribers
+    seen: HashMap<Uuid, Option<Uuid>>,
+}
+
+impl Subscription {
+    // where each todo currently sits, taken when subscribing
+    pub fn track(&mut self, todos: impl IntoIterator<Item = (Uuid, Option<Uuid>)>) {
+        self.seen.extend(todos);
+    }
+
+    pub fn subscribe(&mut self, lists: Vec<Uuid>) {
+        if lists.is_empty() {
+            self.all = true;
+        }
+
+        self.lists.extend(lists);
+    }
+
+    pub fn unsubscribe(&mut self, lists: Vec<Uuid>) {
+        if lists.is_empty() {
+            self.all = false;
+            self.lists.clear();
+        }
+
+        for list in lists {
+            self.lists.remove(&list);
+        }
+    }
+
+    pub fn message(&self) -> ServerMessage {
+        ServerMessage::Subscribed {
+            all: self.all,
+            lists: self.lists.iter().copied().collect(),
+        }
+    }
+
+    pub fn accepts(&mut self, event: &TodoEvent) -> bool {
+        let list = event
+            .payload
+            .get("state_id")
+            .and_then(|value| value.as_str())
+            .and_then(|value| Uuid::parse_str(value).ok());
+
+        let previous = match event.kind {
+            TodoEventKind::Deleted => self.seen.remove(&event.todo_id).flatten(),
+            _ => self.seen.insert(event.todo_id, list).flatten(),
+        };
+
+        self.all
+            || [list, previous]
+                .into_iter()
+                .flatten()
+                .any(|list| self.lists.contains(&list))
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use axum::{Extension, Router, middleware, routing::get};
+use sqlx::PgPool;
+
+use crate::{
+    application::{todo::usecase::TodoUseCase, workflow::usecase::WorkflowUseCase},
+    infrastructure::{
+        database::sqlx::workflow_repository::PostgresWorkflowRepository, realtime::EventHub,
+    },
+    presentation::restapi::{
+        RouterOption, middleware::ws_jwt_middleware, realtime::controller::realtime,
+        todo::router::TodoService, workflow::router::WorkflowService,
+    },
+};
+
+#[derive(Clone)]
+pub struct RealtimeState {
+    pub todo_usecase: Arc<TodoService>,
+    pub workflow_usecase: Arc<WorkflowService>,
+    pub hub: EventHub,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let todo = TodoUseCase::new(
+        opt.repositories.todo.clone(),
+        opt.repositories.transactions.clone(),
+    );
+    let workflow = WorkflowUseCase::new(
+        PostgresWorkflowRepository::new(pool.clone()),
+        opt.repositories.todo.clone(),
+        opt.repositories.transactions.clone(),
+    );
+
+    let state = RealtimeState {
+        todo_usecase: Arc::new(todo),
+        workflow_usecase: Arc::new(workflow),
+        hub: opt.hub.clone(),
+    };
+
+    Router::new()
+        .route("/", get(realtime))
+        .layer(middleware::from_fn(ws_jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
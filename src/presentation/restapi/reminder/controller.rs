@@ -0,0 +1,120 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::reminder::{
+        dto::{CreateReminderRequest, ReminderResponse},
+        error::ReminderError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        reminder::router::ReminderState,
+        response::{ApiResponse, Empty},
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/todo/{id}/reminders",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item to remind about"),
+    ),
+    request_body = CreateReminderRequest,
+    responses(
+        (status = 200, description = "Reminder scheduled", body = ApiResponse<ReminderResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 422, description = "Invalid trigger or the todo has no due date", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "reminders",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn create_reminder(
+    State(state): State<ReminderState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<CreateReminderRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .reminder_usecase
+        .create_reminder(claims.sub, id, dto)
+        .await
+    {
+        Ok(data) => ApiResponse::<ReminderResponse>::success(Some(data)),
+        Err(ReminderError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(ReminderError::InvalidTrigger) => {
+            ApiResponse::unprocessable_entity("set exactly one of remind_at or minutes_before_due")
+        }
+        Err(ReminderError::MissingDueDate) => {
+            ApiResponse::unprocessable_entity("todo has no due date")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/todo/{id}/reminders",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item"),
+    ),
+    responses(
+        (status = 200, description = "Reminders of the todo", body = ApiResponse<Vec<ReminderResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "reminders",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_reminders(
+    State(state): State<ReminderState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.reminder_usecase.find_reminders(claims.sub, id).await {
+        Ok(data) => ApiResponse::<Vec<ReminderResponse>>::success(Some(data)),
+        Err(ReminderError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/reminders/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the reminder to be deleted"),
+    ),
+    responses(
+        (status = 200, description = "Reminder deleted", body = ApiResponse<Empty>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Reminder not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "reminders",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn delete_reminder(
+    State(state): State<ReminderState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.reminder_usecase.delete_reminder(claims.sub, id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(ReminderError::NotFound) => ApiResponse::not_found("reminder not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
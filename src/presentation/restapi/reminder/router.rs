@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{delete, get, post},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::reminder::usecase::ReminderUseCase,
+    infrastructure::database::sqlx::{
+        reminder_repository::PostgresReminderRepository, todo_repository::PostgresTodoRepository,
+    },
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        reminder::controller::{create_reminder, delete_reminder, find_reminders},
+    },
+};
+
+#[derive(Clone)]
+pub struct ReminderState {
+    pub reminder_usecase: Arc<ReminderUseCase<PostgresReminderRepository, PostgresTodoRepository>>,
+}
+
+// routes live under both `/todo/{id}` and `/reminders`, so this router is merged instead of nested
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let reminder = PostgresReminderRepository::new(pool.clone());
+    let todo = PostgresTodoRepository::new(pool.clone());
+    let usecase = ReminderUseCase::new(reminder, todo);
+
+    let state = ReminderState {
+        reminder_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/todo/{id}/reminders", post(create_reminder))
+        .route("/todo/{id}/reminders", get(find_reminders))
+        .route("/reminders/{id}", delete(delete_reminder))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
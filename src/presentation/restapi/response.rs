@@ -54,6 +54,15 @@ where
         }
     }
 
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "40300".to_string(),
+            message: message.into(),
+            data: None,
+            status: StatusCode::FORBIDDEN,
+        }
+    }
+
     pub fn unprocessable_entity(message: impl Into<String>) -> Self {
         Self {
             code: "42200".to_string(),
@@ -81,6 +90,24 @@ where
         }
     }
 
+    pub fn locked(message: impl Into<String>) -> Self {
+        Self {
+            code: "42300".to_string(),
+            message: message.into(),
+            data: None,
+            status: StatusCode::LOCKED,
+        }
+    }
+
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        Self {
+            code: "42900".to_string(),
+            message: message.into(),
+            data: None,
+            status: StatusCode::TOO_MANY_REQUESTS,
+        }
+    }
+
     pub fn general_error() -> Self {
         Self {
             code: "50000".to_string(),
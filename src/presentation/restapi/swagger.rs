@@ -1,12 +1,24 @@
 use utoipa::Modify;
 use utoipa::OpenApi;
+use utoipa::openapi::security::ApiKey;
+use utoipa::openapi::security::ApiKeyValue;
 use utoipa::openapi::security::HttpAuthScheme;
 use utoipa::openapi::security::HttpBuilder;
 use utoipa::openapi::security::SecurityScheme;
 
+use crate::presentation::restapi::admin;
 use crate::presentation::restapi::auth;
+use crate::presentation::restapi::event;
+use crate::presentation::restapi::notification;
+use crate::presentation::restapi::realtime;
+use crate::presentation::restapi::reminder;
+use crate::presentation::restapi::sync;
+use crate::presentation::restapi::template;
+use crate::presentation::restapi::time_entry;
 use crate::presentation::restapi::todo;
 use crate::presentation::restapi::user;
+use crate::presentation::restapi::webhook;
+use crate::presentation::restapi::workflow;
 
 struct JsonWebToken;
 
@@ -26,6 +38,19 @@ impl Modify for JsonWebToken {
     }
 }
 
+struct AdminApiKey;
+
+impl Modify for AdminApiKey {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "api_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+            )
+        }
+    }
+}
+
 #[derive(Debug, OpenApi)]
 #[openapi(
     servers((url = "/api/v1", description = "Base API v1")), 
@@ -35,10 +60,18 @@ impl Modify for JsonWebToken {
         auth::controller::refresh_access_token,
         auth::controller::whoami,
         auth::controller::logout,
+        auth::controller::verify_email,
+        auth::controller::resend_verification,
+        auth::controller::forgot_password,
+        auth::controller::reset_password,
+
+        admin::controller::unlock_user,
 
         user::controller::delete_user,
         user::controller::find_all_user,
         user::controller::find_user_by_id,
+        user::controller::update_profile,
+        user::controller::change_password,
 
         todo::controller::create_todo,
         todo::controller::update_todo,
@@ -46,7 +79,50 @@ impl Modify for JsonWebToken {
         todo::controller::find_all_todo,
         todo::controller::find_todo_by_id,
         todo::controller::toggle_todo,
+        todo::controller::snooze_todo,
+        event::controller::todo_events,
+        realtime::controller::realtime,
+
+        sync::controller::pull_changes,
+        sync::controller::push_changes,
+
+        template::controller::create_template,
+        template::controller::update_template,
+        template::controller::delete_template,
+        template::controller::find_all_template,
+        template::controller::find_template_by_id,
+        template::controller::instantiate_template,
+
+        time_entry::controller::start_timer,
+        time_entry::controller::stop_timer,
+        time_entry::controller::find_time_entries,
+        time_entry::controller::create_time_entry,
+        time_entry::controller::update_time_entry,
+        time_entry::controller::delete_time_entry,
+        time_entry::controller::time_report,
+
+        workflow::controller::create_state,
+        workflow::controller::find_all_state,
+        workflow::controller::update_state,
+        workflow::controller::delete_state,
+        workflow::controller::board,
+        workflow::controller::transition_todo,
+        workflow::controller::find_transitions,
+
+        reminder::controller::create_reminder,
+        reminder::controller::find_reminders,
+        reminder::controller::delete_reminder,
+
+        notification::controller::find_all_notification,
+        notification::controller::mark_notification_read,
+
+        webhook::controller::create_webhook,
+        webhook::controller::find_all_webhook,
+        webhook::controller::update_webhook,
+        webhook::controller::delete_webhook,
+        webhook::controller::find_deliveries,
+        webhook::controller::redeliver,
     ),
-    modifiers(&JsonWebToken)
+    modifiers(&JsonWebToken, &AdminApiKey)
 )]
 pub struct ApiDoc;
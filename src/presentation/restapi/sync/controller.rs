@@ -0,0 +1,68 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use validator::Validate;
+
+use crate::{
+    application::sync::dto::{SyncQuery, SyncRequest, SyncResponse, SyncResult},
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        response::{ApiResponse, Empty},
+        sync::router::SyncState,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/todo/sync",
+    params(SyncQuery),
+    responses(
+        (status = 200, description = "Changes since the cursor, deleted todos come as tombstones", body = ApiResponse<SyncResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "sync",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn pull_changes(
+    State(state): State<SyncState>,
+    Extension(claims): Extension<JwtClaims>,
+    Query(query): Query<SyncQuery>,
+) -> impl IntoResponse {
+    match state.sync_usecase.pull(claims.sub, query.since).await {
+        Ok(data) => ApiResponse::<SyncResponse>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/todo/sync",
+    request_body = SyncRequest,
+    responses(
+        (status = 200, description = "Outcome of every mutation, in request order", body = ApiResponse<SyncResult>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "sync",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn push_changes(
+    State(state): State<SyncState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<SyncRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state.sync_usecase.push(claims.sub, dto).await {
+        Ok(data) => ApiResponse::<SyncResult>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use axum::{Extension, Router, middleware, routing::get};
+
+use crate::{
+    application::sync::usecase::SyncUseCase,
+    domain::{
+        event::repository::EventRepository, shared::transaction::TransactionManager,
+        todo::repository::TodoRepository,
+    },
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        sync::controller::{pull_changes, push_changes},
+    },
+};
+
+pub type SyncService =
+    SyncUseCase<Arc<dyn TodoRepository>, Arc<dyn EventRepository>, Arc<dyn TransactionManager>>;
+
+#[derive(Clone)]
+pub struct SyncState {
+    pub sync_usecase: Arc<SyncService>,
+}
+
+// the route sits under `/todo` next to the todo router, so this router is merged instead of nested
+pub fn setup(opt: &RouterOption) -> Router {
+    let todo = opt.repositories.todo.clone();
+    let event = opt.repositories.event.clone();
+    let transactions = opt.repositories.transactions.clone();
+    let usecase = SyncUseCase::new(todo, event, transactions);
+
+    let state = SyncState {
+        sync_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/todo/sync", get(pull_changes).post(push_changes))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
@@ -0,0 +1,209 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        template::{
+            dto::{
+                CreateTemplateRequest, InstantiateTemplateRequest, TemplateResponse,
+                UpdateTemplateRequest,
+            },
+            error::TemplateError,
+        },
+        todo::dto::TodoResponse,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        response::{ApiResponse, Empty},
+        template::router::TemplateState,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/templates",
+    request_body = CreateTemplateRequest,
+    responses(
+        (status = 200, description = "Template created successfully", body = ApiResponse<TemplateResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn create_template(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<CreateTemplateRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .template_usecase
+        .create_template(claims.sub, dto)
+        .await
+    {
+        Ok(template) => ApiResponse::<TemplateResponse>::success(Some(template)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the template"),
+    ),
+    request_body = UpdateTemplateRequest,
+    responses(
+        (status = 200, description = "Template updated successfully", body = ApiResponse<TemplateResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Template not found", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn update_template(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<UpdateTemplateRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .template_usecase
+        .update_template(claims.sub, id, dto)
+        .await
+    {
+        Ok(template) => ApiResponse::<TemplateResponse>::success(Some(template)),
+        Err(TemplateError::NotFound) => ApiResponse::not_found("template not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the template to be deleted"),
+    ),
+    responses(
+        (status = 200, description = "Template deleted successfully", body = ApiResponse<Empty>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Template not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn delete_template(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.template_usecase.delete_template(claims.sub, id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(TemplateError::NotFound) => ApiResponse::not_found("template not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/templates",
+    responses(
+        (status = 200, description = "List of templates retrieved successfully", body = ApiResponse<Vec<TemplateResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_all_template(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+) -> impl IntoResponse {
+    match state.template_usecase.find_all(claims.sub).await {
+        Ok(templates) => ApiResponse::<Vec<TemplateResponse>>::success(Some(templates)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/templates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the template"),
+    ),
+    responses(
+        (status = 200, description = "Template retrieved successfully", body = ApiResponse<TemplateResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Template not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_template_by_id(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.template_usecase.find_by_id(claims.sub, id).await {
+        Ok(Some(template)) => ApiResponse::<TemplateResponse>::success(Some(template)),
+        Ok(None) | Err(TemplateError::NotFound) => ApiResponse::not_found("template not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/templates/{id}/instantiate",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the template to instantiate"),
+    ),
+    request_body = InstantiateTemplateRequest,
+    responses(
+        (status = 200, description = "Todos created from the template", body = ApiResponse<Vec<TodoResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Template not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "templates",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn instantiate_template(
+    State(state): State<TemplateState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<InstantiateTemplateRequest>,
+) -> impl IntoResponse {
+    match state
+        .template_usecase
+        .instantiate(claims.sub, id, dto)
+        .await
+    {
+        Ok(todos) => ApiResponse::<Vec<TodoResponse>>::success(Some(todos)),
+        Err(TemplateError::NotFound) => ApiResponse::not_found("template not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{delete, get, post, put},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::template::usecase::TemplateUseCase,
+    domain::shared::transaction::TransactionManager,
+    infrastructure::database::sqlx::template_repository::PostgresTemplateRepository,
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        template::controller::{
+            create_template, delete_template, find_all_template, find_template_by_id,
+            instantiate_template, update_template,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct TemplateState {
+    pub template_usecase:
+        Arc<TemplateUseCase<PostgresTemplateRepository, Arc<dyn TransactionManager>>>,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let template = PostgresTemplateRepository::new(pool.clone());
+    let usecase = TemplateUseCase::new(template, opt.repositories.transactions.clone());
+
+    let state = TemplateState {
+        template_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/", post(create_template))
+        .route("/", get(find_all_template))
+        .route("/{id}", put(update_template))
+        .route("/{id}", delete(delete_template))
+        .route("/{id}", get(find_template_by_id))
+        .route("/{id}/instantiate", post(instantiate_template))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
@@ -0,0 +1,264 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::time_entry::{
+        dto::{
+            CreateTimeEntryRequest, StartTimerRequest, TimeEntryResponse, TimeReportQuery,
+            TimeReportResponse, UpdateTimeEntryRequest,
+        },
+        error::TimeEntryError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        response::{ApiResponse, Empty},
+        time_entry::router::TimeEntryState,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/todo/{id}/timer/start",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item to track"),
+    ),
+    request_body = StartTimerRequest,
+    responses(
+        (status = 200, description = "Timer started", body = ApiResponse<TimeEntryResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 409, description = "Another timer is already running", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn start_timer(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<StartTimerRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .time_entry_usecase
+        .start_timer(claims.sub, id, dto)
+        .await
+    {
+        Ok(entry) => ApiResponse::<TimeEntryResponse>::success(Some(entry)),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(TimeEntryError::TimerRunning) => ApiResponse::conflict("a timer is already running"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/todo/{id}/timer/stop",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the tracked todo item"),
+    ),
+    responses(
+        (status = 200, description = "Timer stopped", body = ApiResponse<TimeEntryResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "No running timer for this todo", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn stop_timer(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.time_entry_usecase.stop_timer(claims.sub, id).await {
+        Ok(entry) => ApiResponse::<TimeEntryResponse>::success(Some(entry)),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("no running timer for this todo"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/todo/{id}/time-entries",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item"),
+    ),
+    responses(
+        (status = 200, description = "Time entries of the todo", body = ApiResponse<Vec<TimeEntryResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_time_entries(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.time_entry_usecase.find_by_todo(claims.sub, id).await {
+        Ok(entries) => ApiResponse::<Vec<TimeEntryResponse>>::success(Some(entries)),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/todo/{id}/time-entries",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item"),
+    ),
+    request_body = CreateTimeEntryRequest,
+    responses(
+        (status = 200, description = "Time entry recorded", body = ApiResponse<TimeEntryResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn create_time_entry(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<CreateTimeEntryRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .time_entry_usecase
+        .create_entry(claims.sub, id, dto)
+        .await
+    {
+        Ok(entry) => ApiResponse::<TimeEntryResponse>::success(Some(entry)),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(TimeEntryError::InvalidRange) => {
+            ApiResponse::unprocessable_entity("ended_at must be after started_at")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/time-entries/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the time entry"),
+    ),
+    request_body = UpdateTimeEntryRequest,
+    responses(
+        (status = 200, description = "Time entry updated", body = ApiResponse<TimeEntryResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Time entry not found", body = ApiResponse<Empty>),
+        (status = 409, description = "Another timer is already running", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn update_time_entry(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<UpdateTimeEntryRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .time_entry_usecase
+        .update_entry(claims.sub, id, dto)
+        .await
+    {
+        Ok(entry) => ApiResponse::<TimeEntryResponse>::success(Some(entry)),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("time entry not found"),
+        Err(TimeEntryError::TimerRunning) => ApiResponse::conflict("a timer is already running"),
+        Err(TimeEntryError::InvalidRange) => {
+            ApiResponse::unprocessable_entity("ended_at must be after started_at")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/time-entries/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the time entry to be deleted"),
+    ),
+    responses(
+        (status = 200, description = "Time entry deleted", body = ApiResponse<Empty>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Time entry not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn delete_time_entry(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.time_entry_usecase.delete_entry(claims.sub, id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(TimeEntryError::NotFound) => ApiResponse::not_found("time entry not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/time-entries/report",
+    params(TimeReportQuery),
+    responses(
+        (status = 200, description = "Tracked time aggregated by day and tag", body = ApiResponse<TimeReportResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 422, description = "Invalid date range", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "time entries",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn time_report(
+    State(state): State<TimeEntryState>,
+    Extension(claims): Extension<JwtClaims>,
+    Query(query): Query<TimeReportQuery>,
+) -> impl IntoResponse {
+    match state
+        .time_entry_usecase
+        .report(claims.sub, query.from, query.to)
+        .await
+    {
+        Ok(report) => ApiResponse::<TimeReportResponse>::success(Some(report)),
+        Err(TimeEntryError::InvalidRange) => {
+            ApiResponse::unprocessable_entity("range must be positive and at most 366 days")
+        }
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{delete, get, post, put},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::time_entry::usecase::TimeEntryUseCase,
+    domain::time_entry::repository::TimeEntryRepository,
+    infrastructure::{
+        cache::time_entry_repository::CachedTimeEntryRepository,
+        database::sqlx::{
+            time_entry_repository::PostgresTimeEntryRepository,
+            todo_repository::PostgresTodoRepository,
+        },
+    },
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        time_entry::controller::{
+            create_time_entry, delete_time_entry, find_time_entries, start_timer, stop_timer,
+            time_report, update_time_entry,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct TimeEntryState {
+    pub time_entry_usecase:
+        Arc<TimeEntryUseCase<Arc<dyn TimeEntryRepository>, PostgresTodoRepository>>,
+}
+
+// routes live under both `/todo/{id}` and `/time-entries`, so this router is merged instead of nested
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    // tracked seconds come with the cached todos, so writes have to invalidate them
+    let time_entry: Arc<dyn TimeEntryRepository> = match opt.cache {
+        Some(cache) => Arc::new(CachedTimeEntryRepository::new(
+            PostgresTimeEntryRepository::new(pool.clone()),
+            cache.clone(),
+        )),
+        None => Arc::new(PostgresTimeEntryRepository::new(pool.clone())),
+    };
+    let todo = PostgresTodoRepository::new(pool.clone());
+    let usecase = TimeEntryUseCase::new(time_entry, todo);
+
+    let state = TimeEntryState {
+        time_entry_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/todo/{id}/timer/start", post(start_timer))
+        .route("/todo/{id}/timer/stop", post(stop_timer))
+        .route("/todo/{id}/time-entries", get(find_time_entries))
+        .route("/todo/{id}/time-entries", post(create_time_entry))
+        .route("/time-entries/report", get(time_report))
+        .route("/time-entries/{id}", put(update_time_entry))
+        .route("/time-entries/{id}", delete(delete_time_entry))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
@@ -8,7 +8,7 @@ use validator::Validate;
 
 use crate::{
     application::todo::{
-        dto::{CreateTodoRequest, TodoResponse, UpdateTodoRequest},
+        dto::{CreateTodoRequest, SnoozeTodoRequest, TodoResponse, UpdateTodoRequest},
         error::TodoError,
     },
     infrastructure::security::jwt::JwtClaims,
@@ -23,7 +23,7 @@ use crate::{
     path = "/todos",
     request_body = CreateTodoRequest,
     responses(
-        (status = 200, description = "Todo created successfully", body = ApiResponse<Empty>),
+        (status = 200, description = "Todo created successfully", body = ApiResponse<TodoResponse>),
         (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
         (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
     ),
@@ -37,11 +37,11 @@ pub async fn create_todo(
     Json(dto): Json<CreateTodoRequest>,
 ) -> impl IntoResponse {
     if let Err(err) = dto.validate() {
-        return ApiResponse::<Empty>::unprocessable_entity(err.to_string());
+        return ApiResponse::<TodoResponse>::unprocessable_entity(err.to_string());
     }
 
     match state.todo_usecase.create_todo(claims.sub, dto).await {
-        Ok(_) => ApiResponse::success(None),
+        Ok(todo) => ApiResponse::success(Some(todo)),
         Err(_) => ApiResponse::general_error(),
     }
 }
@@ -54,7 +54,7 @@ pub async fn create_todo(
     ),
     request_body = UpdateTodoRequest,
     responses(
-        (status = 200, description = "Todo updated successfully", body = ApiResponse<Empty>),
+        (status = 200, description = "Todo updated successfully", body = ApiResponse<TodoResponse>),
         (status = 400, description = "Validation error in request body", body = ApiResponse<Empty>),
         (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
         (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
@@ -76,7 +76,7 @@ pub async fn update_todo(
     }
 
     match state.todo_usecase.update_todo(claims.sub, id, dto).await {
-        Ok(_) => ApiResponse::<Empty>::success(None),
+        Ok(todo) => ApiResponse::<TodoResponse>::success(Some(todo)),
         Err(_) => ApiResponse::general_error(),
     }
 }
@@ -188,3 +188,33 @@ pub async fn toggle_todo(
         Err(_) => ApiResponse::general_error(),
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/todos/{id}/snooze",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item to snooze"),
+    ),
+    request_body = SnoozeTodoRequest,
+    responses(
+        (status = 200, description = "Todo hidden from the listing until the given time", body = ApiResponse<TodoResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "todos",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn snooze_todo(
+    State(state): State<TodoState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<SnoozeTodoRequest>,
+) -> impl IntoResponse {
+    match state.todo_usecase.snooze_todo(claims.sub, id, dto).await {
+        Ok(data) => ApiResponse::<TodoResponse>::success(Some(data)),
+        Err(TodoError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -6,37 +6,60 @@ use axum::{
 };
 
 use crate::{
-    application::todo::usecase::TodoUseCase,
-    infrastructure::database::sqlx::todo_repository::PostgresTodoRepository,
+    application::{idempotency::usecase::IdempotencyUseCase, todo::usecase::TodoUseCase},
+    domain::{shared::transaction::TransactionManager, todo::repository::TodoRepository},
+    infrastructure::database::sqlx::idempotency_repository::PostgresIdempotencyRepository,
     presentation::restapi::{
         RouterOption,
-        middleware::jwt_middleware,
+        middleware::{idempotency_middleware, jwt_middleware},
         todo::controller::{
-            create_todo, delete_todo, find_all_todo, find_todo_by_id, toggle_todo, update_todo,
+            create_todo, delete_todo, find_all_todo, find_todo_by_id, snooze_todo, toggle_todo,
+            update_todo,
         },
     },
 };
 
+pub type TodoService = TodoUseCase<Arc<dyn TodoRepository>, Arc<dyn TransactionManager>>;
+
 #[derive(Clone)]
 pub struct TodoState {
-    pub todo_usecase: Arc<TodoUseCase<PostgresTodoRepository>>,
+    pub todo_usecase: Arc<TodoService>,
 }
 
 pub fn setup(opt: &RouterOption) -> Router {
-    let repo = PostgresTodoRepository::new(opt.pool.clone());
-    let usecase = TodoUseCase::new(repo);
+    let todo = opt.repositories.todo.clone();
+    let transactions = opt.repositories.transactions.clone();
+    let usecase = TodoUseCase::new(todo, transactions);
 
     let state = TodoState {
         todo_usecase: Arc::new(usecase),
     };
 
-    Router::new()
+    let router = Router::new()
         .route("/", post(create_todo))
         .route("/", get(find_all_todo))
         .route("/{id}", put(update_todo))
         .route("/{id}", delete(delete_todo))
         .route("/{id}", get(find_todo_by_id))
         .route("/{id}/toggle", patch(toggle_todo))
+        .route("/{id}/snooze", post(snooze_todo));
+
+    // idempotency keys are stored in postgres, other backends serve writes without replay
+    let router = match opt.pool {
+        Some(pool) => {
+            let idempotency = IdempotencyUseCase::new(
+                PostgresIdempotencyRepository::new(pool.clone()),
+                opt.config.idempotency_window(),
+            );
+
+            router
+                .layer(middleware::from_fn(idempotency_middleware))
+                .layer(Extension(Arc::new(idempotency)))
+        }
+        None => router,
+    };
+
+    router
         .layer(middleware::from_fn(jwt_middleware))
         .layer(Extension(opt.config.jwt_secret.clone()))
         .with_state(state)
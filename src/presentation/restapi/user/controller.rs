@@ -1,11 +1,17 @@
 use axum::{
+    Extension, Json,
     extract::{Path, State},
     response::IntoResponse,
 };
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
-    application::user::{dto::UserResponse, error::UserError},
+    application::user::{
+        dto::{ChangePasswordRequest, ChangePasswordResponse, UpdateProfileRequest, UserResponse},
+        error::UserError,
+    },
+    infrastructure::security::jwt::JwtClaims,
     presentation::restapi::{
         response::{ApiResponse, Empty},
         user::router::UserState,
@@ -83,3 +89,71 @@ pub async fn find_user_by_id(
         Err(_) => ApiResponse::general_error(),
     }
 }
+
+#[utoipa::path(
+    patch,
+    path = "/user/me",
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated, a new email is sent a verification link", body = ApiResponse<UserResponse>),
+        (status = 404, description = "User not found", body = ApiResponse<Empty>),
+        (status = 409, description = "Email already registered", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "users",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn update_profile(
+    State(state): State<UserState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<UpdateProfileRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::<UserResponse>::unprocessable_entity(err.to_string());
+    }
+
+    match state.user_usecase.update_profile(claims.sub, dto).await {
+        Ok(user) => ApiResponse::success(Some(user)),
+        Err(UserError::NotFound) => ApiResponse::not_found("User not found"),
+        Err(UserError::Conflict) => ApiResponse::conflict("Email already registered"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/user/me/password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed, other sessions are signed out", body = ApiResponse<ChangePasswordResponse>),
+        (status = 401, description = "Current password mismatch", body = ApiResponse<Empty>),
+        (status = 404, description = "User not found", body = ApiResponse<Empty>),
+        (status = 423, description = "Locked after too many wrong passwords", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>)
+    ),
+    tag = "users",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn change_password(
+    State(state): State<UserState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<ChangePasswordRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::<ChangePasswordResponse>::unprocessable_entity(err.to_string());
+    }
+
+    match state.user_usecase.change_password(claims.sub, dto).await {
+        Ok(data) => ApiResponse::success(Some(data)),
+        Err(UserError::InvalidPassword) => ApiResponse::unauthorized("Current password mismatch"),
+        Err(UserError::Locked(until)) => {
+            ApiResponse::locked(format!("Account locked until {}", until.to_rfc3339()))
+        }
+        Err(UserError::NotFound) => ApiResponse::not_found("User not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
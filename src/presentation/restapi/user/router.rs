@@ -2,27 +2,48 @@ use std::sync::Arc;
 
 use axum::{
     Extension, Router, middleware,
-    routing::{delete, get},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
     application::user::usecase::UserUseCase,
-    infrastructure::database::sqlx::user_repository::PostgresUserRepository,
+    domain::{
+        lockout::repository::LockoutRepository, mail::mailer::Mailer,
+        shared::transaction::TransactionManager, user::repository::UserRepository,
+    },
     presentation::restapi::{
         RouterOption,
         middleware::jwt_middleware,
-        user::controller::{delete_user, find_all_user, find_user_by_id},
+        user::controller::{
+            change_password, delete_user, find_all_user, find_user_by_id, update_profile,
+        },
     },
 };
 
+pub type UserService = UserUseCase<
+    Arc<dyn UserRepository>,
+    Arc<dyn TransactionManager>,
+    Arc<dyn LockoutRepository>,
+    Arc<dyn Mailer>,
+>;
+
 #[derive(Clone)]
 pub struct UserState {
-    pub user_usecase: Arc<UserUseCase<PostgresUserRepository>>,
+    pub user_usecase: Arc<UserService>,
 }
 
 pub fn setup(opt: &RouterOption) -> Router {
-    let repo = PostgresUserRepository::new(opt.pool.clone());
-    let usecase = UserUseCase::new(repo);
+    let repo = opt.repositories.user.clone();
+    let transactions = opt.repositories.transactions.clone();
+    let usecase = UserUseCase::new(
+        repo,
+        transactions,
+        opt.repositories.lockout.clone(),
+        opt.mailer.clone(),
+        opt.config.lockout(),
+        opt.config.email_verification(),
+        opt.config.jwt_secret.clone(),
+    );
 
     let state = UserState {
         user_usecase: Arc::new(usecase),
@@ -30,6 +51,8 @@ pub fn setup(opt: &RouterOption) -> Router {
 
     Router::new()
         .route("/", get(find_all_user))
+        .route("/me", patch(update_profile))
+        .route("/me/password", post(change_password))
         .route("/{id}", delete(delete_user))
         .route("/{id}", get(find_user_by_id))
         .layer(middleware::from_fn(jwt_middleware))
@@ -0,0 +1,193 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::webhook::{
+        dto::{CreateWebhookRequest, DeliveryResponse, UpdateWebhookRequest, WebhookResponse},
+        error::WebhookError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        response::{ApiResponse, Empty},
+        webhook::router::WebhookState,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook registered, the secret signs every delivery", body = ApiResponse<WebhookResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn create_webhook(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<CreateWebhookRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state.webhook_usecase.create_webhook(claims.sub, dto).await {
+        Ok(data) => ApiResponse::<WebhookResponse>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    responses(
+        (status = 200, description = "Registered webhooks", body = ApiResponse<Vec<WebhookResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_all_webhook(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+) -> impl IntoResponse {
+    match state.webhook_usecase.find_all(claims.sub).await {
+        Ok(data) => ApiResponse::<Vec<WebhookResponse>>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the webhook"),
+    ),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = ApiResponse<WebhookResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Webhook not found", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn update_webhook(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<UpdateWebhookRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .webhook_usecase
+        .update_webhook(claims.sub, id, dto)
+        .await
+    {
+        Ok(data) => ApiResponse::<WebhookResponse>::success(Some(data)),
+        Err(WebhookError::NotFound) => ApiResponse::not_found("webhook not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the webhook to be deleted"),
+    ),
+    responses(
+        (status = 200, description = "Webhook deleted together with its delivery log", body = ApiResponse<Empty>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Webhook not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn delete_webhook(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.webhook_usecase.delete_webhook(claims.sub, id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(WebhookError::NotFound) => ApiResponse::not_found("webhook not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}/deliveries",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the webhook"),
+    ),
+    responses(
+        (status = 200, description = "Latest 100 deliveries, newest first", body = ApiResponse<Vec<DeliveryResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Webhook not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_deliveries(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.webhook_usecase.find_deliveries(claims.sub, id).await {
+        Ok(data) => ApiResponse::<Vec<DeliveryResponse>>::success(Some(data)),
+        Err(WebhookError::NotFound) => ApiResponse::not_found("webhook not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhooks/deliveries/{id}/redeliver",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the delivery to send again"),
+    ),
+    responses(
+        (status = 200, description = "Delivery queued again", body = ApiResponse<DeliveryResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Delivery not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "webhooks",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn redeliver(
+    State(state): State<WebhookState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.webhook_usecase.redeliver(claims.sub, id).await {
+        Ok(data) => ApiResponse::<DeliveryResponse>::success(Some(data)),
+        Err(WebhookError::NotFound) => ApiResponse::not_found("delivery not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{delete, get, post, put},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::webhook::usecase::WebhookUseCase,
+    infrastructure::{
+        database::sqlx::webhook_repository::PostgresWebhookRepository,
+        http::webhook_sender::ReqwestWebhookSender,
+    },
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        webhook::controller::{
+            create_webhook, delete_webhook, find_all_webhook, find_deliveries, redeliver,
+            update_webhook,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct WebhookState {
+    pub webhook_usecase: Arc<WebhookUseCase<PostgresWebhookRepository, ReqwestWebhookSender>>,
+}
+
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let repo = PostgresWebhookRepository::new(pool.clone());
+    let usecase = WebhookUseCase::new(repo, ReqwestWebhookSender::new());
+
+    let state = WebhookState {
+        webhook_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/", post(create_webhook))
+        .route("/", get(find_all_webhook))
+        .route("/{id}", put(update_webhook))
+        .route("/{id}", delete(delete_webhook))
+        .route("/{id}/deliveries", get(find_deliveries))
+        .route("/deliveries/{id}/redeliver", post(redeliver))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}
@@ -0,0 +1,230 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::workflow::{
+        dto::{
+            BoardResponse, CreateStateRequest, TransitionRequest, TransitionResponse,
+            UpdateStateRequest, WorkflowStateResponse,
+        },
+        error::WorkflowError,
+    },
+    infrastructure::security::jwt::JwtClaims,
+    presentation::restapi::{
+        response::{ApiResponse, Empty},
+        workflow::router::WorkflowState,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/workflow/states",
+    request_body = CreateStateRequest,
+    responses(
+        (status = 200, description = "Workflow state created", body = ApiResponse<WorkflowStateResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 409, description = "A state with this name already exists", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn create_state(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+    Json(dto): Json<CreateStateRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state.workflow_usecase.create_state(claims.sub, dto).await {
+        Ok(data) => ApiResponse::<WorkflowStateResponse>::success(Some(data)),
+        Err(WorkflowError::Conflict) => ApiResponse::conflict("state already exists"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/workflow/states",
+    responses(
+        (status = 200, description = "Workflow states ordered by position", body = ApiResponse<Vec<WorkflowStateResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_all_state(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+) -> impl IntoResponse {
+    match state.workflow_usecase.find_states(claims.sub).await {
+        Ok(data) => ApiResponse::<Vec<WorkflowStateResponse>>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/workflow/states/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the workflow state"),
+    ),
+    request_body = UpdateStateRequest,
+    responses(
+        (status = 200, description = "Workflow state updated", body = ApiResponse<WorkflowStateResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Workflow state not found", body = ApiResponse<Empty>),
+        (status = 409, description = "A state with this name already exists", body = ApiResponse<Empty>),
+        (status = 422, description = "Validation error in request body", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn update_state(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<UpdateStateRequest>,
+) -> impl IntoResponse {
+    if let Err(err) = dto.validate() {
+        return ApiResponse::unprocessable_entity(err.to_string());
+    }
+
+    match state
+        .workflow_usecase
+        .update_state(claims.sub, id, dto)
+        .await
+    {
+        Ok(data) => ApiResponse::<WorkflowStateResponse>::success(Some(data)),
+        Err(WorkflowError::NotFound) => ApiResponse::not_found("state not found"),
+        Err(WorkflowError::Conflict) => ApiResponse::conflict("state already exists"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/workflow/states/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the workflow state to be deleted"),
+    ),
+    responses(
+        (status = 200, description = "Workflow state deleted, its todos fall back to their category default", body = ApiResponse<Empty>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Workflow state not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn delete_state(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state.workflow_usecase.delete_state(claims.sub, id).await {
+        Ok(_) => ApiResponse::<Empty>::success(None),
+        Err(WorkflowError::NotFound) => ApiResponse::not_found("state not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/workflow/board",
+    responses(
+        (status = 200, description = "Todos grouped by workflow state", body = ApiResponse<BoardResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn board(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+) -> impl IntoResponse {
+    match state.workflow_usecase.board(claims.sub).await {
+        Ok(data) => ApiResponse::<BoardResponse>::success(Some(data)),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/todo/{id}/transition",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item to move"),
+    ),
+    request_body = TransitionRequest,
+    responses(
+        (status = 200, description = "Todo moved to the new state", body = ApiResponse<TransitionResponse>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo or state not found", body = ApiResponse<Empty>),
+        (status = 409, description = "Todo is already in this state", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn transition_todo(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<TransitionRequest>,
+) -> impl IntoResponse {
+    match state.workflow_usecase.transition(claims.sub, id, dto).await {
+        Ok(data) => ApiResponse::<TransitionResponse>::success(Some(data)),
+        Err(WorkflowError::NotFound) => ApiResponse::not_found("todo or state not found"),
+        Err(WorkflowError::Conflict) => ApiResponse::conflict("todo already in this state"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/todo/{id}/transitions",
+    params(
+        ("id" = Uuid, Path, description = "Unique identifier for the todo item"),
+    ),
+    responses(
+        (status = 200, description = "State changes of the todo, oldest first", body = ApiResponse<Vec<TransitionResponse>>),
+        (status = 401, description = "Unauthorized - invalid JWT claims", body = ApiResponse<Empty>),
+        (status = 404, description = "Todo not found", body = ApiResponse<Empty>),
+        (status = 500, description = "Internal server error", body = ApiResponse<Empty>),
+    ),
+    tag = "workflow",
+    security(("bearer_auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn find_transitions(
+    State(state): State<WorkflowState>,
+    Extension(claims): Extension<JwtClaims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    match state
+        .workflow_usecase
+        .find_transitions(claims.sub, id)
+        .await
+    {
+        Ok(data) => ApiResponse::<Vec<TransitionResponse>>::success(Some(data)),
+        Err(WorkflowError::NotFound) => ApiResponse::not_found("todo not found"),
+        Err(_) => ApiResponse::general_error(),
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router, middleware,
+    routing::{delete, get, post, put},
+};
+use sqlx::PgPool;
+
+use crate::{
+    application::workflow::usecase::WorkflowUseCase,
+    domain::{shared::transaction::TransactionManager, todo::repository::TodoRepository},
+    infrastructure::database::sqlx::workflow_repository::PostgresWorkflowRepository,
+    presentation::restapi::{
+        RouterOption,
+        middleware::jwt_middleware,
+        workflow::controller::{
+            board, create_state, delete_state, find_all_state, find_transitions, transition_todo,
+            update_state,
+        },
+    },
+};
+
+pub type WorkflowService = WorkflowUseCase<
+    PostgresWorkflowRepository,
+    Arc<dyn TodoRepository>,
+    Arc<dyn TransactionManager>,
+>;
+
+#[derive(Clone)]
+pub struct WorkflowState {
+    pub workflow_usecase: Arc<WorkflowService>,
+}
+
+// routes live under both `/todo/{id}` and `/workflow`, so this router is merged instead of nested
+pub fn setup(opt: &RouterOption, pool: &PgPool) -> Router {
+    let usecase = WorkflowUseCase::new(
+        PostgresWorkflowRepository::new(pool.clone()),
+        opt.repositories.todo.clone(),
+        opt.repositories.transactions.clone(),
+    );
+
+    let state = WorkflowState {
+        workflow_usecase: Arc::new(usecase),
+    };
+
+    Router::new()
+        .route("/workflow/states", post(create_state))
+        .route("/workflow/states", get(find_all_state))
+        .route("/workflow/states/{id}", put(update_state))
+        .route("/workflow/states/{id}", delete(delete_state))
+        .route("/workflow/board", get(board))
+        .route("/todo/{id}/transition", post(transition_todo))
+        .route("/todo/{id}/transitions", get(find_transitions))
+        .layer(middleware::from_fn(jwt_middleware))
+        .layer(Extension(opt.config.jwt_secret.clone()))
+        .with_state(state)
+}